@@ -630,4 +630,6 @@ macro_rules! float_sum_product {
 }
 
 integer_sum_product! { i8 i16 i32 i64 isize u8 u16 u32 u64 usize }
+#[cfg(not(stage0))]
+integer_sum_product! { i128 u128 }
 float_sum_product! { f32 f64 }
@@ -0,0 +1,210 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Runtime support for 128-bit integers on 32-bit targets.
+//!
+//! LLVM lowers some `i128` and `u128` operations to calls into compiler-rt,
+//! which only provides them on 64-bit targets. The functions here fill the
+//! gap. They must not use 128-bit multiplication, division or variable
+//! shifts themselves, since those would call straight back into this module,
+//! so all of the work is done on pairs of 64-bit halves.
+
+#![allow(non_snake_case)]
+
+use mem;
+
+#[cfg(target_endian = "little")]
+fn split(a: u128) -> (u64, u64) {
+    let words: [u64; 2] = unsafe { mem::transmute(a) };
+    (words[0], words[1])
+}
+
+#[cfg(target_endian = "big")]
+fn split(a: u128) -> (u64, u64) {
+    let words: [u64; 2] = unsafe { mem::transmute(a) };
+    (words[1], words[0])
+}
+
+#[cfg(target_endian = "little")]
+fn join(low: u64, high: u64) -> u128 {
+    unsafe { mem::transmute([low, high]) }
+}
+
+#[cfg(target_endian = "big")]
+fn join(low: u64, high: u64) -> u128 {
+    unsafe { mem::transmute([high, low]) }
+}
+
+fn shl(a: u128, b: u32) -> u128 {
+    let (low, high) = split(a);
+    let b = b & 127;
+    if b == 0 {
+        a
+    } else if b < 64 {
+        join(low << b, (high << b) | (low >> (64 - b)))
+    } else {
+        join(0, low << (b - 64))
+    }
+}
+
+fn lshr(a: u128, b: u32) -> u128 {
+    let (low, high) = split(a);
+    let b = b & 127;
+    if b == 0 {
+        a
+    } else if b < 64 {
+        join((low >> b) | (high << (64 - b)), high >> b)
+    } else {
+        join(high >> (b - 64), 0)
+    }
+}
+
+fn ashr(a: u128, b: u32) -> u128 {
+    let (low, high) = split(a);
+    let high = high as i64;
+    let b = b & 127;
+    if b == 0 {
+        a
+    } else if b < 64 {
+        join((low >> b) | ((high as u64) << (64 - b)), (high >> b) as u64)
+    } else {
+        join((high >> (b - 64)) as u64, (high >> 63) as u64)
+    }
+}
+
+/// Multiplies two 64-bit values into a full 128-bit product.
+fn mul_wide(a: u64, b: u64) -> (u64, u64) {
+    let (a0, a1) = (a & 0xffff_ffff, a >> 32);
+    let (b0, b1) = (b & 0xffff_ffff, b >> 32);
+    let p00 = a0 * b0;
+    let p01 = a0 * b1;
+    let p10 = a1 * b0;
+    let p11 = a1 * b1;
+    let mid = (p00 >> 32) + (p01 & 0xffff_ffff) + (p10 & 0xffff_ffff);
+    let low = (p00 & 0xffff_ffff) | (mid << 32);
+    let high = p11 + (p01 >> 32) + (p10 >> 32) + (mid >> 32);
+    (low, high)
+}
+
+fn mul(a: u128, b: u128) -> u128 {
+    let (a_low, a_high) = split(a);
+    let (b_low, b_high) = split(b);
+    let (low, high) = mul_wide(a_low, b_low);
+    let high = high.wrapping_add(a_low.wrapping_mul(b_high))
+                   .wrapping_add(a_high.wrapping_mul(b_low));
+    join(low, high)
+}
+
+fn less_than(a: u128, b: u128) -> bool {
+    let (a_low, a_high) = split(a);
+    let (b_low, b_high) = split(b);
+    a_high < b_high || (a_high == b_high && a_low < b_low)
+}
+
+fn sub(a: u128, b: u128) -> u128 {
+    let (a_low, a_high) = split(a);
+    let (b_low, b_high) = split(b);
+    let (low, borrow) = a_low.overflowing_sub(b_low);
+    join(low, a_high.wrapping_sub(b_high).wrapping_sub(borrow as u64))
+}
+
+fn negate(a: u128) -> u128 {
+    sub(0, a)
+}
+
+/// Shift-and-subtract long division, returning the quotient and remainder.
+fn udivmod(n: u128, d: u128) -> (u128, u128) {
+    if d == 0 {
+        // The callers check for division by zero before getting here.
+        unsafe { ::intrinsics::abort() }
+    }
+    let mut quotient = 0;
+    let mut remainder = 0;
+    let mut bit = 128;
+    while bit > 0 {
+        bit -= 1;
+        remainder = shl(remainder, 1) | (lshr(n, bit) & 1);
+        if !less_than(remainder, d) {
+            remainder = sub(remainder, d);
+            quotient = quotient | shl(1, bit);
+        }
+    }
+    (quotient, remainder)
+}
+
+fn is_negative(a: i128) -> bool {
+    (split(a as u128).1 as i64) < 0
+}
+
+fn abs(a: i128) -> u128 {
+    if is_negative(a) { negate(a as u128) } else { a as u128 }
+}
+
+#[no_mangle]
+pub extern "C" fn __ashlti3(a: u128, b: u32) -> u128 {
+    shl(a, b)
+}
+
+#[no_mangle]
+pub extern "C" fn __lshrti3(a: u128, b: u32) -> u128 {
+    lshr(a, b)
+}
+
+#[no_mangle]
+pub extern "C" fn __ashrti3(a: i128, b: u32) -> i128 {
+    ashr(a as u128, b) as i128
+}
+
+#[no_mangle]
+pub extern "C" fn __multi3(a: i128, b: i128) -> i128 {
+    mul(a as u128, b as u128) as i128
+}
+
+#[no_mangle]
+pub extern "C" fn __udivti3(a: u128, b: u128) -> u128 {
+    udivmod(a, b).0
+}
+
+#[no_mangle]
+pub extern "C" fn __umodti3(a: u128, b: u128) -> u128 {
+    udivmod(a, b).1
+}
+
+#[no_mangle]
+pub extern "C" fn __divti3(a: i128, b: i128) -> i128 {
+    let quotient = udivmod(abs(a), abs(b)).0;
+    if is_negative(a) != is_negative(b) {
+        negate(quotient) as i128
+    } else {
+        quotient as i128
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn __modti3(a: i128, b: i128) -> i128 {
+    let remainder = udivmod(abs(a), abs(b)).1;
+    if is_negative(a) { negate(remainder) as i128 } else { remainder as i128 }
+}
+
+#[no_mangle]
+pub extern "C" fn __muloti4(a: i128, b: i128, overflow: &mut i32) -> i128 {
+    let result = mul(a as u128, b as u128) as i128;
+    let (abs_a, abs_b) = (abs(a), abs(b));
+    *overflow = 0;
+    if abs_a == 0 || abs_b == 0 {
+        return result;
+    }
+    // The magnitude of the result may reach 2^127 only when it is negative.
+    let limit = if is_negative(a) != is_negative(b) { shl(1, 127) } else { shl(1, 127) - 1 };
+    if less_than(udivmod(limit, abs_b).0, abs_a) {
+        *overflow = 1;
+    }
+    result
+}
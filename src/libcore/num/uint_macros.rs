@@ -10,13 +10,17 @@
 
 #![doc(hidden)]
 
-macro_rules! uint_module { ($T:ident, $bits:expr) => (
+macro_rules! uint_module {
+    ($T:ident, $bits:expr) => (
+        uint_module!($T, $bits, #[stable(feature = "rust1", since = "1.0.0")]);
+    );
+    ($T:ident, $bits:expr, #[$attr:meta]) => (
 
 /// The smallest value that can be represented by this integer type.
-#[stable(feature = "rust1", since = "1.0.0")]
+#[$attr]
 pub const MIN: $T = $T::min_value();
 /// The largest value that can be represented by this integer type.
-#[stable(feature = "rust1", since = "1.0.0")]
+#[$attr]
 pub const MAX: $T = $T::max_value();
 
 ) }
@@ -19,6 +19,7 @@ use syntax::visit::Visitor;
 enum Target {
     Fn,
     Struct,
+    Union,
     Enum,
     Other,
 }
@@ -28,6 +29,7 @@ impl Target {
         match item.node {
             ast::ItemKind::Fn(..) => Target::Fn,
             ast::ItemKind::Struct(..) => Target::Struct,
+            ast::ItemKind::Union(..) => Target::Union,
             ast::ItemKind::Enum(..) => Target::Enum,
             _ => Target::Other,
         }
@@ -55,9 +57,11 @@ impl<'a> CheckAttrVisitor<'a> {
         for word in words {
             let word: &str = &word.name();
             let message = match word {
-                "C" => {
-                    if target != Target::Struct && target != Target::Enum {
-                            "attribute should be applied to struct or enum"
+                "C" | "align" => {
+                    if target != Target::Struct &&
+                            target != Target::Union &&
+                            target != Target::Enum {
+                            "attribute should be applied to struct, enum or union"
                     } else {
                         continue
                     }
@@ -101,6 +105,18 @@ impl<'a> Visitor for CheckAttrVisitor<'a> {
         for attr in &item.attrs {
             self.check_attribute(attr, target);
         }
+
+        let repr_hints = item.attrs.iter()
+            .filter(|attr| attr.name() == "repr")
+            .flat_map(|attr| attr.meta_item_list().unwrap_or(&[]))
+            .map(|hint| hint.name())
+            .collect::<Vec<_>>();
+        if repr_hints.iter().any(|hint| *hint == "packed") &&
+           repr_hints.iter().any(|hint| *hint == "align") {
+            span_err!(self.sess, item.span, E0566,
+                      "type has conflicting packed and align representation hints");
+        }
+
         visit::walk_item(self, item);
     }
 }
@@ -133,7 +133,8 @@ impl<'ast> visit::Visitor for DefCollector<'ast> {
         let def_data = match i.node {
             ItemKind::DefaultImpl(..) | ItemKind::Impl(..) =>
                 DefPathData::Impl,
-            ItemKind::Enum(..) | ItemKind::Struct(..) | ItemKind::Trait(..) |
+            ItemKind::Enum(..) | ItemKind::Struct(..) | ItemKind::Union(..) |
+            ItemKind::Trait(..) |
             ItemKind::ExternCrate(..) | ItemKind::ForeignMod(..) | ItemKind::Ty(..) =>
                 DefPathData::TypeNs(i.ident.name),
             ItemKind::Mod(..) => DefPathData::Module(i.ident.name),
@@ -177,6 +178,13 @@ impl<'ast> visit::Visitor for DefCollector<'ast> {
                         this.create_def(field.id, DefPathData::Field(name));
                     }
                 }
+                ItemKind::Union(ref vdata, _) => {
+                    for (index, field) in vdata.fields().iter().enumerate() {
+                        let name = field.ident.map(|ident| ident.name)
+                            .unwrap_or(token::intern(&index.to_string()));
+                        this.create_def(field.id, DefPathData::Field(name));
+                    }
+                }
                 _ => {}
             }
             visit::walk_item(this, i);
@@ -267,6 +275,9 @@ impl<'ast> visit::Visitor for DefCollector<'ast> {
         if let TyKind::FixedLengthVec(_, ref length) = ty.node {
             self.visit_ast_const_integer(length);
         }
+        if let TyKind::ImplTrait(..) = ty.node {
+            self.create_def(ty.id, DefPathData::ImplTrait);
+        }
         visit::walk_ty(self, ty);
     }
 
@@ -298,7 +309,8 @@ impl<'ast> intravisit::Visitor<'ast> for DefCollector<'ast> {
         let def_data = match i.node {
             hir::ItemDefaultImpl(..) | hir::ItemImpl(..) =>
                 DefPathData::Impl,
-            hir::ItemEnum(..) | hir::ItemStruct(..) | hir::ItemTrait(..) |
+            hir::ItemEnum(..) | hir::ItemStruct(..) | hir::ItemUnion(..) |
+            hir::ItemTrait(..) |
             hir::ItemExternCrate(..) | hir::ItemMod(..) | hir::ItemForeignMod(..) |
             hir::ItemTy(..) =>
                 DefPathData::TypeNs(i.name),
@@ -338,6 +350,11 @@ impl<'ast> intravisit::Visitor<'ast> for DefCollector<'ast> {
                         this.create_def(field.id, DefPathData::Field(field.name));
                     }
                 }
+                hir::ItemUnion(ref vdata, _) => {
+                    for field in vdata.fields() {
+                        this.create_def(field.id, DefPathData::Field(field.name));
+                    }
+                }
                 _ => {}
             }
             intravisit::walk_item(this, i);
@@ -426,6 +443,9 @@ impl<'ast> intravisit::Visitor<'ast> for DefCollector<'ast> {
         if let hir::TyFixedLengthVec(_, ref length) = ty.node {
             self.visit_hir_const_integer(length);
         }
+        if let hir::TyImplTrait(..) = ty.node {
+            self.create_def(ty.id, DefPathData::ImplTrait);
+        }
         intravisit::walk_ty(self, ty);
     }
 
@@ -178,6 +178,8 @@ pub enum DefPathData {
     Initializer,
     /// Pattern binding
     Binding(ast::Name),
+    /// An `impl Trait` type node.
+    ImplTrait,
 }
 
 impl Definitions {
@@ -332,6 +334,10 @@ impl DefPathData {
             Initializer => {
                 InternedString::new("{{initializer}}")
             }
+
+            ImplTrait => {
+                InternedString::new("{{impl-Trait}}")
+            }
         }
     }
 
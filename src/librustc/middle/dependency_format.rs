@@ -139,8 +139,12 @@ fn calculate_type(sess: &session::Session,
             }
         }
 
-        // Everything else falls through below
-        config::CrateTypeExecutable | config::CrateTypeDylib => {},
+        // Everything else falls through below. This includes `proc-macro`
+        // crates, which are loaded into the compiler and so must share its
+        // dynamically linked dependencies.
+        config::CrateTypeExecutable |
+        config::CrateTypeDylib |
+        config::CrateTypeProcMacro => {},
     }
 
     let mut formats = FnvHashMap();
@@ -19,7 +19,7 @@ use ty::MethodCall;
 
 use syntax::ast;
 use syntax_pos::Span;
-use hir;
+use hir::{self, PatKind};
 use hir::intravisit;
 use hir::intravisit::{FnKind, Visitor};
 
@@ -176,11 +176,44 @@ impl<'a, 'tcx, 'v> Visitor<'v> for EffectCheckVisitor<'a, 'tcx> {
                     self.require_unsafe(expr.span, "use of mutable static");
                 }
             }
+            hir::ExprField(ref base_expr, field) => {
+                if self.tcx.expr_ty_adjusted(base_expr).is_union() {
+                    self.require_unsafe(field.span, "access to union field");
+                }
+            }
+            hir::ExprAssign(ref lhs, ref rhs) => {
+                if let hir::ExprField(ref base_expr, field) = lhs.node {
+                    if self.tcx.expr_ty_adjusted(base_expr).is_union() {
+                        // Writing a union field only overwrites its bytes,
+                        // unless the old value of the field has to be
+                        // dropped first, which reads it.
+                        let field_ty = self.tcx.expr_ty_adjusted(lhs);
+                        let param_env = self.tcx.empty_parameter_environment();
+                        if self.tcx.type_needs_drop_given_env(field_ty, &param_env) {
+                            self.require_unsafe(field.span,
+                                                "assignment to non-`Copy` union field");
+                        }
+                        self.visit_expr(base_expr);
+                        self.visit_expr(rhs);
+                        return;
+                    }
+                }
+            }
             _ => {}
         }
 
         intravisit::walk_expr(self, expr);
     }
+
+    fn visit_pat(&mut self, pat: &hir::Pat) {
+        if let PatKind::Struct(..) = pat.node {
+            if self.tcx.pat_ty(pat).is_union() {
+                self.require_unsafe(pat.span, "matching on union field");
+            }
+        }
+
+        intravisit::walk_pat(self, pat);
+    }
 }
 
 pub fn check_crate<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>) {
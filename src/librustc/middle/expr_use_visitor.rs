@@ -462,11 +462,16 @@ impl<'a, 'gcx, 'tcx> ExprUseVisitor<'a, 'gcx, 'tcx> {
                 self.consume_exprs(inputs);
             }
 
-            hir::ExprBreak(..) |
             hir::ExprAgain(..) |
             hir::ExprLit(..) => {}
 
-            hir::ExprLoop(ref blk, _) => {
+            hir::ExprBreak(_, ref opt_expr) => {
+                if let Some(ref expr) = *opt_expr {
+                    self.consume_expr(&expr);
+                }
+            }
+
+            hir::ExprLoop(ref blk, _, _) => {
                 self.walk_block(&blk);
             }
 
@@ -71,6 +71,7 @@ fn verify(sess: &Session, items: &lang_items::LanguageItems) {
         match *kind {
             config::CrateTypeDylib |
             config::CrateTypeCdylib |
+            config::CrateTypeProcMacro |
             config::CrateTypeExecutable |
             config::CrateTypeStaticlib => true,
             config::CrateTypeRlib => false,
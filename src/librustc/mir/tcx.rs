@@ -225,7 +225,7 @@ impl<'a, 'gcx, 'tcx> Mir<'tcx> {
                             ops.iter().map(|op| self.operand_ty(tcx, op)).collect()
                         ))
                     }
-                    AggregateKind::Adt(def, _, substs) => {
+                    AggregateKind::Adt(def, _, substs, _) => {
                         Some(tcx.lookup_item_type(def.did).ty.subst(tcx, substs))
                     }
                     AggregateKind::Closure(did, substs) => {
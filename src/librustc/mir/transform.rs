@@ -105,6 +105,10 @@ pub trait MirPassHook<'tcx>: Pass {
         pass: &Pass,
         is_after: bool
     );
+
+    /// Called once all the passes of a `Passes` manager have run. A hook may
+    /// be moved to another manager afterwards, see `Passes::take_hooks`.
+    fn on_passes_done<'a>(&mut self, _tcx: TyCtxt<'a, 'tcx, 'tcx>) {}
 }
 
 /// A pass which inspects Mir of functions in isolation.
@@ -171,6 +175,9 @@ impl<'a, 'tcx> Passes {
         for pass in &mut self.passes {
             pass.run_pass(tcx, map, &mut self.pass_hooks);
         }
+        for hook in &mut self.pass_hooks {
+            hook.on_passes_done(tcx);
+        }
     }
 
     /// Pushes a built-in pass.
@@ -182,6 +189,12 @@ impl<'a, 'tcx> Passes {
     pub fn push_hook(&mut self, hook: Box<for<'b> MirPassHook<'b>>) {
         self.pass_hooks.push(hook);
     }
+
+    /// Moves the pass hooks of `other` into this manager, so that hooks which
+    /// keep state see the passes of both.
+    pub fn take_hooks(&mut self, other: &mut Passes) {
+        self.pass_hooks.extend(other.pass_hooks.drain(..));
+    }
 }
 
 /// Copies the plugin passes.
@@ -347,6 +347,7 @@ pub enum CrateType {
     CrateTypeRlib,
     CrateTypeStaticlib,
     CrateTypeCdylib,
+    CrateTypeProcMacro,
 }
 
 #[derive(Clone)]
@@ -674,6 +675,8 @@ options! {DebuggingOptions, DebuggingSetter, basic_debugging_options,
         "skip LLVM verification"),
     borrowck_stats: bool = (false, parse_bool,
         "gather borrowck statistics"),
+    borrowck_mir: bool = (false, parse_bool,
+        "also run the (experimental) MIR-based borrow checker on every fn"),
     no_landing_pads: bool = (false, parse_bool,
         "omit landing pads for unwinding"),
     debug_llvm: bool = (false, parse_bool,
@@ -747,6 +750,12 @@ options! {DebuggingOptions, DebuggingSetter, basic_debugging_options,
           "set the MIR optimization level (0-3)"),
     dump_mir: Option<String> = (None, parse_opt_string,
           "dump MIR state at various points in translation"),
+    dump_mir_format: Option<String> = (None, parse_opt_string,
+          "format of the files written by -Z dump-mir (text|json|html)"),
+    dump_mir_diff: Option<String> = (None, parse_opt_string,
+          "diff the MIR before and after each pass (same filter syntax as dump-mir)"),
+    validate_mir: bool = (false, parse_bool,
+          "check MIR structural invariants after every MIR pass"),
     orbit: bool = (false, parse_bool,
           "get MIR where it belongs - everywhere; most importantly, in orbit"),
 }
@@ -981,7 +990,7 @@ pub fn rustc_short_optgroups() -> Vec<RustcOptGroup> {
                              assumed.", "[KIND=]NAME"),
         opt::multi_s("", "crate-type", "Comma separated list of types of crates
                                     for the compiler to emit",
-                   "[bin|lib|rlib|dylib|staticlib]"),
+                   "[bin|lib|rlib|dylib|cdylib|staticlib|proc-macro]"),
         opt::opt_s("", "crate-name", "Specify the name of the crate being built",
                "NAME"),
         opt::multi_s("", "emit", "Comma separated list of types of output for \
@@ -1144,6 +1153,15 @@ pub fn build_session_options(matches: &getopts::Matches) -> Options {
     let mir_opt_level = debugging_opts.mir_opt_level.unwrap_or(1);
     let no_analysis = debugging_opts.no_analysis;
 
+    match debugging_opts.dump_mir_format.as_ref().map(|s| &s[..]) {
+        None | Some("text") | Some("json") | Some("html") => {}
+        Some(format) => {
+            early_error(error_format, &format!("unknown MIR dump format `{}`, expected one \
+                                                of `text`, `json` or `html`",
+                                               format))
+        }
+    }
+
     let mut output_types = HashMap::new();
     if !debugging_opts.parse_only {
         for list in matches.opt_strs("emit") {
@@ -1368,6 +1386,7 @@ pub fn parse_crate_types_from_list(list_list: Vec<String>) -> Result<Vec<CrateTy
                 "dylib"     => CrateTypeDylib,
                 "cdylib"    => CrateTypeCdylib,
                 "bin"       => CrateTypeExecutable,
+                "proc-macro" => CrateTypeProcMacro,
                 _ => {
                     return Err(format!("unknown crate type: `{}`",
                                        part));
@@ -1456,6 +1475,7 @@ impl fmt::Display for CrateType {
             CrateTypeRlib => "rlib".fmt(f),
             CrateTypeStaticlib => "staticlib".fmt(f),
             CrateTypeCdylib => "cdylib".fmt(f),
+            CrateTypeProcMacro => "proc-macro".fmt(f),
         }
     }
 }
@@ -255,7 +255,8 @@ fn ty_is_local_constructor(tcx: TyCtxt, ty: Ty, infer_is_local: InferIsLocal)->
         ty::TyRef(..) |
         ty::TyTuple(..) |
         ty::TyParam(..) |
-        ty::TyProjection(..) => {
+        ty::TyProjection(..) |
+        ty::TyAnon(..) => {
             false
         }
 
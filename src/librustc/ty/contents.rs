@@ -232,6 +232,12 @@ impl<'a, 'tcx> ty::TyS<'tcx> {
                             })
                         });
 
+                    // The fields of a union are never dropped, as it
+                    // is not known which one of them is initialized.
+                    if def.is_union() {
+                        res = res - TC::OwnsAll;
+                    }
+
                     if def.has_dtor() {
                         res = res | TC::OwnsDtor;
                     }
@@ -240,7 +246,8 @@ impl<'a, 'tcx> ty::TyS<'tcx> {
                 }
 
                 ty::TyProjection(..) |
-                ty::TyParam(_) => {
+                ty::TyParam(_) |
+                ty::TyAnon(..) => {
                     TC::All
                 }
 
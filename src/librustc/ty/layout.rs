@@ -34,6 +34,7 @@ pub struct TargetDataLayout {
     pub i16_align: Align,
     pub i32_align: Align,
     pub i64_align: Align,
+    pub i128_align: Align,
     pub f32_align: Align,
     pub f64_align: Align,
     pub pointer_size: Size,
@@ -53,6 +54,7 @@ impl Default for TargetDataLayout {
             i16_align: Align::from_bits(16, 16).unwrap(),
             i32_align: Align::from_bits(32, 32).unwrap(),
             i64_align: Align::from_bits(32, 64).unwrap(),
+            i128_align: Align::from_bits(32, 64).unwrap(),
             f32_align: Align::from_bits(32, 32).unwrap(),
             f64_align: Align::from_bits(64, 64).unwrap(),
             pointer_size: Size::from_bits(64),
@@ -115,6 +117,7 @@ impl TargetDataLayout {
                         Ok(16) => &mut dl.i16_align,
                         Ok(32) => &mut dl.i32_align,
                         Ok(64) => &mut dl.i64_align,
+                        Ok(128) => &mut dl.i128_align,
                         Ok(_) => continue,
                         Err(_) => {
                             size(&s[1..], "i"); // For the user error.
@@ -323,7 +326,8 @@ pub enum Integer {
     I8,
     I16,
     I32,
-    I64
+    I64,
+    I128,
 }
 
 impl Integer {
@@ -356,6 +360,7 @@ impl Integer {
             attr::SignedInt(IntTy::I16) | attr::UnsignedInt(UintTy::U16) => I16,
             attr::SignedInt(IntTy::I32) | attr::UnsignedInt(UintTy::U32) => I32,
             attr::SignedInt(IntTy::I64) | attr::UnsignedInt(UintTy::U64) => I64,
+            attr::SignedInt(IntTy::I128) | attr::UnsignedInt(UintTy::U128) => I128,
             attr::SignedInt(IntTy::Is) | attr::UnsignedInt(UintTy::Us) => {
                 dl.ptr_sized_integer()
             }
@@ -401,6 +406,9 @@ impl Integer {
             attr::ReprSimd => {
                 bug!("Integer::repr_discr: found #[repr(simd)] on an enum");
             }
+            attr::ReprAlign(_) => {
+                bug!("Integer::repr_discr: found #[repr(align)] as a discriminant hint");
+            }
         };
 
         // If there are no negative values, we can use the unsigned fit.
@@ -428,6 +436,7 @@ impl Primitive {
             Int(I16) => Size::from_bits(16),
             Int(I32) | F32 => Size::from_bits(32),
             Int(I64) | F64 => Size::from_bits(64),
+            Int(I128) => Size::from_bits(128),
             Pointer => dl.pointer_size
         }
     }
@@ -439,6 +448,7 @@ impl Primitive {
             Int(I16) => dl.i16_align,
             Int(I32) => dl.i32_align,
             Int(I64) => dl.i64_align,
+            Int(I128) => dl.i128_align,
             F32 => dl.f32_align,
             F64 => dl.f64_align,
             Pointer => dl.pointer_align
@@ -528,6 +538,14 @@ impl<'a, 'gcx, 'tcx> Struct {
         self.min_size().abi_align(self.align)
     }
 
+    /// Raise the alignment to the one requested by `#[repr(align(N))]`, if any.
+    pub fn raise_align(&mut self, repr_align: Option<u64>) {
+        if let Some(bytes) = repr_align {
+            let align = Align::from_bytes(bytes, bytes).unwrap();
+            self.align = self.align.max(align);
+        }
+    }
+
     /// Determine whether a structure would be zero-sized, given its fields.
     pub fn would_be_zero_sized<I>(dl: &TargetDataLayout, fields: I)
                                   -> Result<bool, LayoutError<'gcx>>
@@ -572,6 +590,9 @@ impl<'a, 'gcx, 'tcx> Struct {
                 }
             }
 
+            // The fields of a union may hold any bit pattern.
+            (_, &ty::TyStruct(def, _)) if def.is_union() => Ok(None),
+
             // Perhaps one of the fields of this struct is non-zero
             // let's recurse and find out
             (_, &ty::TyStruct(def, substs)) => {
@@ -616,6 +637,57 @@ impl<'a, 'gcx, 'tcx> Struct {
     }
 }
 
+/// An untagged union, all of whose fields start at offset 0.
+#[derive(PartialEq, Eq, Hash, Debug)]
+pub struct Union {
+    pub align: Align,
+
+    /// The size of the largest field, excluding padding.
+    pub min_size: Size
+}
+
+impl<'a, 'gcx, 'tcx> Union {
+    pub fn new(dl: &TargetDataLayout) -> Union {
+        Union {
+            align: dl.aggregate_align,
+            min_size: Size::from_bytes(0)
+        }
+    }
+
+    /// Extend the Union with more fields.
+    pub fn extend<I>(&mut self, dl: &TargetDataLayout,
+                     fields: I,
+                     scapegoat: Ty<'gcx>)
+                     -> Result<(), LayoutError<'gcx>>
+    where I: Iterator<Item=Result<&'a Layout, LayoutError<'gcx>>> {
+        for (index, field) in fields.enumerate() {
+            let field = field?;
+            if field.is_unsized() {
+                bug!("Union::extend: field #{} of `{}` is unsized",
+                     index, scapegoat);
+            }
+
+            self.align = self.align.max(field.align(dl));
+            self.min_size = cmp::max(self.min_size, field.size(dl));
+        }
+
+        Ok(())
+    }
+
+    /// Get the size with trailing aligment padding.
+    pub fn stride(&self) -> Size {
+        self.min_size.abi_align(self.align)
+    }
+
+    /// Raise the alignment to the one requested by `#[repr(align(N))]`, if any.
+    pub fn raise_align(&mut self, repr_align: Option<u64>) {
+        if let Some(bytes) = repr_align {
+            let align = Align::from_bytes(bytes, bytes).unwrap();
+            self.align = self.align.max(align);
+        }
+    }
+}
+
 /// The first half of a fat pointer.
 /// - For a trait object, this is the address of the box.
 /// - For a slice, this is the base address.
@@ -683,6 +755,11 @@ pub enum Layout {
         non_zero: bool
     },
 
+    /// Untagged unions.
+    UntaggedUnion {
+        variants: Union
+    },
+
     /// General-case enums: for each case there is a struct, and they
     /// all start with a field for the discriminant.
     General {
@@ -876,9 +953,17 @@ impl<'a, 'gcx, 'tcx> Layout {
                     normalize_associated_type(infcx, field.ty(tcx, substs))
                         .layout(infcx)
                 });
+                let repr_align = tcx.lookup_repr_align(def.did);
+                if def.is_union() {
+                    let mut un = Union::new(dl);
+                    un.extend(dl, fields, ty)?;
+                    un.raise_align(repr_align);
+                    return Ok(UntaggedUnion { variants: un });
+                }
                 let packed = tcx.lookup_packed(def.did);
                 let mut st = Struct::new(dl, packed);
                 st.extend(dl, fields, ty)?;
+                st.raise_align(repr_align);
 
                 // FIXME(16758) don't add a drop flag to unsized structs, as it
                 // won't actually be in the location we say it is because it'll be after
@@ -897,8 +982,8 @@ impl<'a, 'gcx, 'tcx> Layout {
                 }
             }
             ty::TyEnum(def, substs) => {
-                let hint = *tcx.lookup_repr_hints(def.did).get(0)
-                    .unwrap_or(&attr::ReprAny);
+                let hint = tcx.lookup_enum_repr_hint(def.did);
+                let repr_align = tcx.lookup_repr_align(def.did);
 
                 let dtor = def.dtor_kind().has_drop_flag();
                 let drop_flag = if dtor {
@@ -914,10 +999,14 @@ impl<'a, 'gcx, 'tcx> Layout {
 
                     let mut st = Struct::new(dl, false);
                     st.extend(dl, drop_flag.iter().map(Ok), ty)?;
+                    st.raise_align(repr_align);
                     return Ok(Univariant { variant: st, non_zero: false });
                 }
 
-                if !dtor && def.variants.iter().all(|v| v.fields.is_empty()) {
+                // A raised alignment needs the general representation, which
+                // has room for padding after the discriminant.
+                if !dtor && repr_align.is_none() &&
+                   def.variants.iter().all(|v| v.fields.is_empty()) {
                     // All bodies empty -> intlike
                     let (mut min, mut max) = (i64::MAX, i64::MIN);
                     for v in &def.variants {
@@ -955,6 +1044,7 @@ impl<'a, 'gcx, 'tcx> Layout {
                     });
                     let mut st = Struct::new(dl, false);
                     st.extend(dl, fields.chain(drop_flag.iter().map(Ok)), ty)?;
+                    st.raise_align(repr_align);
                     return Ok(Univariant { variant: st, non_zero: false });
                 }
 
@@ -965,7 +1055,8 @@ impl<'a, 'gcx, 'tcx> Layout {
                     }).collect::<Vec<_>>()
                 }).collect::<Vec<_>>();
 
-                if !dtor && variants.len() == 2 && hint == attr::ReprAny {
+                if !dtor && variants.len() == 2 && hint == attr::ReprAny &&
+                   repr_align.is_none() {
                     // Nullable pointer optimization
                     for discr in 0..2 {
                         let other_fields = variants[1 - discr].iter().map(|ty| {
@@ -1041,6 +1132,7 @@ impl<'a, 'gcx, 'tcx> Layout {
                     let mut st = Struct::new(dl, false);
                     st.extend(dl, discr.iter().map(Ok).chain(fields)
                                               .chain(drop_flag.iter().map(Ok)), ty)?;
+                    st.raise_align(repr_align);
                     size = cmp::max(size, st.min_size());
                     align = align.max(st.align);
                     Ok(st)
@@ -1074,7 +1166,7 @@ impl<'a, 'gcx, 'tcx> Layout {
                 }
 
                 // FIXME(eddyb) conservative only to avoid diverging from trans::adt.
-                if align.abi() != start_align.abi() {
+                if align.abi() != start_align.abi() || repr_align.is_some() {
                     ity = min_ity;
                 }
 
@@ -1105,7 +1197,7 @@ impl<'a, 'gcx, 'tcx> Layout {
             }
 
             // Types with no meaningful known layout.
-            ty::TyProjection(_) | ty::TyParam(_) => {
+            ty::TyProjection(_) | ty::TyAnon(..) | ty::TyParam(_) => {
                 return Err(LayoutError::Unknown(ty));
             }
             ty::TyInfer(_) | ty::TyError => {
@@ -1120,7 +1212,7 @@ impl<'a, 'gcx, 'tcx> Layout {
     pub fn is_unsized(&self) -> bool {
         match *self {
             Scalar {..} | Vector {..} | FatPointer {..} |
-            CEnum {..} | General {..} |
+            CEnum {..} | General {..} | UntaggedUnion {..} |
             RawNullablePointer {..} |
             StructWrappedNullablePointer {..} => false,
 
@@ -1159,6 +1251,8 @@ impl<'a, 'gcx, 'tcx> Layout {
             StructWrappedNullablePointer { nonnull: ref variant, .. } => {
                 variant.stride()
             }
+
+            UntaggedUnion { ref variants } => variants.stride()
         }
     }
 
@@ -1198,6 +1292,8 @@ impl<'a, 'gcx, 'tcx> Layout {
             StructWrappedNullablePointer { nonnull: ref variant, .. } => {
                 variant.align
             }
+
+            UntaggedUnion { ref variants } => variants.align
         }
     }
 }
@@ -1243,7 +1339,7 @@ impl<'a, 'gcx, 'tcx> SizeSkeleton<'gcx> {
                 let non_zero = !ty.is_unsafe_ptr();
                 let tail = tcx.struct_tail(pointee);
                 match tail.sty {
-                    ty::TyParam(_) | ty::TyProjection(_) => {
+                    ty::TyParam(_) | ty::TyProjection(_) | ty::TyAnon(..) => {
                         assert!(tail.has_param_types() || tail.has_self_ty());
                         Ok(SizeSkeleton::Pointer {
                             non_zero: non_zero,
@@ -1260,7 +1356,7 @@ impl<'a, 'gcx, 'tcx> SizeSkeleton<'gcx> {
 
             ty::TyStruct(def, substs) | ty::TyEnum(def, substs) => {
                 // Only newtypes and enums w/ nullable pointer optimization.
-                if def.variants.is_empty() || def.variants.len() > 2 {
+                if def.is_union() || def.variants.is_empty() || def.variants.len() > 2 {
                     return Err(err);
                 }
 
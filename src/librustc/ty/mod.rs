@@ -1375,6 +1375,7 @@ impl<'a, 'tcx> ParameterEnvironment<'tcx> {
                     }
                     hir::ItemEnum(..) |
                     hir::ItemStruct(..) |
+                    hir::ItemUnion(..) |
                     hir::ItemTy(..) |
                     hir::ItemImpl(..) |
                     hir::ItemConst(..) |
@@ -1461,6 +1462,7 @@ bitflags! {
         const IS_SIMD             = 1 << 4,
         const IS_FUNDAMENTAL      = 1 << 5,
         const IS_NO_DROP_FLAG     = 1 << 6,
+        const IS_UNION            = 1 << 7,
     }
 }
 
@@ -1496,7 +1498,7 @@ pub struct FieldDefData<'tcx, 'container: 'tcx> {
     ty: ivar::TyIVar<'tcx, 'container>
 }
 
-/// The definition of an abstract data type - a struct or enum.
+/// The definition of an abstract data type - a struct, union or enum.
 ///
 /// These are all interned (by intern_adt_def) into the adt_defs
 /// table.
@@ -1553,7 +1555,7 @@ impl<'tcx> Decodable for AdtDef<'tcx> {
 
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
-pub enum AdtKind { Struct, Enum }
+pub enum AdtKind { Struct, Union, Enum }
 
 #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, RustcEncodable, RustcDecodable)]
 pub enum VariantKind { Struct, Tuple, Unit }
@@ -1587,8 +1589,11 @@ impl<'a, 'gcx, 'tcx, 'container> AdtDefData<'gcx, 'container> {
         if Some(did) == tcx.lang_items.phantom_data() {
             flags = flags | AdtFlags::IS_PHANTOM_DATA;
         }
-        if let AdtKind::Enum = kind {
-            flags = flags | AdtFlags::IS_ENUM;
+        match kind {
+            AdtKind::Enum => flags = flags | AdtFlags::IS_ENUM,
+            // Unions have no room for a drop flag.
+            AdtKind::Union => flags = flags | AdtFlags::IS_UNION | AdtFlags::IS_NO_DROP_FLAG,
+            AdtKind::Struct => {}
         }
         AdtDefData {
             did: did,
@@ -1606,16 +1611,25 @@ impl<'a, 'gcx, 'tcx, 'container> AdtDefData<'gcx, 'container> {
         self.flags.set(self.flags.get() | AdtFlags::IS_DTORCK_VALID)
     }
 
-    /// Returns the kind of the ADT - Struct or Enum.
+    /// Returns the kind of the ADT - Struct, Union or Enum.
     #[inline]
     pub fn adt_kind(&self) -> AdtKind {
         if self.flags.get().intersects(AdtFlags::IS_ENUM) {
             AdtKind::Enum
+        } else if self.flags.get().intersects(AdtFlags::IS_UNION) {
+            AdtKind::Union
         } else {
             AdtKind::Struct
         }
     }
 
+    /// Returns true if this is an untagged union, all of whose fields
+    /// share the same storage.
+    #[inline]
+    pub fn is_union(&self) -> bool {
+        self.flags.get().intersects(AdtFlags::IS_UNION)
+    }
+
     /// Returns whether this is a dtorck type. If this returns
     /// true, this type being safe for destruction requires it to be
     /// alive; Otherwise, only the contents are required to be.
@@ -1653,10 +1667,10 @@ impl<'a, 'gcx, 'tcx, 'container> AdtDefData<'gcx, 'container> {
         }
     }
 
-    /// Asserts this is a struct and returns the struct's unique
+    /// Asserts this is a struct or union and returns its unique
     /// variant.
     pub fn struct_variant(&self) -> &VariantDefData<'gcx, 'container> {
-        assert_eq!(self.adt_kind(), AdtKind::Struct);
+        assert!(self.adt_kind() != AdtKind::Enum);
         &self.variants[0]
     }
 
@@ -1880,7 +1894,7 @@ impl<'a, 'tcx> AdtDefData<'tcx, 'tcx> {
                 }
             }
 
-            TyProjection(..) => {
+            TyProjection(..) | TyAnon(..) => {
                 // must calculate explicitly.
                 // FIXME: consider special-casing always-Sized projections
                 vec![ty]
@@ -2604,6 +2618,24 @@ impl<'a, 'gcx, 'tcx> TyCtxt<'a, 'gcx, 'tcx> {
         self.lookup_repr_hints(did).contains(&attr::ReprPacked)
     }
 
+    /// Obtain the alignment, in bytes, requested with `#[repr(align(N))]`
+    pub fn lookup_repr_align(self, did: DefId) -> Option<u64> {
+        self.lookup_repr_hints(did).iter().filter_map(|hint| {
+            match *hint {
+                attr::ReprAlign(align) => Some(align),
+                _ => None
+            }
+        }).max()
+    }
+
+    /// Obtain the representation hint which determines the discriminant
+    /// of an enum, ignoring `#[repr(align(N))]`.
+    pub fn lookup_enum_repr_hint(self, did: DefId) -> attr::ReprAttr {
+        self.lookup_repr_hints(did).iter().cloned()
+            .find(|hint| !hint.is_align())
+            .unwrap_or(attr::ReprAny)
+    }
+
     /// Determine whether an item is annotated with `#[simd]`
     pub fn lookup_simd(self, did: DefId) -> bool {
         self.has_attr(did, "simd")
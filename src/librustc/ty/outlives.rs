@@ -183,6 +183,7 @@ impl<'a, 'gcx, 'tcx> InferCtxt<'a, 'gcx, 'tcx> {
             ty::TyFnDef(..) |       // OutlivesFunction (*)
             ty::TyFnPtr(_) |        // OutlivesFunction (*)
             ty::TyTrait(..) |       // OutlivesObject, OutlivesFragment (*)
+            ty::TyAnon(..) |        // OutlivesNominalType (ish)
             ty::TyError => {
                 // (*) Bare functions and traits are both binders. In the
                 // RFC, this means we would add the bound regions to the
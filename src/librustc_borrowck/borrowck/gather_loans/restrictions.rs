@@ -99,8 +99,32 @@ impl<'a, 'tcx> RestrictionsContext<'a, 'tcx> {
                     Categorization::Downcast(_, variant_id) => Some(variant_id),
                     _ => None
                 };
+                let union_def = match cmt_base.ty.sty {
+                    ty::TyStruct(def, substs) if def.is_union() => Some((def, substs)),
+                    _ => None
+                };
                 let result = self.restrict(cmt_base);
-                self.extend(result, &cmt, LpInterior(opt_variant_id, i.cleaned()))
+                let interior = i.cleaned();
+                match (union_def, result) {
+                    (Some((def, substs)), RestrictionResult::SafeIf(base_lp, mut base_vec)) => {
+                        // All fields of a union share the same memory, so
+                        // borrowing one of them restricts all of them.
+                        for field in &def.struct_variant().fields {
+                            let sibling = InteriorKind::InteriorField(mc::NamedField(field.name));
+                            if sibling != interior {
+                                let v = LpExtend(base_lp.clone(), cmt.mutbl,
+                                                 LpInterior(opt_variant_id, sibling));
+                                let field_ty = field.ty(self.bccx.tcx, substs);
+                                base_vec.push(Rc::new(LoanPath::new(v, field_ty)));
+                            }
+                        }
+                        let result = RestrictionResult::SafeIf(base_lp, base_vec);
+                        self.extend(result, &cmt, LpInterior(opt_variant_id, interior))
+                    }
+                    (_, result) => {
+                        self.extend(result, &cmt, LpInterior(opt_variant_id, interior))
+                    }
+                }
             }
 
             Categorization::StaticItem => {
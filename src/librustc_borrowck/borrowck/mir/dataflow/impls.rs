@@ -12,6 +12,7 @@ use rustc::ty::TyCtxt;
 use rustc::mir::repr::{self, Mir};
 use rustc_data_structures::indexed_vec::Idx;
 
+use super::super::gather_borrows::{BorrowEffect, BorrowIndex, BorrowSet};
 use super::super::gather_moves::{Location};
 use super::super::gather_moves::{MoveOutIndex, MovePathIndex};
 use super::super::MoveDataParamEnv;
@@ -193,6 +194,24 @@ pub struct MovingOutStatements<'a, 'tcx: 'a> {
     mir: &'a Mir<'tcx>,
 }
 
+/// `Borrows` tracks the borrows (`&lvalue`) that may be in scope upon
+/// reaching a particular point in the control flow of a function.
+///
+/// A borrow enters scope at the `Rvalue::Ref` that creates it, and
+/// leaves scope only when the sole local holding the resulting
+/// reference is overwritten. Whether the borrow is actually still
+/// *needed* at some point is a separate question, answered by the
+/// liveness of the locals that may hold it; see `gather_borrows`.
+pub struct Borrows<'a, 'tcx: 'a> {
+    mir: &'a Mir<'tcx>,
+}
+
+impl<'a, 'tcx: 'a> Borrows<'a, 'tcx> {
+    pub fn new(mir: &'a Mir<'tcx>) -> Self {
+        Borrows { mir: mir }
+    }
+}
+
 impl<'a, 'tcx> MaybeInitializedLvals<'a, 'tcx> {
     fn update_bits(sets: &mut BlockSets<MovePathIndex>, path: MovePathIndex,
                    state: DropFlagState)
@@ -500,6 +519,58 @@ impl<'a, 'tcx> BitDenotation for MovingOutStatements<'a, 'tcx> {
     }
 }
 
+impl<'a, 'tcx> BitDenotation for Borrows<'a, 'tcx> {
+    type Idx = BorrowIndex;
+    type Ctxt = BorrowSet<'tcx>;
+    fn name() -> &'static str { "borrows" }
+    fn bits_per_block(&self, ctxt: &Self::Ctxt) -> usize {
+        ctxt.len()
+    }
+
+    fn start_block_effect(&self, _ctxt: &Self::Ctxt, _sets: &mut BlockSets<BorrowIndex>) {
+        // no borrows have been created prior to function execution,
+        // so this method has no effect on `_sets`.
+    }
+
+    fn statement_effect(&self,
+                        ctxt: &Self::Ctxt,
+                        sets: &mut BlockSets<BorrowIndex>,
+                        bb: repr::BasicBlock,
+                        idx: usize) {
+        ctxt.effects_for_location(self.mir, Location { block: bb, index: idx }, |borrow, e| {
+            match e {
+                BorrowEffect::Gen => sets.gen(&borrow),
+                BorrowEffect::Kill => sets.kill(&borrow),
+            }
+        });
+    }
+
+    fn terminator_effect(&self,
+                         ctxt: &Self::Ctxt,
+                         sets: &mut BlockSets<BorrowIndex>,
+                         bb: repr::BasicBlock,
+                         statements_len: usize) {
+        ctxt.effects_for_location(self.mir,
+                                  Location { block: bb, index: statements_len },
+                                  |borrow, e| {
+            match e {
+                BorrowEffect::Gen => sets.gen(&borrow),
+                BorrowEffect::Kill => sets.kill(&borrow),
+            }
+        });
+    }
+
+    fn propagate_call_return(&self,
+                             _ctxt: &Self::Ctxt,
+                             _in_out: &mut IdxSet<BorrowIndex>,
+                             _call_bb: repr::BasicBlock,
+                             _dest_bb: repr::BasicBlock,
+                             _dest_lval: &repr::Lvalue) {
+        // overwriting the call destination is already accounted for
+        // by `terminator_effect`.
+    }
+}
+
 fn zero_to_one(bitvec: &mut [usize], move_index: MoveOutIndex) {
     let retval = bitvec.set_bit(move_index.index());
     assert!(retval);
@@ -512,6 +583,13 @@ impl<'a, 'tcx> BitwiseOperator for MovingOutStatements<'a, 'tcx> {
     }
 }
 
+impl<'a, 'tcx> BitwiseOperator for Borrows<'a, 'tcx> {
+    #[inline]
+    fn join(&self, pred1: usize, pred2: usize) -> usize {
+        pred1 | pred2 // borrows from either pred may be in scope
+    }
+}
+
 impl<'a, 'tcx> BitwiseOperator for MaybeInitializedLvals<'a, 'tcx> {
     #[inline]
     fn join(&self, pred1: usize, pred2: usize) -> usize {
@@ -550,6 +628,13 @@ impl<'a, 'tcx> DataflowOperator for MovingOutStatements<'a, 'tcx> {
     }
 }
 
+impl<'a, 'tcx> DataflowOperator for Borrows<'a, 'tcx> {
+    #[inline]
+    fn bottom_value() -> bool {
+        false // bottom = no borrows in scope by default
+    }
+}
+
 impl<'a, 'tcx> DataflowOperator for MaybeInitializedLvals<'a, 'tcx> {
     #[inline]
     fn bottom_value() -> bool {
@@ -28,6 +28,7 @@ use indexed_set::{IdxSet, IdxSetBuf};
 pub use self::sanity_check::sanity_check_via_rustc_peek;
 pub use self::impls::{MaybeInitializedLvals, MaybeUninitializedLvals};
 pub use self::impls::{DefinitelyInitializedLvals, MovingOutStatements};
+pub use self::impls::Borrows;
 
 mod graphviz;
 mod sanity_check;
@@ -199,6 +200,19 @@ impl<'a, 'tcx: 'a, O> DataflowAnalysis<'a, 'tcx, O>
     pub fn mir(&self) -> &'a Mir<'tcx> { self.mir }
 }
 
+impl<'a, 'tcx: 'a, BD> DataflowAnalysis<'a, 'tcx, BD>
+    where BD: BitDenotation + DataflowOperator
+{
+    /// Runs the analysis to a fixed point, without any of the graphviz
+    /// instrumentation offered by `Dataflow::dataflow` (which is tied
+    /// to analyses over move paths).
+    pub fn compute(mut self) -> DataflowResults<BD> {
+        self.build_sets();
+        self.propagate();
+        self.results()
+    }
+}
+
 pub struct DataflowResults<O>(DataflowState<O>) where O: BitDenotation;
 
 impl<O: BitDenotation> DataflowResults<O> {
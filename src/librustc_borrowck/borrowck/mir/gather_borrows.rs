@@ -0,0 +1,282 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Gathers the borrows (`Rvalue::Ref`) performed by a MIR body, for
+//! use by the `Borrows` dataflow analysis and the MIR borrow checker.
+//!
+//! Besides the borrows themselves, we compute (flow-insensitively) the
+//! set of locals that may end up holding a reference derived from each
+//! borrow. A borrow is considered to still be in use at some point if
+//! any of those "holders" is live there; this is what lets the MIR
+//! borrow checker accept programs where a borrow is simply not used
+//! after a given point, regardless of lexical scopes.
+
+use rustc::mir::repr::*;
+use rustc::mir::visit::{LvalueContext, Visitor};
+use rustc::ty::Region;
+use rustc::util::nodemap::FnvHashMap;
+use rustc_data_structures::indexed_vec::{Idx, IndexVec};
+use syntax_pos::Span;
+
+use indexed_set::IdxSetBuf;
+
+use super::gather_moves::Location;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub struct BorrowIndex(usize);
+
+impl Idx for BorrowIndex {
+    fn new(idx: usize) -> Self { BorrowIndex(idx) }
+    fn index(self) -> usize { self.0 }
+}
+
+/// A single `&lvalue` (or `&mut lvalue`) appearing in the MIR.
+#[derive(Debug)]
+pub struct BorrowData<'tcx> {
+    /// where the borrow is created
+    pub location: Location,
+    pub kind: BorrowKind,
+    pub region: Region,
+    /// the lvalue being borrowed
+    pub borrowed_lvalue: Lvalue<'tcx>,
+    /// the lvalue the resulting reference is stored into
+    pub assigned_lvalue: Lvalue<'tcx>,
+    pub span: Span,
+}
+
+#[derive(Debug)]
+pub struct BorrowSet<'tcx> {
+    pub borrows: IndexVec<BorrowIndex, BorrowData<'tcx>>,
+
+    /// Maps the location of each `Rvalue::Ref` to its borrow.
+    pub location_map: FnvHashMap<Location, BorrowIndex>,
+
+    /// For each borrow, the locals that may hold a reference derived
+    /// from it (including the local it is initially assigned to).
+    pub holders: IndexVec<BorrowIndex, IdxSetBuf<Local>>,
+
+    /// Maps a local to the borrows that are only ever held by that
+    /// local; overwriting the local wholesale ends those borrows.
+    pub killed_by: FnvHashMap<Local, Vec<BorrowIndex>>,
+}
+
+impl<'tcx> BorrowSet<'tcx> {
+    pub fn len(&self) -> usize { self.borrows.len() }
+
+    pub fn gather(mir: &Mir<'tcx>) -> Self {
+        let mut gatherer = GatherBorrows {
+            mir: mir,
+            borrows: IndexVec::new(),
+            location_map: FnvHashMap(),
+            flows: vec![],
+        };
+
+        for (bb, data) in mir.basic_blocks().iter_enumerated() {
+            for (i, stmt) in data.statements.iter().enumerate() {
+                let location = Location { block: bb, index: i };
+                gatherer.gather_statement(location, stmt);
+            }
+            gatherer.gather_terminator(data.terminator());
+        }
+
+        let GatherBorrows { mir, borrows, location_map, flows } = gatherer;
+        let num_locals = mir.count_locals();
+
+        let holders: IndexVec<BorrowIndex, IdxSetBuf<Local>> = borrows.iter().map(|borrow| {
+            let mut holders = IdxSetBuf::new_empty(num_locals);
+            if let Some(local) = base_local(mir, &borrow.assigned_lvalue) {
+                holders.add(&local);
+            }
+            // Propagate along the value flow edges until we reach a
+            // fixed point. The graphs here are small enough that the
+            // naive iteration is fine.
+            let mut changed = true;
+            while changed {
+                changed = false;
+                for &(source, target) in &flows {
+                    if holders.contains(&source) && holders.add(&target) {
+                        changed = true;
+                    }
+                }
+            }
+            holders
+        }).collect();
+
+        let mut killed_by = FnvHashMap();
+        for (index, borrow) in borrows.iter_enumerated() {
+            if let Some(local) = mir.local_index(&borrow.assigned_lvalue) {
+                let sole_holder = (0..num_locals).map(Local::new).all(|l| {
+                    l == local || !holders[index].contains(&l)
+                });
+                if sole_holder {
+                    killed_by.entry(local).or_insert(vec![]).push(index);
+                }
+            }
+        }
+
+        BorrowSet {
+            borrows: borrows,
+            location_map: location_map,
+            holders: holders,
+            killed_by: killed_by,
+        }
+    }
+}
+
+/// Returns the local at the root of `lvalue`, if any (statics have none).
+pub fn base_local<'tcx>(mir: &Mir<'tcx>, lvalue: &Lvalue<'tcx>) -> Option<Local> {
+    match *lvalue {
+        Lvalue::Projection(ref proj) => base_local(mir, &proj.base),
+        _ => mir.local_index(lvalue),
+    }
+}
+
+struct GatherBorrows<'a, 'tcx: 'a> {
+    mir: &'a Mir<'tcx>,
+    borrows: IndexVec<BorrowIndex, BorrowData<'tcx>>,
+    location_map: FnvHashMap<Location, BorrowIndex>,
+    /// `(source, target)` pairs: a reference held in `source` may be
+    /// copied (or reborrowed) into `target`.
+    flows: Vec<(Local, Local)>,
+}
+
+impl<'a, 'tcx> GatherBorrows<'a, 'tcx> {
+    fn gather_statement(&mut self, location: Location, stmt: &Statement<'tcx>) {
+        match stmt.kind {
+            StatementKind::Assign(ref lvalue, ref rvalue) => {
+                if let Rvalue::Ref(region, kind, ref borrowed_lvalue) = *rvalue {
+                    let index = self.borrows.push(BorrowData {
+                        location: location,
+                        kind: kind,
+                        region: region,
+                        borrowed_lvalue: borrowed_lvalue.clone(),
+                        assigned_lvalue: lvalue.clone(),
+                        span: stmt.source_info.span,
+                    });
+                    self.location_map.insert(location, index);
+                }
+
+                match *rvalue {
+                    // These can only produce scalars, which cannot
+                    // carry a reference along.
+                    Rvalue::Len(..) |
+                    Rvalue::BinaryOp(..) |
+                    Rvalue::CheckedBinaryOp(..) |
+                    Rvalue::UnaryOp(..) |
+                    Rvalue::Box(..) => {}
+                    _ => {
+                        let sources = LocalsMentioned::collect(self.mir, |v| {
+                            v.visit_rvalue(rvalue)
+                        });
+                        self.add_flows(&sources, lvalue);
+                    }
+                }
+            }
+        }
+    }
+
+    fn gather_terminator(&mut self, term: &Terminator<'tcx>) {
+        match term.kind {
+            TerminatorKind::Call { ref func, ref args, destination: Some((ref dest, _)), .. } => {
+                // Conservatively assume that the result of a call may
+                // hold on to any reference passed into it.
+                let sources = LocalsMentioned::collect(self.mir, |v| {
+                    v.visit_operand(func);
+                    for arg in args {
+                        v.visit_operand(arg);
+                    }
+                });
+                self.add_flows(&sources, dest);
+            }
+            TerminatorKind::DropAndReplace { ref location, ref value, .. } => {
+                let sources = LocalsMentioned::collect(self.mir, |v| v.visit_operand(value));
+                self.add_flows(&sources, location);
+            }
+            _ => {}
+        }
+    }
+
+    fn add_flows(&mut self, sources: &[Local], target: &Lvalue<'tcx>) {
+        if let Some(target) = base_local(self.mir, target) {
+            for &source in sources {
+                if source != target {
+                    self.flows.push((source, target));
+                }
+            }
+        }
+    }
+}
+
+/// Collects every local mentioned by some piece of MIR.
+struct LocalsMentioned<'a, 'tcx: 'a> {
+    mir: &'a Mir<'tcx>,
+    locals: Vec<Local>,
+}
+
+impl<'a, 'tcx> LocalsMentioned<'a, 'tcx> {
+    fn collect<F>(mir: &'a Mir<'tcx>, f: F) -> Vec<Local>
+        where F: FnOnce(&mut Self)
+    {
+        let mut visitor = LocalsMentioned { mir: mir, locals: vec![] };
+        f(&mut visitor);
+        visitor.locals
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for LocalsMentioned<'a, 'tcx> {
+    fn visit_lvalue(&mut self, lvalue: &Lvalue<'tcx>, context: LvalueContext) {
+        if let Some(local) = self.mir.local_index(lvalue) {
+            self.locals.push(local);
+        }
+        self.super_lvalue(lvalue, context);
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BorrowEffect {
+    /// the borrow is created here
+    Gen,
+    /// the borrow can no longer be used past this point
+    Kill,
+}
+
+impl<'tcx> BorrowSet<'tcx> {
+    /// Calls `each` with the effects of executing the statement (or
+    /// terminator) at `loc` on the set of borrows in scope. Kills are
+    /// reported before gens, so that reborrowing into the same local
+    /// (e.g. in a loop) leaves the new borrow in scope.
+    pub fn effects_for_location<F>(&self, mir: &Mir<'tcx>, loc: Location, mut each: F)
+        where F: FnMut(BorrowIndex, BorrowEffect)
+    {
+        let block = &mir[loc.block];
+        let overwritten = match block.statements.get(loc.index) {
+            Some(stmt) => match stmt.kind {
+                StatementKind::Assign(ref lvalue, _) => Some(lvalue),
+            },
+            None => match block.terminator().kind {
+                TerminatorKind::Call { destination: Some((ref lvalue, _)), .. } |
+                TerminatorKind::DropAndReplace { location: ref lvalue, .. } => Some(lvalue),
+                _ => None,
+            },
+        };
+
+        if let Some(local) = overwritten.and_then(|lv| mir.local_index(lv)) {
+            if let Some(borrows) = self.killed_by.get(&local) {
+                for &borrow in borrows {
+                    each(borrow, BorrowEffect::Kill);
+                }
+            }
+        }
+
+        if let Some(&borrow) = self.location_map.get(&loc) {
+            each(borrow, BorrowEffect::Gen);
+        }
+    }
+}
@@ -200,7 +200,7 @@ impl fmt::Debug for MoveOut {
     }
 }
 
-#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Location {
     /// block where action is located
     pub block: BasicBlock,
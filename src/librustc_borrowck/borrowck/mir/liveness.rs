@@ -0,0 +1,164 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Liveness of MIR locals.
+//!
+//! A local is live at some point if its current value may be read
+//! later on. This is a backwards analysis, so rather than teaching
+//! the (forward-only) `dataflow` framework about direction, it is
+//! computed here by a straightforward fixed-point iteration over the
+//! basic blocks.
+//!
+//! Only whole-local assignments count as definitions; writing into a
+//! projection such as `x.f = ...` or `*x = ...` counts as a use of
+//! `x`, since the remainder of `x` (or the pointer itself) is still
+//! needed afterwards.
+
+use rustc::mir::repr::*;
+use rustc::mir::visit::{LvalueContext, Visitor};
+use rustc_data_structures::indexed_vec::{Idx, IndexVec};
+
+use indexed_set::IdxSetBuf;
+
+pub struct LocalLiveness {
+    num_locals: usize,
+    /// locals live on exit from each basic block
+    live_out: IndexVec<BasicBlock, IdxSetBuf<Local>>,
+}
+
+/// The locals defined and used by some piece of MIR.
+struct DefsUses<'a, 'tcx: 'a> {
+    mir: &'a Mir<'tcx>,
+    defs: IdxSetBuf<Local>,
+    uses: IdxSetBuf<Local>,
+}
+
+impl<'a, 'tcx> DefsUses<'a, 'tcx> {
+    fn new(mir: &'a Mir<'tcx>) -> Self {
+        DefsUses {
+            mir: mir,
+            defs: IdxSetBuf::new_empty(mir.count_locals()),
+            uses: IdxSetBuf::new_empty(mir.count_locals()),
+        }
+    }
+
+    /// Applies the effect of this piece of MIR to `live`, turning the
+    /// set of locals live after it into the set live before it.
+    fn apply(&self, live: &mut IdxSetBuf<Local>) {
+        live.subtract(&self.defs);
+        live.union(&self.uses);
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for DefsUses<'a, 'tcx> {
+    fn visit_lvalue(&mut self, lvalue: &Lvalue<'tcx>, context: LvalueContext) {
+        if let Some(local) = self.mir.local_index(lvalue) {
+            match context {
+                LvalueContext::Store |
+                LvalueContext::Call => { self.defs.add(&local); }
+                _ => { self.uses.add(&local); }
+            }
+        }
+        self.super_lvalue(lvalue, context);
+    }
+}
+
+fn statement_defs_uses<'a, 'tcx>(mir: &'a Mir<'tcx>,
+                                  bb: BasicBlock,
+                                  stmt: &Statement<'tcx>)
+                                  -> DefsUses<'a, 'tcx> {
+    let mut du = DefsUses::new(mir);
+    du.visit_statement(bb, stmt);
+    du
+}
+
+fn terminator_defs_uses<'a, 'tcx>(mir: &'a Mir<'tcx>,
+                                   bb: BasicBlock,
+                                   term: &Terminator<'tcx>)
+                                   -> DefsUses<'a, 'tcx> {
+    let mut du = DefsUses::new(mir);
+    du.visit_terminator(bb, term);
+    if let TerminatorKind::Return = term.kind {
+        du.uses.add(&mir.local_index(&Lvalue::ReturnPointer).unwrap());
+    }
+    du
+}
+
+impl LocalLiveness {
+    pub fn compute<'tcx>(mir: &Mir<'tcx>) -> Self {
+        let num_locals = mir.count_locals();
+
+        // Summarize each block by the locals it defines before any
+        // use, and the locals it uses before any definition.
+        let summaries: IndexVec<BasicBlock, (IdxSetBuf<Local>, IdxSetBuf<Local>)> =
+            mir.basic_blocks().iter_enumerated().map(|(bb, data)| {
+                let term = terminator_defs_uses(mir, bb, data.terminator());
+                let (mut defs, mut uses) = (term.defs, term.uses);
+                for stmt in data.statements.iter().rev() {
+                    let du = statement_defs_uses(mir, bb, stmt);
+                    uses.subtract(&du.defs);
+                    uses.union(&du.uses);
+                    defs.union(&du.defs);
+                }
+                (defs, uses)
+            }).collect();
+
+        let mut live_out = IndexVec::from_elem_n(IdxSetBuf::new_empty(num_locals),
+                                                 mir.basic_blocks().len());
+        let mut live_in = live_out.clone();
+
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for bb in mir.basic_blocks().indices().rev() {
+                let mut out = IdxSetBuf::new_empty(num_locals);
+                for &succ in mir[bb].terminator().successors().iter() {
+                    out.union(&live_in[succ]);
+                }
+
+                let (ref defs, ref uses) = summaries[bb];
+                let mut in_ = out.clone();
+                in_.subtract(defs);
+                in_.union(uses);
+
+                if in_.words() != live_in[bb].words() {
+                    live_in[bb] = in_;
+                    changed = true;
+                }
+                live_out[bb] = out;
+            }
+        }
+
+        LocalLiveness {
+            num_locals: num_locals,
+            live_out: live_out,
+        }
+    }
+
+    /// Returns, for each statement of `bb` and then its terminator,
+    /// the set of locals that are live immediately *after* it.
+    pub fn live_after_each<'tcx>(&self, mir: &Mir<'tcx>, bb: BasicBlock)
+                                 -> Vec<IdxSetBuf<Local>> {
+        let data = &mir[bb];
+        let mut result = vec![IdxSetBuf::new_empty(self.num_locals);
+                              data.statements.len() + 1];
+
+        let mut live = self.live_out[bb].clone();
+        result[data.statements.len()].clone_from(&live);
+        terminator_defs_uses(mir, bb, data.terminator()).apply(&mut live);
+
+        for (i, stmt) in data.statements.iter().enumerate().rev() {
+            result[i].clone_from(&live);
+            statement_defs_uses(mir, bb, stmt).apply(&mut live);
+        }
+
+        result
+    }
+}
@@ -12,6 +12,7 @@ use borrowck::BorrowckCtxt;
 
 use syntax::ast::{self, MetaItem};
 use syntax::attr::AttrMetaMethods;
+use syntax::parse::token::keywords;
 use syntax::ptr::P;
 use syntax_pos::{Span, DUMMY_SP};
 
@@ -19,14 +20,21 @@ use rustc::hir;
 use rustc::hir::intravisit::{FnKind};
 
 use rustc::mir::repr;
-use rustc::mir::repr::{BasicBlock, BasicBlockData, Mir, Statement, Terminator};
+use rustc::mir::repr::{BasicBlock, BasicBlockData, BorrowKind, Local, Lvalue, Mir};
+use rustc::mir::repr::{ProjectionElem, Statement, Terminator};
+use rustc::mir::tcx::LvalueTy;
+use rustc::mir::visit::{LvalueContext, Visitor};
 use rustc::session::Session;
 use rustc::ty::{self, TyCtxt};
 
+use indexed_set::IdxSet;
+
 mod abs_domain;
 pub mod elaborate_drops;
 mod dataflow;
+mod gather_borrows;
 mod gather_moves;
+mod liveness;
 mod patch;
 // mod graphviz;
 
@@ -34,9 +42,11 @@ use self::dataflow::{BitDenotation};
 use self::dataflow::{DataflowOperator};
 use self::dataflow::{Dataflow, DataflowAnalysis, DataflowResults};
 use self::dataflow::{MaybeInitializedLvals, MaybeUninitializedLvals};
-use self::dataflow::{DefinitelyInitializedLvals};
+use self::dataflow::{DefinitelyInitializedLvals, Borrows};
+use self::gather_borrows::{BorrowEffect, BorrowIndex, BorrowSet};
 use self::gather_moves::{MoveData, MovePathIndex, Location};
 use self::gather_moves::{MovePathContent, MovePathData};
+use self::liveness::LocalLiveness;
 
 fn has_rustc_mir_with(attrs: &[ast::Attribute], name: &str) -> Option<P<MetaItem>> {
     for attr in attrs {
@@ -102,13 +112,22 @@ pub fn borrowck_mir<'a, 'tcx: 'a>(
         bcx.tcx.sess.fatal("stop_after_dataflow ended compilation");
     }
 
+    let borrows = BorrowSet::gather(mir);
+    let flow_borrows =
+        DataflowAnalysis::new(tcx, mir, &borrows, Borrows::new(mir)).compute();
+    let liveness = LocalLiveness::compute(mir);
+
     let mut mbcx = MirBorrowckCtxt {
         bcx: bcx,
         mir: mir,
         node_id: id,
         move_data: mdpe.move_data,
+        param_env: mdpe.param_env,
         flow_inits: flow_inits,
         flow_uninits: flow_uninits,
+        borrows: &borrows,
+        flow_borrows: flow_borrows,
+        liveness: liveness,
     };
 
     for bb in mir.basic_blocks().indices() {
@@ -174,28 +193,351 @@ pub struct MirBorrowckCtxt<'b, 'a: 'b, 'tcx: 'a> {
     mir: &'b Mir<'tcx>,
     node_id: ast::NodeId,
     move_data: MoveData<'tcx>,
+    param_env: ty::ParameterEnvironment<'tcx>,
     flow_inits: DataflowResults<MaybeInitializedLvals<'a, 'tcx>>,
-    flow_uninits: DataflowResults<MaybeUninitializedLvals<'a, 'tcx>>
+    flow_uninits: DataflowResults<MaybeUninitializedLvals<'a, 'tcx>>,
+    borrows: &'b BorrowSet<'tcx>,
+    flow_borrows: DataflowResults<Borrows<'b, 'tcx>>,
+    liveness: LocalLiveness,
+}
+
+/// The ways in which a statement or terminator can access an lvalue,
+/// as far as conflicts with outstanding borrows are concerned.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum AccessKind {
+    Read,
+    Move,
+    Write,
+    Drop,
+    Borrow(BorrowKind),
+}
+
+/// Collects the lvalues accessed by a statement or terminator, along
+/// with the context in which they are accessed.
+struct Accesses<'tcx> {
+    accesses: Vec<(Lvalue<'tcx>, LvalueContext)>,
+}
+
+impl<'tcx> Visitor<'tcx> for Accesses<'tcx> {
+    fn visit_lvalue(&mut self, lvalue: &Lvalue<'tcx>, context: LvalueContext) {
+        self.accesses.push((lvalue.clone(), context));
+
+        // The bases of a projection are covered by the access to the
+        // lvalue as a whole, but any index operands are read as well.
+        let mut lvalue = lvalue;
+        while let Lvalue::Projection(ref proj) = *lvalue {
+            if let ProjectionElem::Index(ref index) = proj.elem {
+                self.visit_operand(index);
+            }
+            lvalue = &proj.base;
+        }
+    }
 }
 
 impl<'b, 'a: 'b, 'tcx: 'a> MirBorrowckCtxt<'b, 'a, 'tcx> {
     fn process_basic_block(&mut self, bb: BasicBlock) {
+        let mut in_scope = self.flow_borrows.sets().on_entry_set_for(bb.index()).to_owned();
+        let live_after = self.liveness.live_after_each(self.mir, bb);
+
         let BasicBlockData { ref statements, ref terminator, is_cleanup: _ } =
             self.mir[bb];
-        for stmt in statements {
-            self.process_statement(bb, stmt);
+        for (i, stmt) in statements.iter().enumerate() {
+            let location = Location { block: bb, index: i };
+            self.process_statement(location, stmt, &in_scope, &live_after[i]);
+            self.apply_borrow_effects(location, &mut in_scope);
+        }
+
+        let location = Location { block: bb, index: statements.len() };
+        self.process_terminator(location, terminator, &in_scope, &live_after[statements.len()]);
+    }
+
+    fn process_statement(&mut self,
+                         location: Location,
+                         stmt: &Statement<'tcx>,
+                         in_scope: &IdxSet<BorrowIndex>,
+                         live_after: &IdxSet<Local>) {
+        debug!("MirBorrowckCtxt::process_statement({:?}, {:?}", location, stmt);
+        let mut accesses = Accesses { accesses: vec![] };
+        accesses.visit_statement(location.block, stmt);
+        self.check_accesses(location, stmt.source_info.span, accesses, in_scope, live_after);
+    }
+
+    fn process_terminator(&mut self,
+                          location: Location,
+                          term: &Option<Terminator<'tcx>>,
+                          in_scope: &IdxSet<BorrowIndex>,
+                          live_after: &IdxSet<Local>) {
+        debug!("MirBorrowckCtxt::process_terminator({:?}, {:?})", location, term);
+        if let Some(ref term) = *term {
+            let mut accesses = Accesses { accesses: vec![] };
+            accesses.visit_terminator(location.block, term);
+            self.check_accesses(location, term.source_info.span, accesses, in_scope, live_after);
+        }
+    }
+
+    fn apply_borrow_effects(&self, location: Location, in_scope: &mut IdxSet<BorrowIndex>) {
+        self.borrows.effects_for_location(self.mir, location, |borrow, effect| {
+            match effect {
+                BorrowEffect::Gen => { in_scope.add(&borrow); }
+                BorrowEffect::Kill => { in_scope.remove(&borrow); }
+            }
+        });
+    }
+
+    fn check_accesses(&mut self,
+                      location: Location,
+                      span: Span,
+                      accesses: Accesses<'tcx>,
+                      in_scope: &IdxSet<BorrowIndex>,
+                      live_after: &IdxSet<Local>) {
+        for (lvalue, context) in accesses.accesses {
+            let kind = match context {
+                LvalueContext::Store |
+                LvalueContext::Call => AccessKind::Write,
+                LvalueContext::Drop => AccessKind::Drop,
+                LvalueContext::Borrow { kind, .. } => AccessKind::Borrow(kind),
+                LvalueContext::Consume => {
+                    let ty = self.mir.lvalue_ty(self.bcx.tcx, &lvalue).to_ty(self.bcx.tcx);
+                    if ty.moves_by_default(self.bcx.tcx, &self.param_env, span) {
+                        AccessKind::Move
+                    } else {
+                        AccessKind::Read
+                    }
+                }
+                LvalueContext::Inspect |
+                LvalueContext::Slice { .. } |
+                LvalueContext::Projection => AccessKind::Read,
+            };
+
+            let conflicting = self.borrows.borrows.indices().find(|&index| {
+                in_scope.contains(&index) &&
+                    self.borrow_is_live(index, location, live_after) &&
+                    self.access_conflicts(&lvalue, kind, index)
+            });
+            if let Some(index) = conflicting {
+                self.report_conflict(span, &lvalue, kind, index);
+            }
+        }
+    }
+
+    /// A borrow is live after `location` if some local that may hold
+    /// a reference derived from it is live there.
+    fn borrow_is_live(&self,
+                      index: BorrowIndex,
+                      location: Location,
+                      live_after: &IdxSet<Local>) -> bool {
+        let borrow = &self.borrows.borrows[index];
+        // At the point where a borrow is (re-)created, e.g. in a loop,
+        // the local it is assigned to holds the *new* borrow afterwards,
+        // not the one from the previous iteration.
+        let mut holders = self.borrows.holders[index].clone();
+        if borrow.location == location {
+            if let Some(local) = gather_borrows::base_local(self.mir, &borrow.assigned_lvalue) {
+                holders.remove(&local);
+            }
+        }
+        holders.words().iter().zip(live_after.words()).any(|(h, l)| h & l != 0)
+    }
+
+    fn access_conflicts(&self, lvalue: &Lvalue<'tcx>, kind: AccessKind, index: BorrowIndex)
+                        -> bool {
+        let borrow = &self.borrows.borrows[index];
+        if !lvalues_overlap(lvalue, &borrow.borrowed_lvalue) {
+            return false;
+        }
+        match (kind, borrow.kind) {
+            (AccessKind::Read, BorrowKind::Shared) |
+            (AccessKind::Borrow(BorrowKind::Shared), BorrowKind::Shared) => false,
+            (AccessKind::Write, _) => !self.reseats_pointer(lvalue, &borrow.borrowed_lvalue),
+            _ => true,
         }
+    }
 
-        self.process_terminator(bb, terminator);
+    /// Overwriting a reference (or raw pointer) does not affect data
+    /// that was borrowed through it: `let r2 = &mut *r1; r1 = ...;` is
+    /// fine. Returns true if `assigned` is such a pointer, i.e. the
+    /// path from it to `borrowed` goes through a dereference of one.
+    fn reseats_pointer(&self, assigned: &Lvalue<'tcx>, borrowed: &Lvalue<'tcx>) -> bool {
+        let mut lvalue = borrowed;
+        while lvalue_depth(lvalue) > lvalue_depth(assigned) {
+            if let Lvalue::Projection(ref proj) = *lvalue {
+                if let ProjectionElem::Deref = proj.elem {
+                    let ty = self.mir.lvalue_ty(self.bcx.tcx, &proj.base).to_ty(self.bcx.tcx);
+                    match ty.sty {
+                        ty::TyRef(..) | ty::TyRawPtr(..) => return true,
+                        _ => {}
+                    }
+                }
+                lvalue = &proj.base;
+            }
+        }
+        false
     }
 
-    fn process_statement(&mut self, bb: BasicBlock, stmt: &Statement<'tcx>) {
-        debug!("MirBorrowckCtxt::process_statement({:?}, {:?}", bb, stmt);
+    fn report_conflict(&self,
+                       span: Span,
+                       lvalue: &Lvalue<'tcx>,
+                       kind: AccessKind,
+                       index: BorrowIndex) {
+        let borrow = &self.borrows.borrows[index];
+        let desc = self.describe_lvalue(lvalue);
+        let borrowed_desc = self.describe_lvalue(&borrow.borrowed_lvalue);
+        let borrow_str = |kind| match kind {
+            BorrowKind::Shared => "immutable",
+            BorrowKind::Unique | BorrowKind::Mut => "mutable",
+        };
+
+        let mut err = match kind {
+            AccessKind::Borrow(_) if borrow.kind == BorrowKind::Shared => {
+                let mut err = struct_span_err!(self.bcx, span, E0502,
+                                               "cannot borrow `{}` as mutable because it is \
+                                                also borrowed as immutable (Mir)",
+                                               desc);
+                err.span_label(span, &format!("mutable borrow occurs here"));
+                err
+            }
+            AccessKind::Borrow(BorrowKind::Shared) => {
+                let mut err = struct_span_err!(self.bcx, span, E0502,
+                                               "cannot borrow `{}` as immutable because it is \
+                                                also borrowed as mutable (Mir)",
+                                               desc);
+                err.span_label(span, &format!("immutable borrow occurs here"));
+                err
+            }
+            AccessKind::Borrow(_) => {
+                let mut err = struct_span_err!(self.bcx, span, E0499,
+                                               "cannot borrow `{}` as mutable more than once \
+                                                at a time (Mir)",
+                                               desc);
+                err.span_label(span, &format!("second mutable borrow occurs here"));
+                err
+            }
+            AccessKind::Read => {
+                let mut err = struct_span_err!(self.bcx, span, E0503,
+                                               "cannot use `{}` because it was mutably \
+                                                borrowed (Mir)",
+                                               desc);
+                err.span_label(span, &format!("use of borrowed `{}`", borrowed_desc));
+                err
+            }
+            AccessKind::Move => {
+                let mut err = struct_span_err!(self.bcx, span, E0505,
+                                               "cannot move out of `{}` because it is \
+                                                borrowed (Mir)",
+                                               desc);
+                err.span_label(span, &format!("move out of `{}` occurs here", desc));
+                err
+            }
+            AccessKind::Write => {
+                let mut err = struct_span_err!(self.bcx, span, E0506,
+                                               "cannot assign to `{}` because it is \
+                                                borrowed (Mir)",
+                                               desc);
+                err.span_label(span, &format!("assignment to borrowed `{}` occurs here", desc));
+                err
+            }
+            AccessKind::Drop => {
+                let mut err = self.bcx.struct_span_err(
+                    span, &format!("`{}` dropped here while still borrowed (Mir)", desc));
+                err.span_label(span, &format!("`{}` dropped here", desc));
+                err
+            }
+        };
+        err.span_label(borrow.span,
+                       &format!("{} borrow of `{}` occurs here",
+                                borrow_str(borrow.kind), borrowed_desc));
+        err.emit();
     }
 
-    fn process_terminator(&mut self, bb: BasicBlock, term: &Option<Terminator<'tcx>>) {
-        debug!("MirBorrowckCtxt::process_terminator({:?}, {:?})", bb, term);
+    fn describe_lvalue(&self, lvalue: &Lvalue<'tcx>) -> String {
+        match *lvalue {
+            Lvalue::Var(var) => self.mir.var_decls[var].name.to_string(),
+            Lvalue::Arg(arg) => {
+                let name = self.mir.arg_decls[arg].debug_name;
+                if name == keywords::Invalid.name() {
+                    format!("{:?}", arg)
+                } else {
+                    name.to_string()
+                }
+            }
+            Lvalue::Temp(temp) => format!("{:?}", temp),
+            Lvalue::Static(def_id) => self.bcx.tcx.item_path_str(def_id),
+            Lvalue::ReturnPointer => format!("return value"),
+            Lvalue::Projection(ref proj) => {
+                let base = self.describe_lvalue(&proj.base);
+                match proj.elem {
+                    ProjectionElem::Deref => format!("*{}", base),
+                    ProjectionElem::Field(field, _) => {
+                        let variant = match self.mir.lvalue_ty(self.bcx.tcx, &proj.base) {
+                            LvalueTy::Ty { ty } => match ty.sty {
+                                ty::TyStruct(def, _) => Some(def.struct_variant()),
+                                _ => None,
+                            },
+                            LvalueTy::Downcast { adt_def, variant_index, .. } => {
+                                Some(&adt_def.variants[variant_index])
+                            }
+                        };
+                        match variant {
+                            Some(variant) => {
+                                format!("{}.{}", base, variant.fields[field.index()].name)
+                            }
+                            None => format!("{}.{}", base, field.index()),
+                        }
+                    }
+                    ProjectionElem::Downcast(..) => base,
+                    ProjectionElem::Index(_) |
+                    ProjectionElem::ConstantIndex { .. } |
+                    ProjectionElem::Subslice { .. } => format!("{}[..]", base),
+                }
+            }
+        }
+    }
+}
+
+/// Number of projections applied on top of the base of `lvalue`.
+fn lvalue_depth(lvalue: &Lvalue) -> usize {
+    match *lvalue {
+        Lvalue::Projection(ref proj) => 1 + lvalue_depth(&proj.base),
+        _ => 0,
+    }
+}
+
+/// Returns true if `a` and `b` may refer to overlapping memory, that
+/// is, if they have the same base and one's projections are a prefix
+/// of the other's (modulo distinct fields, variants and constant
+/// indices, which are known to be disjoint).
+fn lvalues_overlap<'tcx>(a: &Lvalue<'tcx>, b: &Lvalue<'tcx>) -> bool {
+    fn components<'a, 'tcx>(lvalue: &'a Lvalue<'tcx>,
+                            elems: &mut Vec<&'a repr::LvalueElem<'tcx>>)
+                            -> &'a Lvalue<'tcx> {
+        match *lvalue {
+            Lvalue::Projection(ref proj) => {
+                let base = components(&proj.base, elems);
+                elems.push(&proj.elem);
+                base
+            }
+            _ => lvalue,
+        }
+    }
+
+    let (mut a_elems, mut b_elems) = (vec![], vec![]);
+    if components(a, &mut a_elems) != components(b, &mut b_elems) {
+        return false;
+    }
+
+    for (a_elem, b_elem) in a_elems.iter().zip(&b_elems) {
+        match (*a_elem, *b_elem) {
+            (&ProjectionElem::Field(a_field, _), &ProjectionElem::Field(b_field, _))
+                if a_field != b_field => return false,
+            (&ProjectionElem::Downcast(_, a_variant), &ProjectionElem::Downcast(_, b_variant))
+                if a_variant != b_variant => return false,
+            (&ProjectionElem::ConstantIndex { offset: a_offset, from_end: a_from_end, .. },
+             &ProjectionElem::ConstantIndex { offset: b_offset, from_end: b_from_end, .. })
+                if a_from_end == b_from_end && a_offset != b_offset => return false,
+            _ => {}
+        }
     }
+    true
 }
 
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
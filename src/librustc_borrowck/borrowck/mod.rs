@@ -168,11 +168,13 @@ fn borrowck_fn(this: &mut BorrowckCtxt,
                attributes: &[ast::Attribute]) {
     debug!("borrowck_fn(id={})", id);
 
-    if attributes.iter().any(|item| item.check_name("rustc_mir_borrowck")) {
-        let mir = this.mir_map.unwrap().map.get(&id).unwrap();
-        this.with_temp_region_map(id, |this| {
-            mir::borrowck_mir(this, fk, decl, mir, body, sp, id, attributes)
-        });
+    if this.tcx.sess.opts.debugging_opts.borrowck_mir ||
+       attributes.iter().any(|item| item.check_name("rustc_mir_borrowck")) {
+        if let Some(mir) = this.mir_map.and_then(|mir_map| mir_map.map.get(&id)) {
+            this.with_temp_region_map(id, |this| {
+                mir::borrowck_mir(this, fk, decl, mir, body, sp, id, attributes)
+            });
+        }
     }
 
     let cfg = cfg::CFG::new(this.tcx, body);
@@ -21,7 +21,8 @@ use rustc::middle::dataflow::DataFlowOperator;
 use rustc::middle::dataflow::KillFrom;
 use rustc::middle::expr_use_visitor as euv;
 use rustc::middle::expr_use_visitor::MutateMode;
-use rustc::ty::TyCtxt;
+use rustc::middle::mem_categorization as mc;
+use rustc::ty::{self, TyCtxt};
 use rustc::util::nodemap::{FnvHashMap, NodeSet};
 
 use std::cell::RefCell;
@@ -359,11 +360,44 @@ impl<'a, 'tcx> MoveData<'tcx> {
 
     }
 
+    /// Returns the paths of the other fields of a union when `lp` is a
+    /// union field, since all of them share the same memory.
+    fn union_sibling_paths(&self, tcx: TyCtxt<'a, 'tcx, 'tcx>, lp: &Rc<LoanPath<'tcx>>)
+                           -> Vec<Rc<LoanPath<'tcx>>> {
+        let mut siblings = vec![];
+        if let LpExtend(ref base_lp, mutbl, LpInterior(opt_variant_id, interior)) = lp.kind {
+            if let ty::TyStruct(def, substs) = base_lp.ty.sty {
+                if def.is_union() {
+                    for field in &def.struct_variant().fields {
+                        let sibling = InteriorKind::InteriorField(mc::NamedField(field.name));
+                        if sibling != interior {
+                            let kind = LpExtend(base_lp.clone(), mutbl,
+                                                LpInterior(opt_variant_id, sibling));
+                            siblings.push(Rc::new(LoanPath::new(kind, field.ty(tcx, substs))));
+                        }
+                    }
+                }
+            }
+        }
+        siblings
+    }
+
     /// Adds a new move entry for a move of `lp` that occurs at location `id` with kind `kind`.
+    /// Moving a union field moves the other fields of the union as well.
     pub fn add_move(&self, tcx: TyCtxt<'a, 'tcx, 'tcx>,
                     lp: Rc<LoanPath<'tcx>>,
                     id: ast::NodeId,
                     kind: MoveKind) {
+        for sibling in self.union_sibling_paths(tcx, &lp) {
+            self.add_move_helper(tcx, sibling, id, kind);
+        }
+        self.add_move_helper(tcx, lp, id, kind);
+    }
+
+    fn add_move_helper(&self, tcx: TyCtxt<'a, 'tcx, 'tcx>,
+                       lp: Rc<LoanPath<'tcx>>,
+                       id: ast::NodeId,
+                       kind: MoveKind) {
         debug!("add_move(lp={:?}, id={}, kind={:?})",
                lp,
                id,
@@ -386,13 +420,25 @@ impl<'a, 'tcx> MoveData<'tcx> {
     }
 
     /// Adds a new record for an assignment to `lp` that occurs at location `id` with the given
-    /// `span`.
+    /// `span`. Assigning to a union field initializes the other fields of the union as well.
     pub fn add_assignment(&self, tcx: TyCtxt<'a, 'tcx, 'tcx>,
                           lp: Rc<LoanPath<'tcx>>,
                           assign_id: ast::NodeId,
                           span: Span,
                           assignee_id: ast::NodeId,
                           mode: euv::MutateMode) {
+        for sibling in self.union_sibling_paths(tcx, &lp) {
+            self.add_assignment_helper(tcx, sibling, assign_id, span, assignee_id, mode);
+        }
+        self.add_assignment_helper(tcx, lp, assign_id, span, assignee_id, mode);
+    }
+
+    fn add_assignment_helper(&self, tcx: TyCtxt<'a, 'tcx, 'tcx>,
+                             lp: Rc<LoanPath<'tcx>>,
+                             assign_id: ast::NodeId,
+                             span: Span,
+                             assignee_id: ast::NodeId,
+                             mode: euv::MutateMode) {
         debug!("add_assignment(lp={:?}, assign_id={}, assignee_id={}",
                lp, assign_id, assignee_id);
 
@@ -43,6 +43,7 @@ use std::cmp::Ordering;
 use std::collections::hash_map::Entry::Vacant;
 
 use rustc_const_math::*;
+use rustc_i128::{i128, u128};
 
 macro_rules! math {
     ($e:expr, $op:expr) => {
@@ -546,10 +547,10 @@ pub fn eval_const_expr_partial<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
         if let hir::ExprLit(ref lit) = inner.node {
             use syntax::ast::*;
             use syntax::ast::LitIntType::*;
-            const I8_OVERFLOW: u64 = ::std::i8::MAX as u64 + 1;
-            const I16_OVERFLOW: u64 = ::std::i16::MAX as u64 + 1;
-            const I32_OVERFLOW: u64 = ::std::i32::MAX as u64 + 1;
-            const I64_OVERFLOW: u64 = ::std::i64::MAX as u64 + 1;
+            const I8_OVERFLOW: u128 = ::std::i8::MAX as u128 + 1;
+            const I16_OVERFLOW: u128 = ::std::i16::MAX as u128 + 1;
+            const I32_OVERFLOW: u128 = ::std::i32::MAX as u128 + 1;
+            const I64_OVERFLOW: u128 = ::std::i64::MAX as u128 + 1;
             match (&lit.node, ety.map(|t| &t.sty)) {
                 (&LitKind::Int(I8_OVERFLOW, Unsuffixed), Some(&ty::TyInt(IntTy::I8))) |
                 (&LitKind::Int(I8_OVERFLOW, Signed(IntTy::I8)), _) => {
@@ -567,6 +568,11 @@ pub fn eval_const_expr_partial<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
                 (&LitKind::Int(I64_OVERFLOW, Signed(IntTy::I64)), _) => {
                     return Ok(Integral(I64(::std::i64::MIN)))
                 },
+                (&LitKind::Int(n, Unsuffixed), Some(&ty::TyInt(IntTy::I128))) |
+                (&LitKind::Int(n, Signed(IntTy::I128)), _)
+                    if n == i128::min_value() as u128 => {
+                    return Ok(Integral(I128(i128::min_value())))
+                },
                 (&LitKind::Int(n, Unsuffixed), Some(&ty::TyInt(IntTy::Is))) |
                 (&LitKind::Int(n, Signed(IntTy::Is)), _) => {
                     match tcx.sess.target.int_type {
@@ -925,18 +931,21 @@ fn infer<'a, 'tcx>(i: ConstInt,
         (&ty::TyInt(IntTy::I16), result @ I16(_)) => Ok(result),
         (&ty::TyInt(IntTy::I32), result @ I32(_)) => Ok(result),
         (&ty::TyInt(IntTy::I64), result @ I64(_)) => Ok(result),
+        (&ty::TyInt(IntTy::I128), result @ I128(_)) => Ok(result),
         (&ty::TyInt(IntTy::Is), result @ Isize(_)) => Ok(result),
 
         (&ty::TyUint(UintTy::U8), result @ U8(_)) => Ok(result),
         (&ty::TyUint(UintTy::U16), result @ U16(_)) => Ok(result),
         (&ty::TyUint(UintTy::U32), result @ U32(_)) => Ok(result),
         (&ty::TyUint(UintTy::U64), result @ U64(_)) => Ok(result),
+        (&ty::TyUint(UintTy::U128), result @ U128(_)) => Ok(result),
         (&ty::TyUint(UintTy::Us), result @ Usize(_)) => Ok(result),
 
         (&ty::TyInt(IntTy::I8), Infer(i)) => Ok(I8(i as i64 as i8)),
         (&ty::TyInt(IntTy::I16), Infer(i)) => Ok(I16(i as i64 as i16)),
         (&ty::TyInt(IntTy::I32), Infer(i)) => Ok(I32(i as i64 as i32)),
         (&ty::TyInt(IntTy::I64), Infer(i)) => Ok(I64(i as i64)),
+        (&ty::TyInt(IntTy::I128), Infer(i)) => Ok(I128(i as i128)),
         (&ty::TyInt(IntTy::Is), Infer(i)) => {
             Ok(Isize(ConstIsize::new_truncating(i as i64, tcx.sess.target.int_type)))
         },
@@ -945,6 +954,7 @@ fn infer<'a, 'tcx>(i: ConstInt,
         (&ty::TyInt(IntTy::I16), InferSigned(i)) => Ok(I16(i as i16)),
         (&ty::TyInt(IntTy::I32), InferSigned(i)) => Ok(I32(i as i32)),
         (&ty::TyInt(IntTy::I64), InferSigned(i)) => Ok(I64(i)),
+        (&ty::TyInt(IntTy::I128), InferSigned(i)) => Ok(I128(i as i128)),
         (&ty::TyInt(IntTy::Is), InferSigned(i)) => {
             Ok(Isize(ConstIsize::new_truncating(i, tcx.sess.target.int_type)))
         },
@@ -953,6 +963,7 @@ fn infer<'a, 'tcx>(i: ConstInt,
         (&ty::TyUint(UintTy::U16), Infer(i)) => Ok(U16(i as u16)),
         (&ty::TyUint(UintTy::U32), Infer(i)) => Ok(U32(i as u32)),
         (&ty::TyUint(UintTy::U64), Infer(i)) => Ok(U64(i)),
+        (&ty::TyUint(UintTy::U128), Infer(i)) => Ok(U128(i as u128)),
         (&ty::TyUint(UintTy::Us), Infer(i)) => {
             Ok(Usize(ConstUsize::new_truncating(i, tcx.sess.target.uint_type)))
         },
@@ -1035,6 +1046,7 @@ fn cast_const_int<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>, val: ConstInt, ty: ty::
         ty::TyInt(ast::IntTy::I16) => Ok(Integral(I16(v as i64 as i16))),
         ty::TyInt(ast::IntTy::I32) => Ok(Integral(I32(v as i64 as i32))),
         ty::TyInt(ast::IntTy::I64) => Ok(Integral(I64(v as i64))),
+        ty::TyInt(ast::IntTy::I128) => Ok(Integral(I128(val.to_u128_unchecked() as i128))),
         ty::TyInt(ast::IntTy::Is) => {
             Ok(Integral(Isize(ConstIsize::new_truncating(v as i64, tcx.sess.target.int_type))))
         },
@@ -1042,6 +1054,7 @@ fn cast_const_int<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>, val: ConstInt, ty: ty::
         ty::TyUint(ast::UintTy::U16) => Ok(Integral(U16(v as u16))),
         ty::TyUint(ast::UintTy::U32) => Ok(Integral(U32(v as u32))),
         ty::TyUint(ast::UintTy::U64) => Ok(Integral(U64(v))),
+        ty::TyUint(ast::UintTy::U128) => Ok(Integral(U128(val.to_u128_unchecked()))),
         ty::TyUint(ast::UintTy::Us) => {
             Ok(Integral(Usize(ConstUsize::new_truncating(v, tcx.sess.target.uint_type))))
         },
@@ -1127,28 +1140,37 @@ fn lit_to_const<'a, 'tcx>(lit: &ast::LitKind,
         LitKind::Str(ref s, _) => Ok(Str((*s).clone())),
         LitKind::ByteStr(ref data) => Ok(ByteStr(data.clone())),
         LitKind::Byte(n) => Ok(Integral(U8(n))),
+        LitKind::Int(n, Signed(IntTy::I128)) => Ok(Integral(I128(n as i128))),
         LitKind::Int(n, Signed(ity)) => {
+            let n = lit_to_u64(n, ConstMathErr::LitOutOfRange(ity))?;
             infer(InferSigned(n as i64), tcx, &ty::TyInt(ity)).map(Integral)
         },
 
         LitKind::Int(n, Unsuffixed) => {
             match ty_hint.map(|t| &t.sty) {
+                Some(&ty::TyInt(IntTy::I128)) => Ok(Integral(I128(n as i128))),
+                Some(&ty::TyUint(UintTy::U128)) => Ok(Integral(U128(n))),
                 Some(&ty::TyInt(ity)) => {
+                    let n = lit_to_u64(n, ConstMathErr::LitOutOfRange(ity))?;
                     infer(InferSigned(n as i64), tcx, &ty::TyInt(ity)).map(Integral)
                 },
                 Some(&ty::TyUint(uty)) => {
+                    let n = lit_to_u64(n, ConstMathErr::ULitOutOfRange(uty))?;
                     infer(Infer(n), tcx, &ty::TyUint(uty)).map(Integral)
                 },
-                None => Ok(Integral(Infer(n))),
+                None => Ok(Integral(Infer(lit_to_u64(n, ConstMathErr::NotInRange)?))),
                 Some(&ty::TyEnum(ref adt, _)) => {
                     let hints = tcx.lookup_repr_hints(adt.did);
                     let int_ty = tcx.enum_repr_type(hints.iter().next());
+                    let n = lit_to_u64(n, ConstMathErr::NotInRange)?;
                     infer(Infer(n), tcx, &int_ty.to_ty(tcx).sty).map(Integral)
                 },
                 Some(ty_hint) => bug!("bad ty_hint: {:?}, {:?}", ty_hint, lit),
             }
         },
+        LitKind::Int(n, Unsigned(UintTy::U128)) => Ok(Integral(U128(n))),
         LitKind::Int(n, Unsigned(ity)) => {
+            let n = lit_to_u64(n, ConstMathErr::ULitOutOfRange(ity))?;
             infer(Infer(n), tcx, &ty::TyUint(ity)).map(Integral)
         },
 
@@ -1167,6 +1189,16 @@ fn lit_to_const<'a, 'tcx>(lit: &ast::LitKind,
     }
 }
 
+// Only the 128-bit types can hold a literal that doesn't fit in 64 bits, and
+// they don't go through `Infer` and `InferSigned`.
+fn lit_to_u64(n: u128, err: ConstMathErr) -> Result<u64, ErrKind> {
+    if n as u64 as u128 == n {
+        Ok(n as u64)
+    } else {
+        Err(Math(err))
+    }
+}
+
 fn parse_float(num: &str, fty_hint: Option<ast::FloatTy>, span: Span) -> ConstFloat {
     let val = match fty_hint {
         Some(ast::FloatTy::F32) => num.parse::<f32>().map(F32),
@@ -30,6 +30,8 @@
 #[macro_use] extern crate log;
 #[macro_use] extern crate syntax;
 
+extern crate rustc_i128;
+
 extern crate serialize as rustc_serialize; // used by deriving
 
 mod float;
@@ -39,6 +39,7 @@ use rustc_plugin as plugin;
 use rustc_passes::{ast_validation, no_asm, loops, consts, rvalues, static_recursion};
 use rustc_const_eval::check_match;
 use super::Compilation;
+use derive_registrar;
 
 use serialize::json;
 
@@ -695,6 +696,17 @@ pub fn phase_2_configure_and_expand<'a, F>(sess: &Session,
                                          sess.diagnostic())
     });
 
+    krate = time(time_passes, "injecting the custom derive registrar", || {
+        let is_proc_macro_crate = sess.crate_types.borrow().contains(&config::CrateTypeProcMacro);
+        let num_crate_types = sess.crate_types.borrow().len();
+        syntax_ext::proc_macro_registrar::modify(&sess.parse_sess,
+                                                 krate,
+                                                 is_proc_macro_crate,
+                                                 num_crate_types,
+                                                 sess.diagnostic(),
+                                                 &sess.features.borrow())
+    });
+
     time(time_passes,
          "checking for inline asm in case the target doesn't support it",
          || no_asm::check_crate(sess, &krate));
@@ -879,6 +891,7 @@ pub fn phase_3_run_analysis_passes<'tcx, F, R>(sess: &'tcx Session,
     sess.plugin_registrar_fn.set(time(time_passes, "looking for plugin registrar", || {
         plugin::build::find_plugin_registrar(sess.diagnostic(), &hir_map)
     }));
+    sess.derive_registrar_fn.set(derive_registrar::find(&hir_map));
 
     let region_map = time(time_passes,
                           "region resolution",
@@ -961,6 +974,12 @@ pub fn phase_3_run_analysis_passes<'tcx, F, R>(sess: &'tcx Session,
             let mut passes = sess.mir_passes.borrow_mut();
             // Push all the built-in passes.
             passes.push_hook(box mir::transform::dump_mir::DumpMir);
+            if sess.opts.debugging_opts.dump_mir_diff.is_some() {
+                passes.push_hook(box mir::transform::dump_mir::DumpMirDiff::new());
+            }
+            if sess.opts.debugging_opts.validate_mir {
+                passes.push_hook(box mir::transform::validate::ValidateMir::new());
+            }
             passes.push_pass(box mir::transform::simplify_cfg::SimplifyCfg::new("initial"));
             passes.push_pass(box mir::transform::qualify_consts::QualifyAndPromoteConstants);
             passes.push_pass(box mir::transform::type_check::TypeckMir);
@@ -1032,7 +1051,9 @@ pub fn phase_4_translate_to_llvm<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
     // to LLVM code.
     time(time_passes, "Prepare MIR codegen passes", || {
         let mut passes = ::rustc::mir::transform::Passes::new();
-        passes.push_hook(box mir::transform::dump_mir::DumpMir);
+        // Reuse the hooks of the analysis passes, so that e.g. the summary of
+        // -Z dump-mir-diff covers both sets of passes.
+        passes.take_hooks(&mut tcx.sess.mir_passes.borrow_mut());
         passes.push_pass(box mir::transform::no_landing_pads::NoLandingPads);
         passes.push_pass(box mir::transform::simplify_cfg::SimplifyCfg::new("no-landing-pads"));
 
@@ -1194,6 +1215,9 @@ pub fn collect_crate_types(session: &Session, attrs: &[ast::Attribute]) -> Vec<c
                              Some(config::CrateTypeStaticlib)
                          }
                          Some(ref n) if *n == "bin" => Some(config::CrateTypeExecutable),
+                         Some(ref n) if *n == "proc-macro" => {
+                             Some(config::CrateTypeProcMacro)
+                         }
                          Some(_) => {
                              session.add_lint(lint::builtin::UNKNOWN_CRATE_TYPES,
                                               ast::CRATE_NODE_ID,
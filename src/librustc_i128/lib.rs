@@ -0,0 +1,40 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![crate_name = "rustc_i128"]
+#![crate_type = "rlib"]
+#![no_std]
+#![feature(staged_api)]
+#![unstable(feature = "rustc_private", issue = "27812")]
+#![cfg_attr(not(stage0), feature(i128_type))]
+#![cfg_attr(not(stage0), deny(warnings))]
+#![allow(non_camel_case_types)]
+
+//! The 128-bit integer types used by the compiler.
+//!
+//! The stage0 compiler does not know about `i128` and `u128`, so while
+//! bootstrapping they are aliases of the 64-bit types. Constant values that
+//! do not fit in 64 bits are truncated in that configuration only.
+
+#[cfg(stage0)]
+pub type i128 = i64;
+#[cfg(stage0)]
+pub type u128 = u64;
+
+#[cfg(not(stage0))]
+pub type i128 = int::_i128;
+#[cfg(not(stage0))]
+pub type u128 = int::_u128;
+
+#[cfg(not(stage0))]
+mod int {
+    pub type _i128 = i128;
+    pub type _u128 = u128;
+}
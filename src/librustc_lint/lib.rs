@@ -45,6 +45,7 @@ extern crate rustc;
 extern crate log;
 extern crate rustc_back;
 extern crate rustc_const_eval;
+extern crate rustc_i128;
 extern crate syntax_pos;
 
 pub use rustc::lint as lint;
@@ -134,6 +135,7 @@ pub fn register_builtins(store: &mut lint::LintStore, sess: Option<&Session>) {
 
     add_builtin_with_new!(sess,
                           TypeLimits,
+                          StackFrameSize,
                           MissingDoc,
                           MissingDebugImplementations,
                           );
@@ -25,13 +25,16 @@ use lint::{LintPass, LateLintPass};
 use std::cmp;
 use std::{i8, i16, i32, i64, u8, u16, u32, u64, f32, f64};
 
+use rustc_i128::{i128, u128};
+
 use syntax::ast;
 use syntax::abi::Abi;
-use syntax::attr;
+use syntax::attr::{self, AttrMetaMethods};
 use syntax_pos::Span;
 use syntax::codemap;
 
 use rustc::hir;
+use rustc::hir::intravisit::{self, FnKind, Visitor};
 
 register_long_diagnostics! {
 E0519: r##"
@@ -146,8 +149,11 @@ impl LateLintPass for TypeLimits {
 
                     if let Some(bits) = opt_ty_bits {
                         let exceeding = if let hir::ExprLit(ref lit) = r.node {
-                            if let ast::LitKind::Int(shift, _) = lit.node { shift >= bits }
-                            else { false }
+                            if let ast::LitKind::Int(shift, _) = lit.node {
+                                shift >= bits as u128
+                            } else {
+                                false
+                            }
                         } else {
                             match eval_const_expr_partial(cx.tcx, &r, ExprTypeChecked, None) {
                                 Ok(ConstVal::Integral(i)) => {
@@ -181,8 +187,8 @@ impl LateLintPass for TypeLimits {
 
                                 // Detect literal value out of range [min, max] inclusive
                                 // avoiding use of -min to prevent overflow/panic
-                                if (negative && v > max as u64 + 1) ||
-                                   (!negative && v > max as u64) {
+                                if (negative && v > max as u128 + 1) ||
+                                   (!negative && v > max as u128) {
                                     cx.span_lint(OVERFLOWING_LITERALS, e.span,
                                                  &format!("literal out of range for {:?}", t));
                                     return;
@@ -198,7 +204,7 @@ impl LateLintPass for TypeLimits {
                             t
                         };
                         let (min, max) = uint_ty_range(uint_type);
-                        let lit_val: u64 = match lit.node {
+                        let lit_val: u128 = match lit.node {
                             // _v is u8, within range by definition
                             ast::LitKind::Byte(_v) => return,
                             ast::LitKind::Int(v, _) => v,
@@ -256,23 +262,25 @@ impl LateLintPass for TypeLimits {
 
         // for isize & usize, be conservative with the warnings, so that the
         // warnings are consistent between 32- and 64-bit platforms
-        fn int_ty_range(int_ty: ast::IntTy) -> (i64, i64) {
+        fn int_ty_range(int_ty: ast::IntTy) -> (i128, i128) {
             match int_ty {
-                ast::IntTy::Is => (i64::MIN,        i64::MAX),
-                ast::IntTy::I8 =>    (i8::MIN  as i64, i8::MAX  as i64),
-                ast::IntTy::I16 =>   (i16::MIN as i64, i16::MAX as i64),
-                ast::IntTy::I32 =>   (i32::MIN as i64, i32::MAX as i64),
-                ast::IntTy::I64 =>   (i64::MIN,        i64::MAX)
+                ast::IntTy::Is => (i64::MIN as i128, i64::MAX as i128),
+                ast::IntTy::I8 =>    (i8::MIN  as i128, i8::MAX  as i128),
+                ast::IntTy::I16 =>   (i16::MIN as i128, i16::MAX as i128),
+                ast::IntTy::I32 =>   (i32::MIN as i128, i32::MAX as i128),
+                ast::IntTy::I64 =>   (i64::MIN as i128, i64::MAX as i128),
+                ast::IntTy::I128 =>  (i128::min_value(), i128::max_value()),
             }
         }
 
-        fn uint_ty_range(uint_ty: ast::UintTy) -> (u64, u64) {
+        fn uint_ty_range(uint_ty: ast::UintTy) -> (u128, u128) {
             match uint_ty {
-                ast::UintTy::Us => (u64::MIN,         u64::MAX),
-                ast::UintTy::U8 =>    (u8::MIN   as u64, u8::MAX   as u64),
-                ast::UintTy::U16 =>   (u16::MIN  as u64, u16::MAX  as u64),
-                ast::UintTy::U32 =>   (u32::MIN  as u64, u32::MAX  as u64),
-                ast::UintTy::U64 =>   (u64::MIN,         u64::MAX)
+                ast::UintTy::Us => (u64::MIN as u128, u64::MAX as u128),
+                ast::UintTy::U8 =>    (u8::MIN   as u128, u8::MAX   as u128),
+                ast::UintTy::U16 =>   (u16::MIN  as u128, u16::MAX  as u128),
+                ast::UintTy::U32 =>   (u32::MIN  as u128, u32::MAX  as u128),
+                ast::UintTy::U64 =>   (u64::MIN  as u128, u64::MAX  as u128),
+                ast::UintTy::U128 =>  (u128::min_value(), u128::max_value()),
             }
         }
 
@@ -290,6 +298,7 @@ impl LateLintPass for TypeLimits {
                 ast::IntTy::I16 => 16 as u64,
                 ast::IntTy::I32 => 32,
                 ast::IntTy::I64 => 64,
+                ast::IntTy::I128 => 128,
             }
         }
 
@@ -300,6 +309,7 @@ impl LateLintPass for TypeLimits {
                 ast::UintTy::U16 => 16,
                 ast::UintTy::U32 => 32,
                 ast::UintTy::U64 => 64,
+                ast::UintTy::U128 => 128,
             }
         }
 
@@ -322,10 +332,10 @@ impl LateLintPass for TypeLimits {
             match tcx.node_id_to_type(expr.id).sty {
                 ty::TyInt(int_ty) => {
                     let (min, max) = int_ty_range(int_ty);
-                    let lit_val: i64 = match lit.node {
+                    let lit_val: i128 = match lit.node {
                         hir::ExprLit(ref li) => match li.node {
                             ast::LitKind::Int(v, ast::LitIntType::Signed(_)) |
-                            ast::LitKind::Int(v, ast::LitIntType::Unsuffixed) => v as i64,
+                            ast::LitKind::Int(v, ast::LitIntType::Unsuffixed) => v as i128,
                             _ => return true
                         },
                         _ => bug!()
@@ -333,8 +343,8 @@ impl LateLintPass for TypeLimits {
                     is_valid(norm_binop, lit_val, min, max)
                 }
                 ty::TyUint(uint_ty) => {
-                    let (min, max): (u64, u64) = uint_ty_range(uint_ty);
-                    let lit_val: u64 = match lit.node {
+                    let (min, max): (u128, u128) = uint_ty_range(uint_ty);
+                    let lit_val: u128 = match lit.node {
                         hir::ExprLit(ref li) => match li.node {
                             ast::LitKind::Int(v, _) => v,
                             _ => return true
@@ -596,7 +606,7 @@ impl<'a, 'tcx> ImproperCTypesVisitor<'a, 'tcx> {
             }
 
             ty::TyParam(..) | ty::TyInfer(..) | ty::TyError |
-            ty::TyClosure(..) | ty::TyProjection(..) |
+            ty::TyClosure(..) | ty::TyProjection(..) | ty::TyAnon(..) |
             ty::TyFnDef(..) => {
                 bug!("Unexpected type in foreign function")
             }
@@ -744,3 +754,144 @@ impl LateLintPass for VariantSizeDifferences {
         }
     }
 }
+
+declare_lint! {
+    LARGE_STACK_FRAMES,
+    Warn,
+    "detects functions whose local variables take up a lot of stack space"
+}
+
+declare_lint! {
+    LARGE_MOVES,
+    Allow,
+    "detects large values passed to or returned from functions by value"
+}
+
+/// Checks the stack usage of functions against the limits set with
+/// `#![stack_frame_limit="N"]` and `#![move_size_limit="N"]`, in bytes.
+pub struct StackFrameSize {
+    frame_limit: u64,
+    move_limit: u64,
+}
+
+impl StackFrameSize {
+    pub fn new() -> StackFrameSize {
+        StackFrameSize {
+            frame_limit: 64 * 1024,
+            move_limit: 4 * 1024,
+        }
+    }
+
+    /// The size of `ty` in bytes, if it does not depend on type parameters.
+    fn size_of<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, ty: Ty<'tcx>) -> Option<u64> {
+        let ty = cx.tcx.erase_regions(&ty);
+        cx.tcx.normalizing_infer_ctxt(ProjectionMode::Any).enter(|infcx| {
+            ty.layout(&infcx).ok().map(|layout| layout.size(&cx.tcx.data_layout).bytes())
+        })
+    }
+
+    fn check_move<'a, 'tcx>(&self, cx: &LateContext<'a, 'tcx>, ty: Ty<'tcx>, span: Span,
+                            what: &str) {
+        if let Some(size) = StackFrameSize::size_of(cx, ty) {
+            if size > self.move_limit {
+                cx.span_lint(LARGE_MOVES, span,
+                             &format!("{} of type `{}` is {} bytes large, more than the \
+                                       limit of {} bytes for values moved by value",
+                                      what, ty, size, self.move_limit));
+            }
+        }
+    }
+}
+
+impl LintPass for StackFrameSize {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(LARGE_STACK_FRAMES, LARGE_MOVES)
+    }
+}
+
+impl LateLintPass for StackFrameSize {
+    fn check_crate(&mut self, cx: &LateContext, krate: &hir::Crate) {
+        for attr in &krate.attrs {
+            let limit = if attr.check_name("stack_frame_limit") {
+                &mut self.frame_limit
+            } else if attr.check_name("move_size_limit") {
+                &mut self.move_limit
+            } else {
+                continue;
+            };
+
+            match attr.value_str().and_then(|s| s.parse().ok()) {
+                Some(n) => *limit = n,
+                None => {
+                    cx.sess().span_err(attr.span, &format!("malformed `{}` attribute, \
+                                                            expected #![{}=\"N\"]",
+                                                           attr.name(), attr.name()));
+                }
+            }
+        }
+    }
+
+    fn check_fn(&mut self, cx: &LateContext, fk: FnKind, decl: &hir::FnDecl,
+                body: &hir::Block, _: Span, id: ast::NodeId) {
+        struct LocalCollector<'a> {
+            locals: Vec<&'a hir::Local>,
+        }
+
+        impl<'a> Visitor<'a> for LocalCollector<'a> {
+            fn visit_local(&mut self, local: &'a hir::Local) {
+                self.locals.push(local);
+                intravisit::walk_local(self, local);
+            }
+
+            fn visit_expr(&mut self, e: &'a hir::Expr) {
+                // Closures have stack frames of their own.
+                if let hir::ExprClosure(..) = e.node {
+                    return;
+                }
+                intravisit::walk_expr(self, e);
+            }
+        }
+
+        let mut collector = LocalCollector { locals: Vec::new() };
+        collector.visit_block(body);
+
+        let mut frame_size = 0u64;
+        let mut largest: Option<(u64, Span)> = None;
+        for local in collector.locals {
+            let ty = cx.tcx.node_id_to_type(local.pat.id);
+            if let Some(size) = StackFrameSize::size_of(cx, ty) {
+                frame_size = frame_size.saturating_add(size);
+                if largest.map_or(true, |(largest_size, _)| size > largest_size) {
+                    largest = Some((size, local.pat.span));
+                }
+            }
+        }
+
+        if frame_size > self.frame_limit {
+            let (size, span) = largest.unwrap();
+            cx.struct_span_lint(LARGE_STACK_FRAMES, span,
+                                &format!("local variables of this function take up {} bytes \
+                                          of stack, more than the limit of {} bytes",
+                                         frame_size, self.frame_limit))
+              .span_label(span, &format!("the largest local variable is {} bytes", size))
+              .emit();
+        }
+
+        if let FnKind::Closure(..) = fk {
+            return;
+        }
+
+        for arg in &decl.inputs {
+            let ty = cx.tcx.node_id_to_type(arg.pat.id);
+            self.check_move(cx, ty, arg.pat.span, "parameter");
+        }
+
+        if let hir::Return(ref ret_ty) = decl.output {
+            let fn_ty = cx.tcx.lookup_item_type(cx.tcx.map.local_def_id(id)).ty;
+            let output = cx.tcx.erase_late_bound_regions(&fn_ty.fn_sig().output());
+            if let ty::FnConverging(ty) = output {
+                self.check_move(cx, ty, ret_ty.span, "return value");
+            }
+        }
+    }
+}
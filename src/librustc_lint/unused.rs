@@ -8,6 +8,8 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use rustc::hir::def::Def;
+use rustc::hir::def_id::DefId;
 use rustc::hir::pat_util;
 use rustc::ty;
 use rustc::ty::adjustment;
@@ -101,7 +103,7 @@ impl LateLintPass for UnusedMut {
 declare_lint! {
     pub UNUSED_MUST_USE,
     Warn,
-    "unused result of a type flagged as #[must_use]"
+    "unused result of a type or function flagged as #[must_use]"
 }
 
 declare_lint! {
@@ -131,24 +133,50 @@ impl LateLintPass for UnusedResults {
         }
 
         let t = cx.tcx.expr_ty(&expr);
-        let warned = match t.sty {
+        let ty_warned = match t.sty {
             ty::TyTuple(ref tys) if tys.is_empty() => return,
-            ty::TyBool => return,
             ty::TyStruct(def, _) |
             ty::TyEnum(def, _) => {
                 let attrs = cx.tcx.get_attrs(def.did);
-                check_must_use(cx, &attrs[..], s.span)
+                let msg = "unused result which must be used".to_string();
+                check_must_use(cx, &attrs[..], s.span, msg)
             }
             _ => false,
         };
-        if !warned {
+
+        // Functions and methods may be flagged as #[must_use] themselves; a
+        // result that was already reported for its type is not reported twice.
+        let callee = match expr.node {
+            hir::ExprCall(ref callee, _) => {
+                match cx.tcx.expect_def_or_none(callee.id) {
+                    Some(Def::Fn(def_id)) | Some(Def::Method(def_id)) => Some(def_id),
+                    _ => None
+                }
+            }
+            hir::ExprMethodCall(..) => {
+                cx.tcx.tables.borrow().method_map.get(&ty::MethodCall::expr(expr.id))
+                    .map(|method| method.def_id)
+            }
+            _ => None
+        };
+        let fn_warned = match callee {
+            Some(def_id) if !ty_warned => check_must_use_fn(cx, def_id, s.span),
+            _ => false,
+        };
+
+        if !ty_warned && !fn_warned {
+            if let ty::TyBool = t.sty {
+                return;
+            }
             cx.span_lint(UNUSED_RESULTS, s.span, "unused result");
         }
 
-        fn check_must_use(cx: &LateContext, attrs: &[ast::Attribute], sp: Span) -> bool {
+        fn check_must_use(cx: &LateContext,
+                          attrs: &[ast::Attribute],
+                          sp: Span,
+                          mut msg: String) -> bool {
             for attr in attrs {
                 if attr.check_name("must_use") {
-                    let mut msg = "unused result which must be used".to_string();
                     // check for #[must_use="..."]
                     if let Some(s) = attr.value_str() {
                         msg.push_str(": ");
@@ -160,6 +188,22 @@ impl LateLintPass for UnusedResults {
             }
             false
         }
+
+        // An implementation of a trait method is also checked against the
+        // attributes of the method in the trait definition.
+        fn check_must_use_fn(cx: &LateContext, def_id: DefId, sp: Span) -> bool {
+            let msg = format!("unused return value of `{}` which must be used",
+                              cx.tcx.item_path_str(def_id));
+            if check_must_use(cx, &cx.tcx.get_attrs(def_id), sp, msg.clone()) {
+                return true;
+            }
+            match cx.tcx.trait_item_of_item(def_id).map(|item| item.def_id()) {
+                Some(trait_def_id) if trait_def_id != def_id => {
+                    check_must_use(cx, &cx.tcx.get_attrs(trait_def_id), sp, msg)
+                }
+                _ => false
+            }
+        }
     }
 }
 
@@ -741,6 +741,10 @@ extern {
     /* Operations on scalar constants */
     pub fn LLVMConstInt(IntTy: TypeRef, N: c_ulonglong, SignExtend: Bool)
                         -> ValueRef;
+    pub fn LLVMConstIntOfArbitraryPrecision(IntTy: TypeRef,
+                                            NumWords: c_uint,
+                                            Words: *const u64)
+                                            -> ValueRef;
     pub fn LLVMConstIntOfString(IntTy: TypeRef, Text: *const c_char, Radix: u8)
                                 -> ValueRef;
     pub fn LLVMConstIntOfStringAndSize(IntTy: TypeRef,
@@ -130,6 +130,18 @@ struct ExtensionCrate {
     target_only: bool,
 }
 
+/// The macros exported by a crate loaded with `extern crate`.
+pub struct Macros {
+    pub macro_rules: Vec<ast::MacroDef>,
+
+    /// The index of the custom derive registrar, if the crate is a
+    /// `proc-macro` crate.
+    pub custom_derive_registrar: Option<DefIndex>,
+
+    pub svh: Svh,
+    pub dylib: Option<PathBuf>,
+}
+
 enum PMDSource {
     Registered(Rc<cstore::CrateMetadata>),
     Owned(MetadataBlob),
@@ -528,7 +540,11 @@ impl<'a> CrateReader<'a> {
             }
             LoadResult::Loaded(library) => {
                 let dylib = library.dylib.clone();
-                let metadata = if should_link {
+                // `proc-macro` crates are only ever loaded into the compiler,
+                // never linked into the output.
+                let is_proc_macro =
+                    decoder::get_derive_registrar_fn(library.metadata.as_slice()).is_some();
+                let metadata = if should_link && !is_proc_macro {
                     // Register crate now to avoid double-reading metadata
                     let (_, cmd, _) = self.register_crate(&None, &info.ident,
                                                           &info.name, span,
@@ -550,12 +566,17 @@ impl<'a> CrateReader<'a> {
     }
 
     /// Read exported macros.
-    pub fn read_exported_macros(&mut self, item: &ast::Item) -> Vec<ast::MacroDef> {
+    pub fn read_macros(&mut self, item: &ast::Item) -> Macros {
         let ci = self.extract_crate_info(item).unwrap();
         let ekrate = self.read_extension_crate(item.span, &ci);
 
         let source_name = format!("<{} macros>", item.ident);
-        let mut macros = vec![];
+        let mut ret = Macros {
+            macro_rules: Vec::new(),
+            custom_derive_registrar: None,
+            svh: decoder::get_crate_hash(ekrate.metadata.as_slice()),
+            dylib: None,
+        };
         decoder::each_exported_macro(ekrate.metadata.as_slice(),
                                      &self.cstore.intr,
             |name, attrs, span, body| {
@@ -581,7 +602,7 @@ impl<'a> CrateReader<'a> {
                     attr::mark_used(attr);
                 }
 
-                macros.push(ast::MacroDef {
+                ret.macro_rules.push(ast::MacroDef {
                     ident: ast::Ident::with_empty_ctxt(name),
                     attrs: attrs,
                     id: ast::DUMMY_NODE_ID,
@@ -599,7 +620,25 @@ impl<'a> CrateReader<'a> {
                 true
             }
         );
-        macros
+
+        match decoder::get_derive_registrar_fn(ekrate.metadata.as_slice()) {
+            Some(id) => ret.custom_derive_registrar = Some(id),
+            None => return ret,
+        }
+
+        if ekrate.target_only {
+            let message = format!("proc-macro crate is not available for \
+                                   triple `{}` (only found {})",
+                                  config::host_triple(),
+                                  self.sess.opts.target_triple);
+            self.sess.span_fatal(item.span, &message);
+        }
+
+        if ekrate.dylib.is_none() {
+            span_bug!(item.span, "proc-macro crate not dylib");
+        }
+        ret.dylib = ekrate.dylib;
+        ret
     }
 
     /// Look for a plugin registrar. Returns library path, crate
@@ -771,6 +810,7 @@ impl<'a> CrateReader<'a> {
                 config::CrateTypeExecutable => need_exe_alloc = true,
                 config::CrateTypeDylib |
                 config::CrateTypeCdylib |
+                config::CrateTypeProcMacro |
                 config::CrateTypeStaticlib => need_lib_alloc = true,
                 config::CrateTypeRlib => {}
             }
@@ -905,6 +945,11 @@ impl<'a> LocalCrateReader<'a> {
                 if !should_link(i) {
                     return;
                 }
+                // Crates loaded only for their custom derive modes are not
+                // linked into the output.
+                if self.cstore.was_used_for_derive_macros(i) {
+                    return;
+                }
 
                 if let Some(info) = self.creader.extract_crate_info(i) {
                     let (cnum, _, _) = self.creader.resolve_crate(&None,
@@ -1012,6 +1012,39 @@ fn encode_info_for_item<'a, 'tcx>(ecx: &EncodeContext<'a, 'tcx>,
             encode_info_for_struct_ctor(ecx, rbml_w, item.name, struct_def, index, item.id);
         }
       }
+      hir::ItemUnion(..) => {
+        let _task = index.record(def_id, rbml_w);
+
+        let def = ecx.tcx.lookup_adt_def(def_id);
+        let variant = def.struct_variant();
+
+        rbml_w.start_tag(tag_items_data_item);
+        encode_def_id_and_key(ecx, rbml_w, def_id);
+        encode_family(rbml_w, 'U');
+        encode_bounds_and_type_for_item(rbml_w, ecx, index, item.id);
+
+        encode_item_variances(rbml_w, ecx, item.id);
+        encode_name(rbml_w, item.name);
+        encode_attributes(rbml_w, &item.attrs);
+        encode_stability(rbml_w, stab);
+        encode_deprecation(rbml_w, depr);
+        encode_visibility(rbml_w, vis);
+        encode_repr_attrs(rbml_w, ecx, &item.attrs);
+
+        encode_struct_fields(rbml_w, variant);
+
+        encode_inlined_item(ecx, rbml_w, InlinedItemRef::Item(item));
+        encode_mir(ecx, rbml_w, item.id);
+
+        // Encode inherent implementations for this union.
+        encode_inherent_implementations(ecx, rbml_w, def_id);
+
+        rbml_w.end_tag();
+
+        for field in &variant.fields {
+            encode_field(ecx, rbml_w, field, index);
+        }
+      }
       hir::ItemDefaultImpl(unsafety, _) => {
           let _task = index.record(def_id, rbml_w);
           rbml_w.start_tag(tag_items_data_item);
@@ -1370,6 +1403,21 @@ fn my_visit_expr(expr: &hir::Expr,
     }
 }
 
+fn my_visit_ty(ty: &hir::Ty,
+               rbml_w: &mut Encoder,
+               ecx: &EncodeContext,
+               index: &mut CrateIndex) {
+    if let hir::TyImplTrait(_) = ty.node {
+        let def_id = ecx.tcx.map.local_def_id(ty.id);
+        let _task = index.record(def_id, rbml_w);
+        rbml_w.start_tag(tag_items_data_item);
+        encode_def_id_and_key(ecx, rbml_w, def_id);
+        encode_family(rbml_w, 'y');
+        encode_bounds_and_type_for_item(rbml_w, ecx, index, ty.id);
+        rbml_w.end_tag();
+    }
+}
+
 struct EncodeVisitor<'a, 'b:'a, 'c:'a, 'tcx:'c> {
     rbml_w_for_visit_item: &'a mut Encoder<'b>,
     ecx: &'a EncodeContext<'c, 'tcx>,
@@ -1381,6 +1429,10 @@ impl<'a, 'b, 'c, 'tcx> Visitor<'tcx> for EncodeVisitor<'a, 'b, 'c, 'tcx> {
         intravisit::walk_expr(self, ex);
         my_visit_expr(ex, self.rbml_w_for_visit_item, self.ecx, self.index);
     }
+    fn visit_ty(&mut self, ty: &'tcx hir::Ty) {
+        intravisit::walk_ty(self, ty);
+        my_visit_ty(ty, self.rbml_w_for_visit_item, self.ecx, self.index);
+    }
     fn visit_item(&mut self, i: &'tcx hir::Item) {
         intravisit::walk_item(self, i);
         encode_info_for_item(self.ecx, self.rbml_w_for_visit_item, i, self.index);
@@ -1586,6 +1638,13 @@ fn encode_plugin_registrar_fn(ecx: &EncodeContext, rbml_w: &mut Encoder) {
     }
 }
 
+fn encode_derive_registrar_fn(ecx: &EncodeContext, rbml_w: &mut Encoder) {
+    if let Some(id) = ecx.tcx.sess.derive_registrar_fn.get() {
+        let def_id = ecx.tcx.map.local_def_id(id);
+        rbml_w.wr_tagged_u32(tag_macro_derive_registrar, def_id.index.as_u32());
+    }
+}
+
 fn encode_codemap(ecx: &EncodeContext, rbml_w: &mut Encoder) {
     rbml_w.start_tag(tag_codemap);
     let codemap = ecx.tcx.sess.codemap();
@@ -1918,6 +1977,7 @@ fn encode_metadata_inner(rbml_w: &mut Encoder,
     // Encode the plugin registrar function
     i = rbml_w.writer.seek(SeekFrom::Current(0)).unwrap();
     encode_plugin_registrar_fn(&ecx, rbml_w);
+    encode_derive_registrar_fn(&ecx, rbml_w);
     stats.plugin_registrar_fn_bytes = rbml_w.writer.seek(SeekFrom::Current(0)).unwrap() - i;
 
     // Encode codemap
@@ -21,6 +21,8 @@
 #![feature(enumset)]
 #![feature(quote)]
 #![feature(rustc_diagnostic_macros)]
+#![feature(proc_macro_internals)]
+#![feature(proc_macro_lib)]
 #![feature(rustc_private)]
 #![feature(staged_api)]
 #![feature(question_mark)]
@@ -40,6 +42,8 @@ extern crate rustc_data_structures;
 extern crate rustc_back;
 extern crate rustc_llvm;
 extern crate rustc_const_math;
+extern crate proc_macro;
+extern crate syntax_ext;
 
 pub use rustc::middle;
 
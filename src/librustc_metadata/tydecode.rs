@@ -319,10 +319,12 @@ impl<'a,'tcx> TyDecoder<'a,'tcx> {
                     'w' => return tcx.types.u16,
                     'l' => return tcx.types.u32,
                     'd' => return tcx.types.u64,
+                    'o' => return tcx.types.u128,
                     'B' => return tcx.types.i8,
                     'W' => return tcx.types.i16,
                     'L' => return tcx.types.i32,
                     'D' => return tcx.types.i64,
+                    'O' => return tcx.types.i128,
                     'f' => return tcx.types.f32,
                     'F' => return tcx.types.f64,
                     _ => bug!("parse_ty: bad numeric type")
@@ -445,6 +447,13 @@ impl<'a,'tcx> TyDecoder<'a,'tcx> {
                 let name = token::intern(&self.parse_str(']'));
                 return tcx.mk_projection(trait_ref, name);
             }
+            'A' => {
+                assert_eq!(self.next(), '[');
+                let def_id = self.parse_def();
+                let substs = self.parse_substs();
+                assert_eq!(self.next(), ']');
+                return self.tcx.mk_anon(def_id, self.tcx.mk_substs(substs));
+            }
             'e' => {
                 return tcx.types.err;
             }
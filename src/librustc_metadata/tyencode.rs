@@ -80,7 +80,8 @@ pub fn enc_ty<'a, 'tcx>(w: &mut Cursor<Vec<u8>>, cx: &ctxt<'a, 'tcx>, t: Ty<'tcx
                 ast::IntTy::I8 => write!(w, "MB"),
                 ast::IntTy::I16 => write!(w, "MW"),
                 ast::IntTy::I32 => write!(w, "ML"),
-                ast::IntTy::I64 => write!(w, "MD")
+                ast::IntTy::I64 => write!(w, "MD"),
+                ast::IntTy::I128 => write!(w, "MO")
             };
         }
         ty::TyUint(t) => {
@@ -89,7 +90,8 @@ pub fn enc_ty<'a, 'tcx>(w: &mut Cursor<Vec<u8>>, cx: &ctxt<'a, 'tcx>, t: Ty<'tcx
                 ast::UintTy::U8 => write!(w, "Mb"),
                 ast::UintTy::U16 => write!(w, "Mw"),
                 ast::UintTy::U32 => write!(w, "Ml"),
-                ast::UintTy::U64 => write!(w, "Md")
+                ast::UintTy::U64 => write!(w, "Md"),
+                ast::UintTy::U128 => write!(w, "Mo")
             };
         }
         ty::TyFloat(t) => {
@@ -170,6 +172,11 @@ pub fn enc_ty<'a, 'tcx>(w: &mut Cursor<Vec<u8>>, cx: &ctxt<'a, 'tcx>, t: Ty<'tcx
             enc_trait_ref(w, cx, data.trait_ref);
             write!(w, "{}]", data.item_name);
         }
+        ty::TyAnon(def_id, substs) => {
+            write!(w, "A[{}|", (cx.ds)(cx.tcx, def_id));
+            enc_substs(w, cx, substs);
+            write!(w, "]");
+        }
         ty::TyError => {
             write!(w, "e");
         }
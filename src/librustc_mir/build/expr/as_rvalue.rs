@@ -20,6 +20,7 @@ use build::{BlockAnd, BlockAndExtension, Builder};
 use build::expr::category::{Category, RvalueFunc};
 use hair::*;
 use rustc_const_math::{ConstInt, ConstIsize};
+use rustc_i128::i128;
 use rustc::middle::const_val::ConstVal;
 use rustc::ty;
 use rustc::mir::repr::*;
@@ -190,7 +191,11 @@ impl<'a, 'gcx, 'tcx> Builder<'a, 'gcx, 'tcx> {
 
                 let field_names = this.hir.all_fields(adt_def, variant_index);
 
-                let fields = if let Some(FruInfo { base, field_types }) = base {
+                let (fields, active_field) = if adt_def.is_union() {
+                    // A union literal initializes exactly one field.
+                    let (&field, operand) = fields_map.iter().next().unwrap();
+                    (vec![operand.clone()], Some(field.index()))
+                } else if let Some(FruInfo { base, field_types }) = base {
                     let base = unpack!(block = this.as_lvalue(block, base));
 
                     // MIR does not natively support FRU, so for each
@@ -202,13 +207,14 @@ impl<'a, 'gcx, 'tcx> Builder<'a, 'gcx, 'tcx> {
                             Some(v) => v.clone(),
                             None => Operand::Consume(base.clone().field(n, ty))
                         })
-                        .collect()
+                        .collect();
+                    (fields, None)
                 } else {
-                    field_names.iter().map(|n| fields_map[n].clone()).collect()
+                    (field_names.iter().map(|n| fields_map[n].clone()).collect(), None)
                 };
 
-                block.and(Rvalue::Aggregate(AggregateKind::Adt(adt_def, variant_index, substs),
-                                            fields))
+                let adt = AggregateKind::Adt(adt_def, variant_index, substs, active_field);
+                block.and(Rvalue::Aggregate(adt, fields))
             }
             ExprKind::Assign { .. } |
             ExprKind::AssignOp { .. } => {
@@ -339,6 +345,7 @@ impl<'a, 'gcx, 'tcx> Builder<'a, 'gcx, 'tcx> {
                     ast::IntTy::I16 => ConstInt::I16(-1),
                     ast::IntTy::I32 => ConstInt::I32(-1),
                     ast::IntTy::I64 => ConstInt::I64(-1),
+                    ast::IntTy::I128 => ConstInt::I128(-1),
                     ast::IntTy::Is => {
                         let int_ty = self.hir.tcx().sess.target.int_type;
                         let val = ConstIsize::new(-1, int_ty).unwrap();
@@ -365,6 +372,7 @@ impl<'a, 'gcx, 'tcx> Builder<'a, 'gcx, 'tcx> {
                     ast::IntTy::I16 => ConstInt::I16(std::i16::MIN),
                     ast::IntTy::I32 => ConstInt::I32(std::i32::MIN),
                     ast::IntTy::I64 => ConstInt::I64(std::i64::MIN),
+                    ast::IntTy::I128 => ConstInt::I128(i128::min_value()),
                     ast::IntTy::Is => {
                         let int_ty = self.hir.tcx().sess.target.int_type;
                         let min = match int_ty {
@@ -150,13 +150,10 @@ impl<'a, 'gcx, 'tcx> Builder<'a, 'gcx, 'tcx> {
                 this.cfg.terminate(block, source_info,
                                    TerminatorKind::Goto { target: loop_block });
 
-                let might_break = this.in_loop_scope(loop_block, exit_block, move |this| {
+                this.in_loop_scope(loop_block, exit_block, destination.clone(), move |this| {
                     // conduct the test, if necessary
                     let body_block;
                     if let Some(cond_expr) = opt_cond_expr {
-                        // This loop has a condition, ergo its exit_block is reachable.
-                        this.find_loop_scope(expr_span, None).might_break = true;
-
                         let loop_block_end;
                         let cond = unpack!(loop_block_end = this.as_operand(loop_block, cond_expr));
                         body_block = this.cfg.start_new_block();
@@ -165,6 +162,11 @@ impl<'a, 'gcx, 'tcx> Builder<'a, 'gcx, 'tcx> {
                                                cond: cond,
                                                targets: (body_block, exit_block)
                                            });
+
+                        // There is no `break` to assign the destination when the condition
+                        // becomes false, so do it here. This also follows the assignment of any
+                        // `break`, but the value of a loop with a condition is always `()`.
+                        this.cfg.push_assign_unit(exit_block, source_info, destination);
                     } else {
                         body_block = loop_block;
                     }
@@ -172,19 +174,14 @@ impl<'a, 'gcx, 'tcx> Builder<'a, 'gcx, 'tcx> {
                     // The “return” value of the loop body must always be an unit, but we cannot
                     // reuse that as a “return” value of the whole loop expressions, because some
                     // loops are diverging (e.g. `loop {}`). Thus, we introduce a unit temporary as
-                    // the destination for the loop body and assign the loop’s own “return” value
-                    // immediately after the iteration is finished.
+                    // the destination for the loop body, and the loop’s own “return” value is
+                    // assigned by the `break` leaving it (or above, once its condition fails).
                     let tmp = this.get_unit_temp();
                     // Execute the body, branching back to the test.
                     let body_block_end = unpack!(this.into(&tmp, body_block, body));
                     this.cfg.terminate(body_block_end, source_info,
                                        TerminatorKind::Goto { target: loop_block });
                 });
-                // If the loop may reach its exit_block, we assign an empty tuple to the
-                // destination to keep the MIR well-formed.
-                if might_break {
-                    this.cfg.push_assign_unit(exit_block, source_info, destination);
-                }
                 exit_block.unit()
             }
             ExprKind::Call { ty, fun, args } => {
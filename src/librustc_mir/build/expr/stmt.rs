@@ -82,11 +82,17 @@ impl<'a, 'gcx, 'tcx> Builder<'a, 'gcx, 'tcx> {
                 this.break_or_continue(expr_span, label, block,
                                        |loop_scope| loop_scope.continue_block)
             }
-            ExprKind::Break { label } => {
-                this.break_or_continue(expr_span, label, block, |loop_scope| {
-                    loop_scope.might_break = true;
-                    loop_scope.break_block
-                })
+            ExprKind::Break { label, value } => {
+                let destination = this.find_loop_scope(expr_span, label).break_destination.clone();
+                block = match value {
+                    Some(value) => unpack!(this.into(&destination, block, value)),
+                    None => {
+                        this.cfg.push_assign_unit(block, source_info, &destination);
+                        block
+                    }
+                };
+                this.break_or_continue(expr_span, label, block,
+                                       |loop_scope| loop_scope.break_block)
             }
             ExprKind::Return { value } => {
                 block = match value {
@@ -117,7 +123,7 @@ impl<'a, 'gcx, 'tcx> Builder<'a, 'gcx, 'tcx> {
                             block: BasicBlock,
                             exit_selector: F)
                             -> BlockAnd<()>
-        where F: FnOnce(&mut LoopScope) -> BasicBlock
+        where F: FnOnce(&mut LoopScope<'tcx>) -> BasicBlock
     {
         let (exit_block, extent) = {
             let loop_scope = self.find_loop_scope(span, label);
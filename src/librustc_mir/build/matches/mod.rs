@@ -274,6 +274,14 @@ enum TestKind<'tcx> {
         indices: FnvHashMap<ConstVal, usize>,
     },
 
+    // test a string (or byte string) against several constants at
+    // once, using a decision tree on its length and bytes
+    StrSwitch {
+        switch_ty: Ty<'tcx>,
+        options: Vec<ConstVal>,
+        indices: FnvHashMap<ConstVal, usize>,
+    },
+
     // test for equality
     Eq {
         value: ConstVal,
@@ -424,8 +432,9 @@ impl<'a, 'gcx, 'tcx> Builder<'a, 'gcx, 'tcx> {
     ///
     /// 1. `x @ Some(P)` -- we will do a `Switch` to decide what variant `x` has
     /// 2. `x @ 22` -- we will do a `SwitchInt`
-    /// 3. `x @ 3..5` -- we will do a range test
-    /// 4. etc.
+    /// 3. `x @ "foo"` -- we will do a `StrSwitch`
+    /// 4. `x @ 3..5` -- we will do a range test
+    /// 5. etc.
     ///
     /// Once we know what sort of test we are going to perform, this
     /// test may also help us with other candidates. So we walk over
@@ -491,6 +500,9 @@ impl<'a, 'gcx, 'tcx> Builder<'a, 'gcx, 'tcx> {
     /// ```
     ///
     /// Here we first test the match-pair `x @ "foo"`, which is an `Eq` test.
+    /// (String constants actually get a `StrSwitch`, which sorts all three
+    /// candidates at once, but the reasoning below applies to any `Eq` test,
+    /// such as those on floats.)
     ///
     /// It might seem that we would end up with 2 disjoint candidate
     /// sets, consisting of the first candidate or the other 3, but our
@@ -535,7 +547,8 @@ impl<'a, 'gcx, 'tcx> Builder<'a, 'gcx, 'tcx> {
         // may want to add cases based on the candidates that are
         // available
         match test.kind {
-            TestKind::SwitchInt { switch_ty, ref mut options, ref mut indices } => {
+            TestKind::SwitchInt { switch_ty, ref mut options, ref mut indices } |
+            TestKind::StrSwitch { switch_ty, ref mut options, ref mut indices } => {
                 for candidate in candidates.iter() {
                     if !self.add_cases_to_switch(&match_pair.lvalue,
                                                  candidate,
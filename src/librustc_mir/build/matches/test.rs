@@ -23,6 +23,7 @@ use rustc_data_structures::bitvec::BitVector;
 use rustc::middle::const_val::ConstVal;
 use rustc::ty::{self, Ty};
 use rustc::mir::repr::*;
+use rustc_const_math::{ConstInt, ConstUsize};
 use syntax_pos::Span;
 use std::cmp::Ordering;
 
@@ -59,6 +60,20 @@ impl<'a, 'gcx, 'tcx> Builder<'a, 'gcx, 'tcx> {
                 }
             }
 
+            PatternKind::Constant { ref value }
+            if is_str_switch(value, match_pair.pattern.ty) => {
+                // for strings, we build a decision tree over all the
+                // string constants tested at once, see `str_switch`
+                Test {
+                    span: match_pair.pattern.span,
+                    kind: TestKind::StrSwitch {
+                        switch_ty: match_pair.pattern.ty,
+                        options: vec![],
+                        indices: FnvHashMap(),
+                    }
+                }
+            }
+
             PatternKind::Constant { ref value } => {
                 Test {
                     span: match_pair.pattern.span,
@@ -250,78 +265,27 @@ impl<'a, 'gcx, 'tcx> Builder<'a, 'gcx, 'tcx> {
                 targets
             }
 
-            TestKind::Eq { ref value, mut ty } => {
-                let mut val = Operand::Consume(lvalue.clone());
-
-                // If we're using b"..." as a pattern, we need to insert an
-                // unsizing coercion, as the byte string has the type &[u8; N].
-                let expect = if let ConstVal::ByteStr(ref bytes) = *value {
-                    let tcx = self.hir.tcx();
-
-                    // Unsize the lvalue to &[u8], too, if necessary.
-                    if let ty::TyRef(region, mt) = ty.sty {
-                        if let ty::TyArray(_, _) = mt.ty.sty {
-                            ty = tcx.mk_imm_ref(region, tcx.mk_slice(tcx.types.u8));
-                            let val_slice = self.temp(ty);
-                            self.cfg.push_assign(block, source_info, &val_slice,
-                                                 Rvalue::Cast(CastKind::Unsize, val, ty));
-                            val = Operand::Consume(val_slice);
-                        }
-                    }
-
-                    assert!(ty.is_slice());
-
-                    let array_ty = tcx.mk_array(tcx.types.u8, bytes.len());
-                    let array_ref = tcx.mk_imm_ref(tcx.mk_region(ty::ReStatic), array_ty);
-                    let array = self.literal_operand(test.span, array_ref, Literal::Value {
-                        value: value.clone()
-                    });
-
-                    let slice = self.temp(ty);
-                    self.cfg.push_assign(block, source_info, &slice,
-                                         Rvalue::Cast(CastKind::Unsize, array, ty));
-                    Operand::Consume(slice)
+            TestKind::StrSwitch { switch_ty, ref options, indices: _ } => {
+                let otherwise = self.cfg.start_new_block();
+                let targets: Vec<_> =
+                    options.iter()
+                           .map(|_| self.cfg.start_new_block())
+                           .chain(Some(otherwise))
+                           .collect();
+                if options.len() == 1 {
+                    self.eq_test(block, lvalue, &options[0], switch_ty, test.span,
+                                 targets[0], otherwise);
                 } else {
-                    self.literal_operand(test.span, ty, Literal::Value {
-                        value: value.clone()
-                    })
-                };
+                    self.str_switch(block, lvalue, switch_ty, options, &targets, test.span);
+                }
+                targets
+            }
 
-                // Use PartialEq::eq for &str and &[u8] slices, instead of BinOp::Eq.
+            TestKind::Eq { ref value, ty } => {
                 let fail = self.cfg.start_new_block();
-                if let ty::TyRef(_, mt) = ty.sty {
-                    assert!(ty.is_slice());
-                    let eq_def_id = self.hir.tcx().lang_items.eq_trait().unwrap();
-                    let ty = mt.ty;
-                    let (mty, method) = self.hir.trait_method(eq_def_id, "eq", ty, vec![ty]);
-
-                    let bool_ty = self.hir.bool_ty();
-                    let eq_result = self.temp(bool_ty);
-                    let eq_block = self.cfg.start_new_block();
-                    let cleanup = self.diverge_cleanup();
-                    self.cfg.terminate(block, source_info, TerminatorKind::Call {
-                        func: Operand::Constant(Constant {
-                            span: test.span,
-                            ty: mty,
-                            literal: method
-                        }),
-                        args: vec![val, expect],
-                        destination: Some((eq_result.clone(), eq_block)),
-                        cleanup: cleanup,
-                    });
-
-                    // check the result
-                    let block = self.cfg.start_new_block();
-                    self.cfg.terminate(eq_block, source_info, TerminatorKind::If {
-                        cond: Operand::Consume(eq_result),
-                        targets: (block, fail),
-                    });
-
-                    vec![block, fail]
-                } else {
-                    let block = self.compare(block, fail, test.span, BinOp::Eq, expect, val);
-                    vec![block, fail]
-                }
+                let success = self.cfg.start_new_block();
+                self.eq_test(block, lvalue, value, ty, test.span, success, fail);
+                vec![success, fail]
             }
 
             TestKind::Range { ref lo, ref hi, ty } => {
@@ -367,6 +331,220 @@ impl<'a, 'gcx, 'tcx> Builder<'a, 'gcx, 'tcx> {
         }
     }
 
+    /// Branches to `success` if `lvalue` is equal to the constant
+    /// `value`, and to `fail` otherwise.
+    fn eq_test(&mut self,
+               block: BasicBlock,
+               lvalue: &Lvalue<'tcx>,
+               value: &ConstVal,
+               mut ty: Ty<'tcx>,
+               span: Span,
+               success: BasicBlock,
+               fail: BasicBlock) {
+        let source_info = self.source_info(span);
+        let mut val = Operand::Consume(lvalue.clone());
+
+        // If we're using b"..." as a pattern, we need to insert an
+        // unsizing coercion, as the byte string has the type &[u8; N].
+        let expect = if let ConstVal::ByteStr(ref bytes) = *value {
+            let tcx = self.hir.tcx();
+
+            // Unsize the lvalue to &[u8], too, if necessary.
+            if let ty::TyRef(region, mt) = ty.sty {
+                if let ty::TyArray(_, _) = mt.ty.sty {
+                    ty = tcx.mk_imm_ref(region, tcx.mk_slice(tcx.types.u8));
+                    let val_slice = self.temp(ty);
+                    self.cfg.push_assign(block, source_info, &val_slice,
+                                         Rvalue::Cast(CastKind::Unsize, val, ty));
+                    val = Operand::Consume(val_slice);
+                }
+            }
+
+            assert!(ty.is_slice());
+
+            let array_ty = tcx.mk_array(tcx.types.u8, bytes.len());
+            let array_ref = tcx.mk_imm_ref(tcx.mk_region(ty::ReStatic), array_ty);
+            let array = self.literal_operand(span, array_ref, Literal::Value {
+                value: value.clone()
+            });
+
+            let slice = self.temp(ty);
+            self.cfg.push_assign(block, source_info, &slice,
+                                 Rvalue::Cast(CastKind::Unsize, array, ty));
+            Operand::Consume(slice)
+        } else {
+            self.literal_operand(span, ty, Literal::Value {
+                value: value.clone()
+            })
+        };
+
+        // Use PartialEq::eq for &str and &[u8] slices, instead of BinOp::Eq.
+        let bool_ty = self.hir.bool_ty();
+        let result = self.temp(bool_ty);
+        let block = if let ty::TyRef(_, mt) = ty.sty {
+            assert!(ty.is_slice());
+            let eq_def_id = self.hir.tcx().lang_items.eq_trait().unwrap();
+            let ty = mt.ty;
+            let (mty, method) = self.hir.trait_method(eq_def_id, "eq", ty, vec![ty]);
+
+            let eq_block = self.cfg.start_new_block();
+            let cleanup = self.diverge_cleanup();
+            self.cfg.terminate(block, source_info, TerminatorKind::Call {
+                func: Operand::Constant(Constant {
+                    span: span,
+                    ty: mty,
+                    literal: method
+                }),
+                args: vec![val, expect],
+                destination: Some((result.clone(), eq_block)),
+                cleanup: cleanup,
+            });
+            eq_block
+        } else {
+            self.cfg.push_assign(block, source_info, &result,
+                                 Rvalue::BinaryOp(BinOp::Eq, expect, val));
+            block
+        };
+
+        // check the result
+        self.cfg.terminate(block, source_info, TerminatorKind::If {
+            cond: Operand::Consume(result),
+            targets: (success, fail),
+        });
+    }
+
+    /// Lowers a `StrSwitch` over two or more distinct string (or byte
+    /// string) constants `options` into a decision tree: a `SwitchInt`
+    /// on the length, then, among the constants of that length, a
+    /// `SwitchInt` on the byte that best tells them apart (repeatedly),
+    /// and finally an equality test against the one constant left.
+    ///
+    /// `targets` holds the block for each option, followed by the
+    /// block for when none of them matches.
+    fn str_switch(&mut self,
+                  block: BasicBlock,
+                  lvalue: &Lvalue<'tcx>,
+                  ty: Ty<'tcx>,
+                  options: &[ConstVal],
+                  targets: &[BasicBlock],
+                  span: Span) {
+        let source_info = self.source_info(span);
+        let tcx = self.hir.tcx();
+        let otherwise = *targets.last().unwrap();
+
+        // The bytes to look at, as a `[u8]` (or `[u8; N]`) lvalue. As
+        // `str` can't be indexed, we look at a `&str` through a
+        // `*const [u8]` (which has the same representation).
+        let bytes = match ty.sty {
+            ty::TyRef(_, ty::TypeAndMut { ty: pointee, .. }) => {
+                if let ty::TyStr = pointee.sty {
+                    let ptr_ty = tcx.mk_imm_ptr(tcx.mk_slice(tcx.types.u8));
+                    let ptr = self.temp(ptr_ty);
+                    self.cfg.push_assign(block, source_info, &ptr,
+                                         Rvalue::Cast(CastKind::Misc,
+                                                      Operand::Consume(lvalue.clone()),
+                                                      ptr_ty));
+                    ptr.deref()
+                } else {
+                    lvalue.clone().deref()
+                }
+            }
+            _ => span_bug!(span, "unexpected type for string switch: {:?}", ty),
+        };
+
+        let strings: Vec<&[u8]> = options.iter().map(|value| {
+            match *value {
+                ConstVal::Str(ref s) => s.as_bytes(),
+                ConstVal::ByteStr(ref bytes) => &bytes[..],
+                ref v => span_bug!(span, "expected string constant but got {:?}", v),
+            }
+        }).collect();
+
+        let usize_ty = self.hir.usize_ty();
+        let len = self.temp(usize_ty);
+        self.cfg.push_assign(block, source_info, &len, Rvalue::Len(bytes.clone()));
+
+        let groups = group_by_key(0..options.len(), |&i| strings[i].len());
+        let group_blocks: Vec<_> = groups.iter().map(|_| self.cfg.start_new_block()).collect();
+        let uint_type = tcx.sess.target.uint_type;
+        let values = groups.iter().map(|&(len, _)| {
+            match ConstUsize::new(len as u64, uint_type) {
+                Ok(len) => ConstVal::Integral(ConstInt::Usize(len)),
+                Err(_) => span_bug!(span, "string length out of range for target"),
+            }
+        }).collect();
+        self.cfg.terminate(block, source_info, TerminatorKind::SwitchInt {
+            discr: len,
+            switch_ty: usize_ty,
+            values: values,
+            targets: group_blocks.iter().cloned().chain(Some(otherwise)).collect(),
+        });
+
+        for ((_, group), group_block) in groups.into_iter().zip(group_blocks) {
+            self.str_switch_bytes(group_block, lvalue, ty, &bytes, options, &strings,
+                                  group, targets, span);
+        }
+    }
+
+    /// Discriminates between the `group` of `options` (all of the same
+    /// length) for `str_switch`.
+    fn str_switch_bytes(&mut self,
+                        block: BasicBlock,
+                        lvalue: &Lvalue<'tcx>,
+                        ty: Ty<'tcx>,
+                        bytes: &Lvalue<'tcx>,
+                        options: &[ConstVal],
+                        strings: &[&[u8]],
+                        group: Vec<usize>,
+                        targets: &[BasicBlock],
+                        span: Span) {
+        let otherwise = *targets.last().unwrap();
+        if group.len() == 1 {
+            let option = group[0];
+            self.eq_test(block, lvalue, &options[option], ty, span, targets[option], otherwise);
+            return;
+        }
+
+        // Switch on the byte which splits the group into the most
+        // subgroups; as the options are distinct, there always is one
+        // which splits it at all.
+        let mut best = (0, 0);
+        for pos in 0..strings[group[0]].len() {
+            let mut seen: Vec<u8> = group.iter().map(|&i| strings[i][pos]).collect();
+            seen.sort();
+            seen.dedup();
+            if seen.len() > best.1 {
+                best = (pos, seen.len());
+            }
+        }
+        let pos = best.0;
+
+        let source_info = self.source_info(span);
+        let u8_ty = self.hir.tcx().types.u8;
+        let index = self.push_usize(block, source_info, pos as u64);
+        let byte = self.temp(u8_ty);
+        self.cfg.push_assign(block, source_info, &byte,
+                             Rvalue::Use(Operand::Consume(
+                                 bytes.clone().index(Operand::Consume(index)))));
+
+        let subgroups = group_by_key(group.into_iter(), |&i| strings[i][pos]);
+        let subgroup_blocks: Vec<_> =
+            subgroups.iter().map(|_| self.cfg.start_new_block()).collect();
+        self.cfg.terminate(block, source_info, TerminatorKind::SwitchInt {
+            discr: byte,
+            switch_ty: u8_ty,
+            values: subgroups.iter()
+                             .map(|&(b, _)| ConstVal::Integral(ConstInt::U8(b)))
+                             .collect(),
+            targets: subgroup_blocks.iter().cloned().chain(Some(otherwise)).collect(),
+        });
+
+        for ((_, subgroup), subgroup_block) in subgroups.into_iter().zip(subgroup_blocks) {
+            self.str_switch_bytes(subgroup_block, lvalue, ty, bytes, options, strings,
+                                  subgroup, targets, span);
+        }
+    }
+
     fn compare(&mut self,
                block: BasicBlock,
                fail_block: BasicBlock,
@@ -480,6 +658,18 @@ impl<'a, 'gcx, 'tcx> Builder<'a, 'gcx, 'tcx> {
             }
             (&TestKind::SwitchInt { .. }, _) => false,
 
+            // Likewise for a switch over strings.
+            (&TestKind::StrSwitch { switch_ty: _, options: _, ref indices },
+             &PatternKind::Constant { ref value })
+            if is_str_switch(value, match_pair.pattern.ty) => {
+                let index = indices[value];
+                let new_candidate = self.candidate_without_match_pair(match_pair_index,
+                                                                      candidate);
+                resulting_candidates[index].push(new_candidate);
+                true
+            }
+            (&TestKind::StrSwitch { .. }, _) => false,
+
 
             (&TestKind::Len { len: test_len, op: BinOp::Eq },
              &PatternKind::Slice { ref prefix, ref slice, ref suffix }) => {
@@ -665,3 +855,32 @@ impl<'a, 'gcx, 'tcx> Builder<'a, 'gcx, 'tcx> {
 fn is_switch_ty<'tcx>(ty: Ty<'tcx>) -> bool {
     ty.is_integral() || ty.is_char() || ty.is_bool()
 }
+
+fn is_str_switch<'tcx>(value: &ConstVal, ty: Ty<'tcx>) -> bool {
+    match *value {
+        ConstVal::Str(_) | ConstVal::ByteStr(_) => {}
+        _ => return false,
+    }
+    match ty.sty {
+        ty::TyRef(_, mt) => match mt.ty.sty {
+            ty::TyStr | ty::TySlice(_) | ty::TyArray(..) => true,
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Groups `items` by `key`, ordering the groups by key.
+fn group_by_key<I, K, F>(items: I, mut key: F) -> Vec<(K, Vec<usize>)>
+    where I: Iterator<Item=usize>, K: Ord, F: FnMut(&usize) -> K
+{
+    let mut groups: Vec<(K, Vec<usize>)> = vec![];
+    for item in items {
+        let k = key(&item);
+        match groups.binary_search_by(|&(ref other, _)| other.cmp(&k)) {
+            Ok(index) => groups[index].1.push(item),
+            Err(index) => groups.insert(index, (k, vec![item])),
+        }
+    }
+    groups
+}
@@ -0,0 +1,146 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Line-based unified diffs, used by `-Z dump-mir-diff`.
+
+use std::cmp;
+use std::io::{self, Write};
+
+#[derive(Copy, Clone)]
+enum Edit {
+    Same,
+    Delete,
+    Insert,
+}
+
+/// Computes a shortest edit script from `a` to `b`, as the sequence of
+/// edits together with the line of `a` or `b` each one refers to.
+///
+/// The common prefix and suffix are stripped first; passes tend to
+/// touch only a few blocks, so the quadratic LCS table only has to
+/// cover the part that actually changed.
+fn diff_lines<'s>(a: &[&'s str], b: &[&'s str]) -> Vec<(Edit, &'s str)> {
+    let prefix = a.iter().zip(b).take_while(|&(x, y)| x == y).count();
+    let suffix = a[prefix..].iter().rev()
+        .zip(b[prefix..].iter().rev())
+        .take_while(|&(x, y)| x == y)
+        .count();
+    let a_mid = &a[prefix..a.len() - suffix];
+    let b_mid = &b[prefix..b.len() - suffix];
+
+    // lcs[i][j] is the length of the longest common subsequence of
+    // a_mid[i..] and b_mid[j..].
+    let (n, m) = (a_mid.len(), b_mid.len());
+    let mut lcs = vec![vec![0u32; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if a_mid[i] == b_mid[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                cmp::max(lcs[i + 1][j], lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut edits: Vec<_> = a[..prefix].iter().map(|&line| (Edit::Same, line)).collect();
+    let (mut i, mut j) = (0, 0);
+    while i < n || j < m {
+        if i < n && j < m && a_mid[i] == b_mid[j] {
+            edits.push((Edit::Same, a_mid[i]));
+            i += 1;
+            j += 1;
+        } else if j == m || (i < n && lcs[i + 1][j] >= lcs[i][j + 1]) {
+            edits.push((Edit::Delete, a_mid[i]));
+            i += 1;
+        } else {
+            edits.push((Edit::Insert, b_mid[j]));
+            j += 1;
+        }
+    }
+    edits.extend(a[a.len() - suffix..].iter().map(|&line| (Edit::Same, line)));
+    edits
+}
+
+/// Writes a unified diff (with `context` lines of context around each
+/// change) from `before` to `after`. Returns whether they differ.
+pub fn write_unified_diff(before: &str,
+                          after: &str,
+                          before_label: &str,
+                          after_label: &str,
+                          context: usize,
+                          w: &mut Write)
+                          -> io::Result<bool> {
+    if before == after {
+        return Ok(false);
+    }
+    let a: Vec<&str> = before.lines().collect();
+    let b: Vec<&str> = after.lines().collect();
+    let edits = diff_lines(&a, &b);
+
+    // The (0-based) line of `a` and of `b` at which each edit starts.
+    let mut positions = Vec::with_capacity(edits.len() + 1);
+    let (mut a_pos, mut b_pos) = (0, 0);
+    for &(edit, _) in &edits {
+        positions.push((a_pos, b_pos));
+        match edit {
+            Edit::Same => { a_pos += 1; b_pos += 1; }
+            Edit::Delete => a_pos += 1,
+            Edit::Insert => b_pos += 1,
+        }
+    }
+    positions.push((a_pos, b_pos));
+
+    writeln!(w, "--- {}", before_label)?;
+    writeln!(w, "+++ {}", after_label)?;
+
+    let mut i = 0;
+    while i < edits.len() {
+        if let Edit::Same = edits[i].0 {
+            i += 1;
+            continue;
+        }
+
+        // Extend the hunk for as long as the next change is close
+        // enough for the context of both to overlap.
+        let start = i.saturating_sub(context);
+        let mut end = i + 1;
+        for j in i + 1..edits.len() {
+            match edits[j].0 {
+                Edit::Same => {
+                    if j + 1 - end > 2 * context {
+                        break;
+                    }
+                }
+                _ => end = j + 1,
+            }
+        }
+        let stop = cmp::min(edits.len(), end + context);
+
+        let (a_start, b_start) = positions[start];
+        let (a_stop, b_stop) = positions[stop];
+        let (a_len, b_len) = (a_stop - a_start, b_stop - b_start);
+        // Empty ranges are numbered by the line preceding them.
+        writeln!(w, "@@ -{},{} +{},{} @@",
+                 if a_len == 0 { a_start } else { a_start + 1 }, a_len,
+                 if b_len == 0 { b_start } else { b_start + 1 }, b_len)?;
+        for &(edit, line) in &edits[start..stop] {
+            let marker = match edit {
+                Edit::Same => ' ',
+                Edit::Delete => '-',
+                Edit::Insert => '+',
+            };
+            writeln!(w, "{}{}", marker, line)?;
+        }
+
+        i = stop;
+    }
+
+    Ok(true)
+}
@@ -0,0 +1,164 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A standalone HTML page showing MIR next to the source it was built
+//! from (`-Z dump-mir-format=html`). Successor labels link to their
+//! blocks, and clicking a statement or terminator highlights the
+//! source lines of its span.
+
+use dot;
+use pretty;
+use rustc::mir::repr::*;
+use rustc::mir::transform::MirSource;
+use rustc::ty::TyCtxt;
+use std::fmt::Debug;
+use std::io::{self, Write};
+use syntax_pos::{Span, DUMMY_SP};
+
+const STYLE: &'static str = "
+body { margin: 0; font-family: monospace; font-size: 13px; }
+.pane { position: absolute; top: 0; bottom: 0; overflow: auto; padding: 8px; }
+#mir { left: 0; width: 55%; }
+#source { right: 0; width: 42%; border-left: 1px solid #999; }
+.intro { white-space: pre; }
+.block { margin: 8px 0; border: 1px solid #bbb; }
+.block:target { border-color: #36c; box-shadow: 0 0 4px #36c; }
+.block.cleanup .label { background: #fdd; }
+.label { background: #ddd; padding: 2px 4px; font-weight: bold; }
+.stmt, .term { padding: 0 4px 0 16px; white-space: pre; cursor: pointer; }
+.stmt:hover, .term:hover { background: #eef; }
+.term { border-top: 1px dashed #bbb; }
+.line { white-space: pre; }
+.line .no { display: inline-block; width: 4em; color: #999; }
+.line.hl { background: #ffa; }
+";
+
+const SCRIPT: &'static str = "
+function highlight(lo, hi) {
+    var lines = document.querySelectorAll('.line');
+    for (var i = 0; i < lines.length; i++) {
+        var n = +lines[i].getAttribute('data-line');
+        lines[i].className = (n >= lo && n <= hi) ? 'line hl' : 'line';
+    }
+    var first = document.getElementById('L' + lo);
+    if (first) { first.scrollIntoView(); }
+}
+document.addEventListener('click', function (e) {
+    var lo = e.target.getAttribute('data-lo');
+    if (lo) { highlight(+lo, +e.target.getAttribute('data-hi')); }
+});
+";
+
+/// Write out an HTML page for the given MIR.
+pub fn write_mir_html<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
+                                src: MirSource,
+                                mir: &Mir<'tcx>,
+                                title: &str,
+                                w: &mut Write)
+                                -> io::Result<()> {
+    writeln!(w, "<!DOCTYPE html>")?;
+    writeln!(w, "<html><head><meta charset=\"utf-8\">")?;
+    writeln!(w, "<title>{}</title>", dot::escape_html(title))?;
+    writeln!(w, "<style>{}</style>", STYLE)?;
+    writeln!(w, "</head><body>")?;
+
+    writeln!(w, "<div class=\"pane\" id=\"mir\">")?;
+    let mut intro = vec![];
+    pretty::write_mir_intro(tcx, src, mir, &mut intro)?;
+    writeln!(w, "<div class=\"intro\">{}</div>",
+             dot::escape_html(&String::from_utf8_lossy(&intro)))?;
+    for (block, data) in mir.basic_blocks().iter_enumerated() {
+        write_basic_block(tcx, block, data, w)?;
+    }
+    writeln!(w, "<div class=\"intro\">}}</div>")?;
+    writeln!(w, "</div>")?;
+
+    writeln!(w, "<div class=\"pane\" id=\"source\">")?;
+    write_source(tcx, mir.span, w)?;
+    writeln!(w, "</div>")?;
+
+    writeln!(w, "<script>{}</script>", SCRIPT)?;
+    writeln!(w, "</body></html>")
+}
+
+fn write_basic_block(tcx: TyCtxt,
+                     block: BasicBlock,
+                     data: &BasicBlockData,
+                     w: &mut Write)
+                     -> io::Result<()> {
+    let (class, suffix) = if data.is_cleanup {
+        ("block cleanup", " (cleanup)")
+    } else {
+        ("block", "")
+    };
+    writeln!(w, "<div class=\"{}\" id=\"{:?}\">", class, block)?;
+    writeln!(w, "<div class=\"label\">{:?}{}</div>", block, suffix)?;
+
+    for statement in &data.statements {
+        writeln!(w, "<div class=\"stmt\"{}>{};</div>",
+                 span_attrs(tcx, statement.source_info.span),
+                 escape(statement))?;
+    }
+
+    // As in the graphviz output, the successors are displayed as
+    // labelled links rather than as part of the terminator itself.
+    let terminator = data.terminator();
+    let mut head = String::new();
+    terminator.kind.fmt_head(&mut head).unwrap();
+    write!(w, "<div class=\"term\"{}>{}",
+           span_attrs(tcx, terminator.source_info.span),
+           dot::escape_html(&head))?;
+    let labels = terminator.kind.fmt_successor_labels();
+    for (i, (target, label)) in terminator.successors().iter().zip(labels).enumerate() {
+        write!(w, "{}", if i == 0 { " -> [" } else { ", " })?;
+        write!(w, "{}: <a href=\"#{:?}\">{:?}</a>", dot::escape_html(&label), target, target)?;
+        if i + 1 == terminator.successors().len() {
+            write!(w, "]")?;
+        }
+    }
+    writeln!(w, ";</div>")?;
+
+    writeln!(w, "</div>")
+}
+
+/// The attributes used by the script to highlight the source lines of
+/// `span`; none for spans without a location.
+fn span_attrs(tcx: TyCtxt, span: Span) -> String {
+    if span == DUMMY_SP {
+        return String::new();
+    }
+    let codemap = tcx.sess.codemap();
+    let lo = codemap.lookup_char_pos(span.lo);
+    let hi = codemap.lookup_char_pos(span.hi);
+    format!(" data-lo=\"{}\" data-hi=\"{}\" title=\"{}\"",
+            lo.line, hi.line, dot::escape_html(&codemap.span_to_string(span)))
+}
+
+/// Write the source lines covered by `span`, numbered.
+fn write_source(tcx: TyCtxt, span: Span, w: &mut Write) -> io::Result<()> {
+    if span == DUMMY_SP {
+        return Ok(());
+    }
+    let codemap = tcx.sess.codemap();
+    let lo = codemap.lookup_char_pos(span.lo);
+    let hi = codemap.lookup_char_pos(span.hi);
+    writeln!(w, "<div class=\"label\">{}</div>", dot::escape_html(&lo.file.name))?;
+    for line in lo.line..hi.line + 1 {
+        let text = lo.file.get_line(line - 1).unwrap_or("");
+        writeln!(w, "<div class=\"line\" id=\"L{0}\" data-line=\"{0}\">\
+                     <span class=\"no\">{0}</span>{1}</div>",
+                 line, dot::escape_html(text))?;
+    }
+    Ok(())
+}
+
+fn escape<T: Debug>(t: &T) -> String {
+    dot::escape_html(&format!("{:?}", t))
+}
@@ -0,0 +1,207 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A JSON representation of MIR (`-Z dump-mir-format=json`), for tools
+//! which want to consume MIR without parsing the pretty printer output.
+//!
+//! Every dump is a single object with the following keys:
+//!
+//! - `item`, `node_id`, `promoted` (the index of the promoted constant,
+//!   or `null`), `kind` (`fn`, `const`, `static`, `static mut` or
+//!   `promoted`) and `span`;
+//! - `pass_name` and `disambiguator`, when dumped between passes;
+//! - `args`, `vars` and `temps`: the declared locals, each with its
+//!   `local` name (as in the pretty printer, e.g. `var0`) and `ty`;
+//! - `return_ty` and `scopes` (the visibility scopes);
+//! - `basic_blocks`: each with its `name`, `is_cleanup`, `statements`
+//!   and `terminator`. Lvalues, rvalues and terminators are rendered as
+//!   in the pretty printer; terminators also list their `successors`.
+//!
+//! Spans are objects with `file`, `lo_line`, `lo_col`, `hi_line` and
+//! `hi_col` (lines are 1-based, columns 0-based), or `null` for dummy
+//! spans.
+
+use rustc::hir;
+use rustc::mir::repr::*;
+use rustc::mir::transform::MirSource;
+use rustc::ty::{self, TyCtxt};
+use rustc_data_structures::indexed_vec::Idx;
+use rustc_serialize::json::{Json, Object, ToJson};
+use std::fmt::Display;
+use std::io::{self, Write};
+use syntax_pos::{Span, DUMMY_SP};
+
+/// Identifies the point in the pass pipeline a dump was taken at.
+pub struct DumpHeader<'a> {
+    pub pass_name: &'a str,
+    pub disambiguator: &'a Display,
+}
+
+/// Write out a JSON representation of the given MIR.
+pub fn write_mir_json<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
+                                src: MirSource,
+                                mir: &Mir<'tcx>,
+                                header: Option<&DumpHeader>,
+                                w: &mut Write)
+                                -> io::Result<()> {
+    writeln!(w, "{}", mir_to_json(tcx, src, mir, header).pretty())
+}
+
+pub fn mir_to_json<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
+                             src: MirSource,
+                             mir: &Mir<'tcx>,
+                             header: Option<&DumpHeader>)
+                             -> Json {
+    let (kind, promoted) = match src {
+        MirSource::Fn(_) => ("fn", Json::Null),
+        MirSource::Const(_) => ("const", Json::Null),
+        MirSource::Static(_, hir::MutImmutable) => ("static", Json::Null),
+        MirSource::Static(_, hir::MutMutable) => ("static mut", Json::Null),
+        MirSource::Promoted(_, i) => ("promoted", i.index().to_json()),
+    };
+
+    let mut fields = vec![
+        ("item", tcx.node_path_str(src.item_id()).to_json()),
+        ("node_id", src.item_id().to_json()),
+        ("promoted", promoted),
+        ("kind", kind.to_json()),
+        ("span", span_to_json(tcx, mir.span)),
+    ];
+    if let Some(header) = header {
+        fields.push(("pass_name", header.pass_name.to_json()));
+        fields.push(("disambiguator", header.disambiguator.to_string().to_json()));
+    }
+
+    let args = mir.arg_decls.iter_enumerated().map(|(i, arg)| {
+        object(vec![
+            ("local", format!("{:?}", Lvalue::Arg(i)).to_json()),
+            ("name", arg.debug_name.to_string().to_json()),
+            ("ty", arg.ty.to_string().to_json()),
+        ])
+    }).collect();
+    let vars = mir.var_decls.iter_enumerated().map(|(i, var)| {
+        object(vec![
+            ("local", format!("{:?}", Lvalue::Var(i)).to_json()),
+            ("name", var.name.to_string().to_json()),
+            ("mutable", (var.mutability == Mutability::Mut).to_json()),
+            ("ty", var.ty.to_string().to_json()),
+            ("source_info", source_info_to_json(tcx, var.source_info)),
+        ])
+    }).collect();
+    let temps = mir.temp_decls.iter_enumerated().map(|(i, temp)| {
+        object(vec![
+            ("local", format!("{:?}", Lvalue::Temp(i)).to_json()),
+            ("ty", temp.ty.to_string().to_json()),
+        ])
+    }).collect();
+    let return_ty = match mir.return_ty {
+        ty::FnOutput::FnConverging(ty) => ty.to_string(),
+        ty::FnOutput::FnDiverging => "!".to_string(),
+    };
+    let scopes = mir.visibility_scopes.iter_enumerated().map(|(scope, data)| {
+        object(vec![
+            ("index", scope.index().to_json()),
+            ("parent", data.parent_scope.map(|p| p.index()).to_json()),
+            ("span", span_to_json(tcx, data.span)),
+        ])
+    }).collect();
+    let basic_blocks = mir.basic_blocks().iter_enumerated().map(|(block, data)| {
+        basic_block_to_json(tcx, block, data)
+    }).collect();
+
+    fields.push(("args", Json::Array(args)));
+    fields.push(("vars", Json::Array(vars)));
+    fields.push(("temps", Json::Array(temps)));
+    fields.push(("return_ty", return_ty.to_json()));
+    fields.push(("scopes", Json::Array(scopes)));
+    fields.push(("basic_blocks", Json::Array(basic_blocks)));
+    object(fields)
+}
+
+fn basic_block_to_json(tcx: TyCtxt, block: BasicBlock, data: &BasicBlockData) -> Json {
+    let statements = data.statements.iter().map(|statement| {
+        let mut fields = match statement.kind {
+            StatementKind::Assign(ref lvalue, ref rvalue) => vec![
+                ("kind", "assign".to_json()),
+                ("lvalue", format!("{:?}", lvalue).to_json()),
+                ("rvalue", format!("{:?}", rvalue).to_json()),
+            ],
+        };
+        fields.push(("source_info", source_info_to_json(tcx, statement.source_info)));
+        object(fields)
+    }).collect();
+
+    let terminator = data.terminator();
+    let mut head = String::new();
+    terminator.kind.fmt_head(&mut head).unwrap();
+    let successors = terminator.successors().iter()
+        .zip(terminator.kind.fmt_successor_labels())
+        .map(|(target, label)| {
+            object(vec![
+                ("target", format!("{:?}", target).to_json()),
+                ("label", label[..].to_json()),
+            ])
+        }).collect();
+
+    object(vec![
+        ("name", format!("{:?}", block).to_json()),
+        ("is_cleanup", data.is_cleanup.to_json()),
+        ("statements", Json::Array(statements)),
+        ("terminator", object(vec![
+            ("kind", terminator_kind_name(&terminator.kind).to_json()),
+            ("head", head.to_json()),
+            ("successors", Json::Array(successors)),
+            ("source_info", source_info_to_json(tcx, terminator.source_info)),
+        ])),
+    ])
+}
+
+fn terminator_kind_name(kind: &TerminatorKind) -> &'static str {
+    match *kind {
+        TerminatorKind::Goto { .. } => "goto",
+        TerminatorKind::If { .. } => "if",
+        TerminatorKind::Switch { .. } => "switch",
+        TerminatorKind::SwitchInt { .. } => "switch_int",
+        TerminatorKind::Resume => "resume",
+        TerminatorKind::Return => "return",
+        TerminatorKind::Unreachable => "unreachable",
+        TerminatorKind::Drop { .. } => "drop",
+        TerminatorKind::DropAndReplace { .. } => "drop_and_replace",
+        TerminatorKind::Call { .. } => "call",
+        TerminatorKind::Assert { .. } => "assert",
+    }
+}
+
+fn source_info_to_json(tcx: TyCtxt, source_info: SourceInfo) -> Json {
+    object(vec![
+        ("scope", source_info.scope.index().to_json()),
+        ("span", span_to_json(tcx, source_info.span)),
+    ])
+}
+
+pub fn span_to_json(tcx: TyCtxt, span: Span) -> Json {
+    if span == DUMMY_SP {
+        return Json::Null;
+    }
+    let codemap = tcx.sess.codemap();
+    let lo = codemap.lookup_char_pos(span.lo);
+    let hi = codemap.lookup_char_pos(span.hi);
+    object(vec![
+        ("file", lo.file.name.to_json()),
+        ("lo_line", lo.line.to_json()),
+        ("lo_col", lo.col.0.to_json()),
+        ("hi_line", hi.line.to_json()),
+        ("hi_col", hi.col.0.to_json()),
+    ])
+}
+
+fn object(fields: Vec<(&str, Json)>) -> Json {
+    Json::Object(fields.into_iter().map(|(k, v)| (k.to_string(), v)).collect::<Object>())
+}
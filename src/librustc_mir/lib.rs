@@ -41,12 +41,17 @@ extern crate syntax;
 extern crate syntax_pos;
 extern crate rustc_const_math;
 extern crate rustc_const_eval;
+extern crate rustc_i128;
+extern crate serialize as rustc_serialize;
 
 pub mod diagnostics;
 
 pub mod build;
+mod diff;
 pub mod graphviz;
 mod hair;
+pub mod html;
+pub mod json;
 pub mod mir_map;
 pub mod pretty;
 pub mod transform;
@@ -9,6 +9,8 @@
 // except according to those terms.
 
 use build::{Location, ScopeAuxiliaryVec, ScopeId};
+use html;
+use json;
 use rustc::hir;
 use rustc::mir::repr::*;
 use rustc::mir::transform::MirSource;
@@ -38,6 +40,9 @@ const ALIGN: usize = 40;
 /// - `substring1&substring2,...` -- `&`-separated list of substrings
 ///   that can appear in the pass-name or the `item_path_str` for the given
 ///   node-id. If any one of the substrings match, the data is dumped out.
+///
+/// `-Z dump-mir-format=json` (or `html`) writes the MIR in the format of
+/// the `json` (or `html`) module instead, with the matching extension.
 pub fn dump_mir<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
                           pass_name: &str,
                           disambiguator: &Display,
@@ -48,37 +53,68 @@ pub fn dump_mir<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
         None => return,
         Some(ref filters) => filters,
     };
-    let node_id = src.item_id();
-    let node_path = tcx.item_path_str(tcx.map.local_def_id(node_id));
-    let is_matched =
-        filters.split("&")
-               .any(|filter| {
-                   filter == "all" ||
-                       pass_name.contains(filter) ||
-                       node_path.contains(filter)
-               });
-    if !is_matched {
+    if !dump_filter_matches(tcx, filters, pass_name, src) {
         return;
     }
+    let node_id = src.item_id();
+    let node_path = tcx.item_path_str(tcx.map.local_def_id(node_id));
 
     let promotion_id = match src {
         MirSource::Promoted(_, id) => format!("-{:?}", id),
         _ => String::new()
     };
 
-    let file_name = format!("rustc.node{}{}.{}.{}.mir",
-                            node_id, promotion_id, pass_name, disambiguator);
+    let format = tcx.sess.opts.debugging_opts.dump_mir_format.as_ref().map(|s| &s[..]);
+    let extension = match format {
+        Some("json") => "json",
+        Some("html") => "html",
+        _ => "mir",
+    };
+
+    let file_name = format!("rustc.node{}{}.{}.{}.{}",
+                            node_id, promotion_id, pass_name, disambiguator, extension);
     let _ = fs::File::create(&file_name).and_then(|mut file| {
-        try!(writeln!(file, "// MIR for `{}`", node_path));
-        try!(writeln!(file, "// node_id = {}", node_id));
-        try!(writeln!(file, "// pass_name = {}", pass_name));
-        try!(writeln!(file, "// disambiguator = {}", disambiguator));
-        try!(writeln!(file, ""));
-        try!(write_mir_fn(tcx, src, mir, &mut file, auxiliary));
-        Ok(())
+        match format {
+            Some("json") => {
+                let header = json::DumpHeader {
+                    pass_name: pass_name,
+                    disambiguator: disambiguator,
+                };
+                json::write_mir_json(tcx, src, mir, Some(&header), &mut file)
+            }
+            Some("html") => {
+                let title = format!("{} ({} {})", node_path, pass_name, disambiguator);
+                html::write_mir_html(tcx, src, mir, &title, &mut file)
+            }
+            _ => {
+                try!(writeln!(file, "// MIR for `{}`", node_path));
+                try!(writeln!(file, "// node_id = {}", node_id));
+                try!(writeln!(file, "// pass_name = {}", pass_name));
+                try!(writeln!(file, "// disambiguator = {}", disambiguator));
+                try!(writeln!(file, ""));
+                try!(write_mir_fn(tcx, src, mir, &mut file, auxiliary));
+                Ok(())
+            }
+        }
     });
 }
 
+/// Whether `filters` (in the syntax of `-Z dump-mir`, see `dump_mir`)
+/// select the given pass or item.
+pub fn dump_filter_matches<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
+                                     filters: &str,
+                                     pass_name: &str,
+                                     src: MirSource)
+                                     -> bool {
+    let node_path = tcx.item_path_str(tcx.map.local_def_id(src.item_id()));
+    filters.split("&")
+           .any(|filter| {
+               filter == "all" ||
+                   pass_name.contains(filter) ||
+                   node_path.contains(filter)
+           })
+}
+
 /// Write out a human-readable textual representation for the given MIR.
 pub fn write_mir_pretty<'a, 'b, 'tcx, I>(tcx: TyCtxt<'b, 'tcx, 'tcx>,
                                          iter: I,
@@ -258,7 +294,8 @@ fn write_scope_tree(tcx: TyCtxt,
 
 /// Write out a human-readable textual representation of the MIR's `fn` type and the types of its
 /// local variables (both user-defined bindings and compiler temporaries).
-fn write_mir_intro<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
+/// This is also the header of the HTML output.
+pub fn write_mir_intro<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
                              src: MirSource,
                              mir: &Mir,
                              w: &mut Write)
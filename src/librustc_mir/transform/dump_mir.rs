@@ -11,10 +11,16 @@
 //! This pass just dumps MIR at a specified point.
 
 use std::fmt;
+use std::fs;
+use std::io::Write;
 
 use rustc::ty::TyCtxt;
 use rustc::mir::repr::*;
 use rustc::mir::transform::{Pass, MirPass, MirPassHook, MirSource};
+use rustc_data_structures::fnv::FnvHashMap;
+use rustc_data_structures::indexed_vec::Idx;
+use syntax::ast::NodeId;
+use diff;
 use pretty;
 
 pub struct Marker<'a>(pub &'a str);
@@ -71,3 +77,117 @@ impl<'tcx> MirPassHook<'tcx> for DumpMir {
 }
 
 impl<'b> Pass for DumpMir {}
+
+/// Writes, under `-Z dump-mir-diff=<filter>`, a unified diff of the
+/// pretty-printed MIR of every matching item that a pass changed, into
+///
+/// ```text
+/// rustc.node<node_id>.<pass_name>[-<disambiguator>].diff
+/// ```
+///
+/// and a list of which passes changed which items into
+/// `rustc.mir-diff-summary.txt` next to them.
+pub struct DumpMirDiff {
+    /// the MIR of each item as it was before the current pass
+    before: FnvHashMap<(NodeId, Option<usize>), String>,
+    /// the passes which changed anything, and the items they changed
+    changes: Vec<(String, Vec<String>)>,
+}
+
+impl DumpMirDiff {
+    pub fn new() -> Self {
+        DumpMirDiff {
+            before: FnvHashMap(),
+            changes: vec![],
+        }
+    }
+}
+
+impl<'tcx> MirPassHook<'tcx> for DumpMirDiff {
+    fn on_mir_pass<'a>(
+        &mut self,
+        tcx: TyCtxt<'a, 'tcx, 'tcx>,
+        src: MirSource,
+        mir: &Mir<'tcx>,
+        pass: &Pass,
+        is_after: bool)
+    {
+        let pass_name = match pass.disambiguator() {
+            Some(disambiguator) => format!("{}-{}", pass.name(), disambiguator),
+            None => pass.name().to_string(),
+        };
+        let matched = match tcx.sess.opts.debugging_opts.dump_mir_diff {
+            Some(ref filters) => pretty::dump_filter_matches(tcx, filters, &pass_name, src),
+            None => false,
+        };
+        if !matched {
+            return;
+        }
+
+        let promoted = match src {
+            MirSource::Promoted(_, id) => Some(id.index()),
+            _ => None,
+        };
+        let key = (src.item_id(), promoted);
+        let mut text = vec![];
+        if pretty::write_mir_fn(tcx, src, mir, &mut text, None).is_err() {
+            return;
+        }
+        let text = String::from_utf8_lossy(&text).into_owned();
+
+        if !is_after {
+            self.before.insert(key, text);
+            return;
+        }
+        // Promoted constants created by the pass have no "before".
+        let before = match self.before.remove(&key) {
+            Some(before) => before,
+            None => return,
+        };
+        if before == text {
+            return;
+        }
+
+        let item = match promoted {
+            Some(id) => format!("{} (promoted{})", tcx.node_path_str(key.0), id),
+            None => tcx.node_path_str(key.0),
+        };
+        let promotion_id = match promoted {
+            Some(id) => format!("-promoted{}", id),
+            None => String::new(),
+        };
+        let file_name = format!("rustc.node{}{}.{}.diff", key.0, promotion_id, pass_name);
+        let _ = fs::File::create(&file_name).and_then(|mut file| {
+            diff::write_unified_diff(&before, &text,
+                                     &format!("{} (before {})", item, pass_name),
+                                     &format!("{} (after {})", item, pass_name),
+                                     3, &mut file)
+        });
+
+        match self.changes.last_mut() {
+            Some(&mut (ref name, ref mut items)) if *name == pass_name => {
+                items.push(item);
+                return;
+            }
+            _ => {}
+        }
+        self.changes.push((pass_name, vec![item]));
+    }
+
+    fn on_passes_done<'a>(&mut self, _tcx: TyCtxt<'a, 'tcx, 'tcx>) {
+        // The same hook runs for the analysis and the codegen passes, so
+        // rewrite the summary with everything seen so far each time.
+        let _ = fs::File::create("rustc.mir-diff-summary.txt").and_then(|mut file| {
+            writeln!(file, "MIR changed by passes:")?;
+            if self.changes.is_empty() {
+                writeln!(file, "    (none)")?;
+            }
+            for &(ref pass_name, ref items) in &self.changes {
+                writeln!(file, "    {}: {}", pass_name, items.join(", "))?;
+            }
+            Ok(())
+        });
+    }
+}
+
+impl Pass for DumpMirDiff {}
@@ -499,6 +499,16 @@ impl<'a, 'tcx> Visitor<'tcx> for Qualifier<'a, 'tcx, 'tcx> {
 
                         ProjectionElem::Field(..) |
                         ProjectionElem::Index(_) => {
+                            let base_ty = this.mir.lvalue_ty(this.tcx, &proj.base)
+                                              .to_ty(this.tcx);
+                            if base_ty.is_union() {
+                                this.add(Qualif::NOT_CONST);
+                                if this.mode != Mode::Fn {
+                                    this.tcx.sess.span_err(this.span,
+                                        &format!("union fields cannot be accessed in {}s",
+                                                 this.mode));
+                                }
+                            }
                             if this.mode != Mode::Fn &&
                                this.qualif.intersects(Qualif::STATIC) {
                                 span_err!(this.tcx.sess, this.span, E0494,
@@ -692,7 +702,7 @@ impl<'a, 'tcx> Visitor<'tcx> for Qualifier<'a, 'tcx, 'tcx> {
             }
 
             Rvalue::Aggregate(ref kind, _) => {
-                if let AggregateKind::Adt(def, _, _) = *kind {
+                if let AggregateKind::Adt(def, _, _, _) = *kind {
                     if def.has_dtor() {
                         self.add(Qualif::NEEDS_DROP);
                         self.deny_drop();
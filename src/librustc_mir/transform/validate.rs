@@ -0,0 +1,361 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! This pass hook checks the structural invariants of the MIR after
+//! every pass (under `-Z validate-mir`), so that a pass which breaks
+//! them is reported by name, instead of the breakage surfacing much
+//! later (typically in trans).
+//!
+//! The invariants checked are:
+//!
+//! - every basic block has a terminator, and every branch target is
+//!   an existing basic block;
+//! - cleanup edges (unwind targets) lead to cleanup blocks, normal
+//!   edges never cross between cleanup and non-cleanup blocks,
+//!   `Resume` only appears in cleanup blocks and `Return` only outside
+//!   of them;
+//! - every var, temp and arg referenced is declared;
+//! - no local is read where it is definitely unassigned, and the
+//!   return pointer is assigned (on some path) before every `Return`.
+
+use rustc::mir::repr::*;
+use rustc::mir::transform::{MirPassHook, MirSource, Pass};
+use rustc::mir::traversal;
+use rustc::mir::visit::{LvalueContext, Visitor};
+use rustc::ty::{FnOutput, TyCtxt};
+use rustc::util::nodemap::NodeSet;
+use rustc_data_structures::bitvec::BitVector;
+use rustc_data_structures::indexed_vec::{Idx, IndexVec};
+use syntax_pos::Span;
+
+pub struct ValidateMir {
+    /// Items we already reported broken MIR for; once a pass breaks
+    /// the MIR, every later pass would just repeat the complaint.
+    reported: NodeSet,
+}
+
+impl ValidateMir {
+    pub fn new() -> Self {
+        ValidateMir { reported: NodeSet() }
+    }
+}
+
+impl<'tcx> MirPassHook<'tcx> for ValidateMir {
+    fn on_mir_pass<'a>(
+        &mut self,
+        tcx: TyCtxt<'a, 'tcx, 'tcx>,
+        src: MirSource,
+        mir: &Mir<'tcx>,
+        pass: &Pass,
+        is_after: bool)
+    {
+        if !is_after || self.reported.contains(&src.item_id()) {
+            return;
+        }
+
+        let mut validator = Validator { mir: mir, errors: vec![] };
+        validator.validate();
+        if validator.errors.is_empty() {
+            return;
+        }
+        self.reported.insert(src.item_id());
+
+        let pass_name = match pass.disambiguator() {
+            Some(disambiguator) => format!("{}-{}", pass.name(), disambiguator),
+            None => pass.name().to_string(),
+        };
+        let item = match src {
+            MirSource::Promoted(id, promoted) => {
+                format!("{} ({:?})", tcx.node_path_str(id), promoted)
+            }
+            _ => tcx.node_path_str(src.item_id()),
+        };
+        for (span, message) in validator.errors {
+            tcx.sess.span_err(span, &format!("broken MIR in `{}` after pass `{}`: {}",
+                                             item, pass_name, message));
+        }
+    }
+}
+
+impl Pass for ValidateMir {}
+
+struct Validator<'a, 'tcx: 'a> {
+    mir: &'a Mir<'tcx>,
+    errors: Vec<(Span, String)>,
+}
+
+impl<'a, 'tcx> Validator<'a, 'tcx> {
+    fn error(&mut self, span: Span, message: String) {
+        self.errors.push((span, message));
+    }
+
+    fn validate(&mut self) {
+        let mir = self.mir;
+        self.check_cfg();
+        DeclaredLocals { validator: self }.visit_mir(mir);
+
+        // The definedness check walks the CFG, which is only
+        // meaningful once the CFG itself is known to be sound.
+        if self.errors.is_empty() {
+            self.check_definedness();
+        }
+    }
+
+    fn check_cfg(&mut self) {
+        let mir = self.mir;
+        let num_blocks = mir.basic_blocks().len();
+        if num_blocks == 0 {
+            self.error(mir.span, format!("no basic blocks"));
+            return;
+        }
+
+        for (bb, data) in mir.basic_blocks().iter_enumerated() {
+            let terminator = match data.terminator {
+                Some(ref terminator) => terminator,
+                None => {
+                    self.error(mir.span, format!("{:?} has no terminator", bb));
+                    continue;
+                }
+            };
+            let span = terminator.source_info.span;
+
+            let mut edges = vec![];
+            let mut unwind = None;
+            match terminator.kind {
+                TerminatorKind::Resume => {
+                    if !data.is_cleanup {
+                        self.error(span, format!("{:?}: resume on non-cleanup block", bb));
+                    }
+                }
+                TerminatorKind::Return => {
+                    if data.is_cleanup {
+                        self.error(span, format!("{:?}: return on cleanup block", bb));
+                    }
+                }
+                TerminatorKind::Drop { target, unwind: cleanup, .. } |
+                TerminatorKind::DropAndReplace { target, unwind: cleanup, .. } |
+                TerminatorKind::Assert { target, cleanup, .. } => {
+                    edges.push(target);
+                    unwind = cleanup;
+                }
+                TerminatorKind::Call { ref destination, cleanup, .. } => {
+                    if let Some((_, target)) = *destination {
+                        edges.push(target);
+                    }
+                    unwind = cleanup;
+                }
+                _ => {
+                    edges.extend(terminator.successors().iter().cloned());
+                }
+            }
+
+            let mut in_bounds = true;
+            for &target in edges.iter().chain(unwind.iter()) {
+                if target.index() >= num_blocks {
+                    self.error(span, format!("{:?} branches to nonexistent block {:?}",
+                                             bb, target));
+                    in_bounds = false;
+                }
+            }
+            if !in_bounds {
+                continue;
+            }
+
+            for &target in &edges {
+                if mir[target].is_cleanup != data.is_cleanup {
+                    self.error(span, format!("{:?} branches to {:?}, but only one of them \
+                                              is a cleanup block",
+                                             bb, target));
+                }
+            }
+            if let Some(target) = unwind {
+                if data.is_cleanup {
+                    self.error(span, format!("cleanup block {:?} has an unwind edge", bb));
+                } else if !mir[target].is_cleanup {
+                    self.error(span, format!("{:?} unwinds to non-cleanup block {:?}",
+                                             bb, target));
+                }
+            }
+        }
+    }
+
+    /// Checks, using a "maybe assigned" dataflow analysis over the
+    /// locals, that no local is read where it is definitely unassigned.
+    fn check_definedness(&mut self) {
+        let mir = self.mir;
+        let num_locals = mir.count_locals();
+
+        let mut on_entry = IndexVec::from_elem_n(BitVector::new(num_locals),
+                                                 mir.basic_blocks().len());
+        for arg in mir.arg_decls.indices() {
+            on_entry[START_BLOCK].insert(mir.local_index(&Lvalue::Arg(arg)).unwrap().index());
+        }
+
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for (bb, data) in mir.basic_blocks().iter_enumerated() {
+                let mut assigned = on_entry[bb].clone();
+                for stmt in &data.statements {
+                    let accesses = LocalAccesses::of(mir, |v| v.visit_statement(bb, stmt));
+                    accesses.apply(&mut assigned);
+                }
+                let terminator = data.terminator();
+                LocalAccesses::of_terminator(mir, bb, terminator).apply(&mut assigned);
+
+                for &succ in terminator.successors().iter() {
+                    if on_entry[succ].insert_all(&assigned) {
+                        changed = true;
+                    }
+                }
+            }
+        }
+
+        let return_pointer = mir.local_index(&Lvalue::ReturnPointer).unwrap();
+        for (bb, data) in traversal::preorder(mir) {
+            let mut assigned = on_entry[bb].clone();
+            for stmt in &data.statements {
+                let accesses = LocalAccesses::of(mir, |v| v.visit_statement(bb, stmt));
+                self.check_uses(&accesses, &assigned, stmt.source_info.span);
+                accesses.apply(&mut assigned);
+            }
+
+            let terminator = data.terminator();
+            let accesses = LocalAccesses::of_terminator(mir, bb, terminator);
+            self.check_uses(&accesses, &assigned, terminator.source_info.span);
+
+            if let TerminatorKind::Return = terminator.kind {
+                if let FnOutput::FnConverging(_) = mir.return_ty {
+                    if !assigned.contains(return_pointer.index()) {
+                        self.error(terminator.source_info.span,
+                                   format!("{:?}: return pointer is not assigned before \
+                                            return", bb));
+                    }
+                }
+            }
+        }
+    }
+
+    fn check_uses(&mut self,
+                  accesses: &LocalAccesses<'a, 'tcx>,
+                  assigned: &BitVector,
+                  span: Span) {
+        for &(ref lvalue, local) in &accesses.uses {
+            if !assigned.contains(local.index()) {
+                self.error(span, format!("use of unassigned {:?}", lvalue));
+            }
+        }
+    }
+}
+
+/// Checks that every var, temp and arg referenced is declared.
+struct DeclaredLocals<'b, 'a: 'b, 'tcx: 'a> {
+    validator: &'b mut Validator<'a, 'tcx>,
+}
+
+impl<'b, 'a, 'tcx> Visitor<'tcx> for DeclaredLocals<'b, 'a, 'tcx> {
+    fn visit_lvalue(&mut self, lvalue: &Lvalue<'tcx>, context: LvalueContext) {
+        let mir = self.validator.mir;
+        let declared = match *lvalue {
+            Lvalue::Var(var) => var.index() < mir.var_decls.len(),
+            Lvalue::Temp(temp) => temp.index() < mir.temp_decls.len(),
+            Lvalue::Arg(arg) => arg.index() < mir.arg_decls.len(),
+            _ => true,
+        };
+        if !declared {
+            self.validator.error(mir.span, format!("use of undeclared {:?}", lvalue));
+        }
+        self.super_lvalue(lvalue, context);
+    }
+}
+
+/// The locals read and (wholly or partially) assigned by a statement
+/// or terminator.
+struct LocalAccesses<'a, 'tcx: 'a> {
+    mir: &'a Mir<'tcx>,
+    uses: Vec<(Lvalue<'tcx>, Local)>,
+    defs: Vec<Local>,
+}
+
+impl<'a, 'tcx> LocalAccesses<'a, 'tcx> {
+    fn of<F>(mir: &'a Mir<'tcx>, f: F) -> Self
+        where F: FnOnce(&mut Self)
+    {
+        let mut accesses = LocalAccesses { mir: mir, uses: vec![], defs: vec![] };
+        f(&mut accesses);
+        accesses
+    }
+
+    fn of_terminator(mir: &'a Mir<'tcx>, bb: BasicBlock, terminator: &Terminator<'tcx>)
+                     -> Self {
+        let mut accesses = LocalAccesses::of(mir, |v| v.visit_terminator(bb, terminator));
+        // The visitor only sees the drop half of a `DropAndReplace`,
+        // but its location is (re)assigned as well.
+        if let TerminatorKind::DropAndReplace { ref location, .. } = terminator.kind {
+            if let Some(local) = direct_base_local(mir, location) {
+                accesses.defs.push(local);
+            }
+        }
+        accesses
+    }
+
+    fn apply(&self, assigned: &mut BitVector) {
+        for local in &self.defs {
+            assigned.insert(local.index());
+        }
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for LocalAccesses<'a, 'tcx> {
+    fn visit_lvalue(&mut self, lvalue: &Lvalue<'tcx>, context: LvalueContext) {
+        match context {
+            // Drops are scheduled for every binding in scope, whether
+            // it was assigned or not; drop elaboration sorts that out.
+            LvalueContext::Drop => return,
+            LvalueContext::Store | LvalueContext::Call => {
+                if let Some(local) = self.mir.local_index(lvalue) {
+                    self.defs.push(local);
+                    return;
+                }
+                // A store into a field of a local (as opposed to through
+                // a pointer) partially initializes that local.
+                if let Some(local) = direct_base_local(self.mir, lvalue) {
+                    self.defs.push(local);
+                    let mut lvalue = lvalue;
+                    while let Lvalue::Projection(ref proj) = *lvalue {
+                        if let ProjectionElem::Index(ref index) = proj.elem {
+                            self.visit_operand(index);
+                        }
+                        lvalue = &proj.base;
+                    }
+                    return;
+                }
+            }
+            _ => {
+                if let Some(local) = self.mir.local_index(lvalue) {
+                    self.uses.push((lvalue.clone(), local));
+                }
+            }
+        }
+        self.super_lvalue(lvalue, context);
+    }
+}
+
+/// Returns the local at the root of `lvalue` if it is reached without
+/// going through any dereference.
+fn direct_base_local<'tcx>(mir: &Mir<'tcx>, lvalue: &Lvalue<'tcx>) -> Option<Local> {
+    match *lvalue {
+        Lvalue::Projection(ref proj) => match proj.elem {
+            ProjectionElem::Deref => None,
+            _ => direct_base_local(mir, &proj.base),
+        },
+        _ => mir.local_index(lvalue),
+    }
+}
@@ -609,9 +609,17 @@ fn check_expr<'a, 'tcx>(v: &mut CheckCrateVisitor<'a, 'tcx>,
             }
         }
 
+        hir::ExprField(ref base, _) => {
+            // Reading a union field reinterprets its bytes, which constant
+            // evaluation does not support.
+            let base_ty = v.tcx.expr_ty_adjusted(base);
+            if base_ty.is_union() {
+                v.add_qualif(ConstQualif::NOT_CONST);
+            }
+        }
+
         hir::ExprBlock(_) |
         hir::ExprIndex(..) |
-        hir::ExprField(..) |
         hir::ExprTupField(..) |
         hir::ExprVec(_) |
         hir::ExprType(..) |
@@ -626,7 +634,7 @@ fn check_expr<'a, 'tcx>(v: &mut CheckCrateVisitor<'a, 'tcx>,
         hir::ExprLoop(..) |
 
         // More control flow (also not very meaningful).
-        hir::ExprBreak(_) |
+        hir::ExprBreak(..) |
         hir::ExprAgain(_) |
         hir::ExprRet(_) |
 
@@ -45,13 +45,18 @@ impl<'a, 'v> Visitor<'v> for CheckLoopVisitor<'a> {
                 self.visit_expr(&e);
                 self.with_context(Loop, |v| v.visit_block(&b));
             }
-            hir::ExprLoop(ref b, _) => {
+            hir::ExprLoop(ref b, _, _) => {
                 self.with_context(Loop, |v| v.visit_block(&b));
             }
             hir::ExprClosure(_, _, ref b, _) => {
                 self.with_context(Closure, |v| v.visit_block(&b));
             }
-            hir::ExprBreak(_) => self.require_loop("break", e.span),
+            hir::ExprBreak(_, ref opt_expr) => {
+                if let Some(ref value) = *opt_expr {
+                    self.visit_expr(&value);
+                }
+                self.require_loop("break", e.span);
+            }
             hir::ExprAgain(_) => self.require_loop("continue", e.span),
             _ => intravisit::walk_expr(self, e)
         }
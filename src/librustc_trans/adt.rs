@@ -45,6 +45,7 @@ pub use self::Repr::*;
 use super::Disr;
 
 use std;
+use std::cmp;
 use std::rc::Rc;
 
 use llvm::{ValueRef, True, IntEQ, IntNE};
@@ -113,6 +114,10 @@ pub enum Repr<'tcx> {
     /// (The flag, if nonzero, represents the initialization value to use;
     ///  if zero, then use no flag at all.)
     General(IntType, Vec<Struct<'tcx>>, u8),
+    /// Untagged unions: all fields are stored at offset 0, and the
+    /// value is as large and as aligned as its largest and most
+    /// aligned field.
+    UntaggedUnion(Union<'tcx>),
     /// Two cases distinguished by a nullable pointer: the case with discriminant
     /// `nndiscr` must have single field which is known to be nonnull due to its type.
     /// The other case is known to be zero sized. Hence we represent the enum
@@ -149,6 +154,17 @@ pub struct Struct<'tcx> {
     pub align: u32,
     pub sized: bool,
     pub packed: bool,
+    // If true, `#[repr(align(N))]` raised the alignment above that of the
+    // fields, and the LLVM type ends in a zero-sized field of that alignment.
+    pub over_aligned: bool,
+    pub fields: Vec<Ty<'tcx>>,
+}
+
+/// For untagged unions.
+#[derive(Eq, PartialEq, Debug)]
+pub struct Union<'tcx> {
+    pub min_size: u64,
+    pub align: u32,
     pub fields: Vec<Ty<'tcx>>,
 }
 
@@ -250,11 +266,22 @@ fn represent_type_uncached<'a, 'tcx>(cx: &CrateContext<'a, 'tcx>,
         ty::TyTuple(ref elems) => {
             Univariant(mk_struct(cx, &elems[..], false, t), 0)
         }
+        ty::TyStruct(def, substs) if def.is_union() => {
+            let ftys = def.struct_variant().fields.iter().map(|field| {
+                monomorphize::field_ty(cx.tcx(), substs, field)
+            }).collect::<Vec<_>>();
+            let mut un = mk_union(cx, &ftys[..], t);
+            if let Some(align) = cx.tcx().lookup_repr_align(def.did) {
+                un.align = cmp::max(un.align, align as u32);
+            }
+            UntaggedUnion(un)
+        }
         ty::TyStruct(def, substs) => {
             let mut ftys = def.struct_variant().fields.iter().map(|field| {
                 monomorphize::field_ty(cx.tcx(), substs, field)
             }).collect::<Vec<_>>();
             let packed = cx.tcx().lookup_packed(def.did);
+            let repr_align = cx.tcx().lookup_repr_align(def.did);
             // FIXME(16758) don't add a drop flag to unsized structs, as it
             // won't actually be in the location we say it is because it'll be after
             // the unsized field. Several other pieces of code assume that the unsized
@@ -264,15 +291,16 @@ fn represent_type_uncached<'a, 'tcx>(cx: &CrateContext<'a, 'tcx>,
                 ftys.push(cx.tcx().dtor_type());
             }
 
-            Univariant(mk_struct(cx, &ftys[..], packed, t), dtor_to_init_u8(dtor))
+            let st = raise_align(mk_struct(cx, &ftys[..], packed, t), repr_align);
+            Univariant(st, dtor_to_init_u8(dtor))
         }
         ty::TyClosure(_, ref substs) => {
             Univariant(mk_struct(cx, &substs.upvar_tys, false, t), 0)
         }
         ty::TyEnum(def, substs) => {
             let cases = get_cases(cx.tcx(), def, substs);
-            let hint = *cx.tcx().lookup_repr_hints(def.did).get(0)
-                .unwrap_or(&attr::ReprAny);
+            let hint = cx.tcx().lookup_enum_repr_hint(def.did);
+            let repr_align = cx.tcx().lookup_repr_align(def.did);
 
             let dtor = def.dtor_kind().has_drop_flag();
 
@@ -281,11 +309,13 @@ fn represent_type_uncached<'a, 'tcx>(cx: &CrateContext<'a, 'tcx>,
                 // (Typechecking will reject discriminant-sizing attrs.)
                 assert_eq!(hint, attr::ReprAny);
                 let ftys = if dtor { vec!(cx.tcx().dtor_type()) } else { vec!() };
-                return Univariant(mk_struct(cx, &ftys[..], false, t),
+                return Univariant(raise_align(mk_struct(cx, &ftys[..], false, t), repr_align),
                                   dtor_to_init_u8(dtor));
             }
 
-            if !dtor && cases.iter().all(|c| c.tys.is_empty()) {
+            // A raised alignment needs the general representation, which has
+            // room for padding after the discriminant.
+            if !dtor && repr_align.is_none() && cases.iter().all(|c| c.tys.is_empty()) {
                 // All bodies empty -> intlike
                 let discrs: Vec<_> = cases.iter().map(|c| Disr::from(c.discr)).collect();
                 let bounds = IntBounds {
@@ -309,11 +339,11 @@ fn represent_type_uncached<'a, 'tcx>(cx: &CrateContext<'a, 'tcx>,
                 // Equivalent to a struct/tuple/newtype.
                 let mut ftys = cases[0].tys.clone();
                 if dtor { ftys.push(cx.tcx().dtor_type()); }
-                return Univariant(mk_struct(cx, &ftys[..], false, t),
+                return Univariant(raise_align(mk_struct(cx, &ftys[..], false, t), repr_align),
                                   dtor_to_init_u8(dtor));
             }
 
-            if !dtor && cases.len() == 2 && hint == attr::ReprAny {
+            if !dtor && cases.len() == 2 && hint == attr::ReprAny && repr_align.is_none() {
                 // Nullable pointer optimization
                 let mut discr = 0;
                 while discr < 2 {
@@ -357,7 +387,7 @@ fn represent_type_uncached<'a, 'tcx>(cx: &CrateContext<'a, 'tcx>,
                 let mut ftys = vec!(ty_of_inttype(cx.tcx(), min_ity));
                 ftys.extend_from_slice(&c.tys);
                 if dtor { ftys.push(cx.tcx().dtor_type()); }
-                mk_struct(cx, &ftys, false, t)
+                raise_align(mk_struct(cx, &ftys, false, t), repr_align)
             }).collect();
 
 
@@ -373,8 +403,10 @@ fn represent_type_uncached<'a, 'tcx>(cx: &CrateContext<'a, 'tcx>,
             // This check is needed to avoid increasing the size of types when
             // the alignment of the first field is smaller than the overall
             // alignment of the type.
+            // A raised alignment says nothing about the fields, so keep the
+            // minimal discriminant in that case.
             let (_, align) = union_size_and_align(&fields);
-            let mut use_align = true;
+            let mut use_align = repr_align.is_none();
             for st in &fields {
                 // Get the first non-zero-sized field
                 let field = st.fields.iter().skip(1).filter(|ty| {
@@ -419,7 +451,7 @@ fn represent_type_uncached<'a, 'tcx>(cx: &CrateContext<'a, 'tcx>,
                 let mut ftys = vec!(ty_of_inttype(cx.tcx(), ity));
                 ftys.extend_from_slice(&c.tys);
                 if dtor { ftys.push(cx.tcx().dtor_type()); }
-                mk_struct(cx, &ftys[..], false, t)
+                raise_align(mk_struct(cx, &ftys[..], false, t), repr_align)
             }).collect();
 
             ensure_enum_fits_in_address_space(cx, &fields[..], t);
@@ -474,6 +506,9 @@ fn find_discr_field_candidate<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
             }
         },
 
+        // The fields of a union may hold any bit pattern.
+        ty::TyStruct(def, _) if def.is_union() => None,
+
         // Perhaps one of the fields of this struct is non-zero
         // let's recurse and find out
         ty::TyStruct(def, substs) => {
@@ -574,6 +609,44 @@ fn mk_struct<'a, 'tcx>(cx: &CrateContext<'a, 'tcx>,
         align: machine::llalign_of_min(cx, llty_rec),
         sized: sized,
         packed: packed,
+        over_aligned: false,
+        fields: tys.to_vec(),
+    }
+}
+
+/// Raise the alignment of `st` to the one requested by `#[repr(align(N))]`,
+/// rounding its size up accordingly.
+fn raise_align<'tcx>(mut st: Struct<'tcx>, repr_align: Option<u64>) -> Struct<'tcx> {
+    if let Some(align) = repr_align {
+        let align = align as u32;
+        if align > st.align {
+            st.align = align;
+            st.size = roundup(st.size, align);
+            st.over_aligned = true;
+        }
+    }
+    st
+}
+
+fn mk_union<'a, 'tcx>(cx: &CrateContext<'a, 'tcx>,
+                      tys: &[Ty<'tcx>],
+                      scapegoat: Ty<'tcx>)
+                      -> Union<'tcx> {
+    let mut min_size = 0;
+    let mut align = 1;
+    for &ty in tys {
+        let llty = type_of::sizing_type_of(cx, ty);
+        min_size = cmp::max(min_size, machine::llsize_of_alloc(cx, llty));
+        align = cmp::max(align, machine::llalign_of_min(cx, llty));
+    }
+
+    if roundup(min_size, align) >= cx.obj_size_bound() {
+        cx.report_overbig_object(scapegoat);
+    }
+
+    Union {
+        min_size: min_size,
+        align: align,
         fields: tys.to_vec(),
     }
 }
@@ -634,6 +707,9 @@ fn range_to_inttype(cx: &CrateContext, hint: Hint, bounds: &IntBounds) -> IntTyp
         attr::ReprSimd => {
             bug!("range_to_inttype: found ReprSimd on an enum");
         }
+        attr::ReprAlign(_) => {
+            bug!("range_to_inttype: found ReprAlign as a discriminant hint");
+        }
     }
     for &ity in attempts {
         if bounds_usable(cx, ity, bounds) {
@@ -750,10 +826,12 @@ pub fn incomplete_type_of<'a, 'tcx>(cx: &CrateContext<'a, 'tcx>,
 pub fn finish_type_of<'a, 'tcx>(cx: &CrateContext<'a, 'tcx>,
                                 r: &Repr<'tcx>, llty: &mut Type) {
     match *r {
-        CEnum(..) | General(..) | RawNullablePointer { .. } => { }
-        Univariant(ref st, _) | StructWrappedNullablePointer { nonnull: ref st, .. } =>
-            llty.set_struct_body(&struct_llfields(cx, st, false, false),
-                                 st.packed)
+        CEnum(..) | General(..) | UntaggedUnion(..) | RawNullablePointer { .. } => { }
+        Univariant(ref st, _) | StructWrappedNullablePointer { nonnull: ref st, .. } => {
+            let mut fields = struct_llfields(cx, st, false, false);
+            fields.extend(align_fill(cx, st));
+            llty.set_struct_body(&fields, st.packed)
+        }
     }
 }
 
@@ -772,9 +850,9 @@ fn generic_type_of<'a, 'tcx>(cx: &CrateContext<'a, 'tcx>,
         StructWrappedNullablePointer { nonnull: ref st, .. } => {
             match name {
                 None => {
-                    TypeContext::direct(
-                        Type::struct_(cx, &struct_llfields(cx, st, sizing, dst),
-                                      st.packed))
+                    let mut fields = struct_llfields(cx, st, sizing, dst);
+                    fields.extend(align_fill(cx, st));
+                    TypeContext::direct(Type::struct_(cx, &fields, st.packed))
                 }
                 Some(name) => {
                     assert_eq!(sizing, false);
@@ -790,6 +868,7 @@ fn generic_type_of<'a, 'tcx>(cx: &CrateContext<'a, 'tcx>,
                     if delay_drop_flag && dtor_needed {
                         fields.pop();
                     }
+                    fields.extend(align_fill(cx, st));
                     TypeContext::may_need_drop_flag(
                         Type::struct_(cx, &fields,
                                       st.packed),
@@ -803,6 +882,20 @@ fn generic_type_of<'a, 'tcx>(cx: &CrateContext<'a, 'tcx>,
                 }
             }
         }
+        UntaggedUnion(ref un) => {
+            // Use alignment-sized ints to fill all the union storage.
+            let fill_ty = union_fill(cx, roundup(un.min_size, un.align), un.align);
+            match name {
+                None => {
+                    TypeContext::direct(Type::struct_(cx, &[fill_ty], false))
+                }
+                Some(name) => {
+                    let mut llty = Type::named_struct(cx, name);
+                    llty.set_struct_body(&[fill_ty], false);
+                    TypeContext::direct(llty)
+                }
+            }
+        }
         General(ity, ref sts, dtor_needed) => {
             let dtor_needed = dtor_needed != 0;
             // We need a representation that has:
@@ -818,23 +911,10 @@ fn generic_type_of<'a, 'tcx>(cx: &CrateContext<'a, 'tcx>,
             //
             // FIXME #10604: this breaks when vector types are present.
             let (size, align) = union_size_and_align(&sts[..]);
-            let align_s = align as u64;
             let discr_ty = ll_inttype(cx, ity);
             let discr_size = machine::llsize_of_alloc(cx, discr_ty);
             let padded_discr_size = roundup(discr_size, align);
-            assert_eq!(size % align_s, 0); // Ensure division in align_units comes out evenly
-            let align_units = (size - padded_discr_size) / align_s;
-            let fill_ty = match align_s {
-                1 => Type::array(&Type::i8(cx), align_units),
-                2 => Type::array(&Type::i16(cx), align_units),
-                4 => Type::array(&Type::i32(cx), align_units),
-                8 if machine::llalign_of_min(cx, Type::i64(cx)) == 8 =>
-                                 Type::array(&Type::i64(cx), align_units),
-                a if a.count_ones() == 1 => Type::array(&Type::vector(&Type::i32(cx), a / 4),
-                                                              align_units),
-                _ => bug!("unsupported enum alignment: {}", align)
-            };
-            assert_eq!(machine::llalign_of_min(cx, fill_ty), align);
+            let fill_ty = union_fill(cx, size - padded_discr_size, align);
             assert_eq!(padded_discr_size % discr_size, 0); // Ensure discr_ty can fill pad evenly
             let mut fields: Vec<Type> =
                 [discr_ty,
@@ -861,6 +941,36 @@ fn generic_type_of<'a, 'tcx>(cx: &CrateContext<'a, 'tcx>,
     }
 }
 
+/// An array of `align`-sized integers (or vectors) filling `size`
+/// bytes, used for storage which has no single field type.
+fn union_fill(cx: &CrateContext, size: u64, align: u32) -> Type {
+    let align_s = align as u64;
+    assert_eq!(size % align_s, 0); // Ensure division in align_units comes out evenly
+    let align_units = size / align_s;
+    let fill_ty = match align_s {
+        1 => Type::array(&Type::i8(cx), align_units),
+        2 => Type::array(&Type::i16(cx), align_units),
+        4 => Type::array(&Type::i32(cx), align_units),
+        8 if machine::llalign_of_min(cx, Type::i64(cx)) == 8 =>
+                         Type::array(&Type::i64(cx), align_units),
+        a if a.count_ones() == 1 => Type::array(&Type::vector(&Type::i32(cx), a / 4),
+                                                      align_units),
+        _ => bug!("unsupported enum alignment: {}", align)
+    };
+    assert_eq!(machine::llalign_of_min(cx, fill_ty), align);
+    fill_ty
+}
+
+/// The zero-sized trailing field which gives an over-aligned struct its
+/// alignment, if any.
+fn align_fill(cx: &CrateContext, st: &Struct) -> Option<Type> {
+    if st.over_aligned {
+        Some(union_fill(cx, 0, st.align))
+    } else {
+        None
+    }
+}
+
 fn struct_llfields<'a, 'tcx>(cx: &CrateContext<'a, 'tcx>, st: &Struct<'tcx>,
                              sizing: bool, dst: bool) -> Vec<Type> {
     if sizing {
@@ -886,7 +996,7 @@ pub fn trans_switch<'blk, 'tcx>(bcx: Block<'blk, 'tcx>,
             (_match::Switch, Some(trans_get_discr(bcx, r, scrutinee, None,
                                                   range_assert)))
         }
-        Univariant(..) => {
+        Univariant(..) | UntaggedUnion(..) => {
             // N.B.: Univariant means <= 1 enum variants (*not* == 1 variants).
             (_match::Single, None)
         }
@@ -897,7 +1007,7 @@ pub fn is_discr_signed<'tcx>(r: &Repr<'tcx>) -> bool {
     match *r {
         CEnum(ity, _, _) => ity.is_signed(),
         General(ity, _, _) => ity.is_signed(),
-        Univariant(..) => false,
+        Univariant(..) | UntaggedUnion(..) => false,
         RawNullablePointer { .. } => false,
         StructWrappedNullablePointer { .. } => false,
     }
@@ -918,7 +1028,7 @@ pub fn trans_get_discr<'blk, 'tcx>(bcx: Block<'blk, 'tcx>, r: &Repr<'tcx>,
             load_discr(bcx, ity, ptr, Disr(0), Disr(cases.len() as u64 - 1),
                        range_assert)
         }
-        Univariant(..) => C_u8(bcx.ccx(), 0),
+        Univariant(..) | UntaggedUnion(..) => C_u8(bcx.ccx(), 0),
         RawNullablePointer { nndiscr, nnty, .. } =>  {
             let cmp = if nndiscr == Disr(0) { IntEQ } else { IntNE };
             let llptrty = type_of::sizing_type_of(bcx.ccx(), nnty);
@@ -980,8 +1090,8 @@ pub fn trans_case<'blk, 'tcx>(bcx: Block<'blk, 'tcx>, r: &Repr, discr: Disr)
         General(ity, _, _) => {
             C_integral(ll_inttype(bcx.ccx(), ity), discr.0, true)
         }
-        Univariant(..) => {
-            bug!("no cases for univariants or structs")
+        Univariant(..) | UntaggedUnion(..) => {
+            bug!("no cases for univariants, structs or unions")
         }
         RawNullablePointer { .. } |
         StructWrappedNullablePointer { .. } => {
@@ -1017,6 +1127,9 @@ pub fn trans_set_discr<'blk, 'tcx>(bcx: Block<'blk, 'tcx>, r: &Repr<'tcx>,
                       StructGEP(bcx, val, st.fields.len() - 1));
             }
         }
+        UntaggedUnion(..) => {
+            assert_eq!(discr, Disr(0));
+        }
         RawNullablePointer { nndiscr, nnty, ..} => {
             if discr != nndiscr {
                 let llptrty = type_of::sizing_type_of(bcx.ccx(), nnty);
@@ -1058,6 +1171,10 @@ pub fn num_args(r: &Repr, discr: Disr) -> usize {
         General(_, ref cases, dtor) => {
             cases[discr.0 as usize].fields.len() - 1 - (if dtor_active(dtor) { 1 } else { 0 })
         }
+        UntaggedUnion(ref un) => {
+            assert_eq!(discr, Disr(0));
+            un.fields.len()
+        }
         RawNullablePointer { nndiscr, ref nullfields, .. } => {
             if discr == nndiscr { 1 } else { nullfields.len() }
         }
@@ -1094,6 +1211,12 @@ pub fn trans_field_ptr_builder<'blk, 'tcx>(bcx: &BlockAndBuilder<'blk, 'tcx>,
         General(_, ref cases, _) => {
             struct_field_ptr(bcx, &cases[discr.0 as usize], val, ix + 1, true)
         }
+        UntaggedUnion(ref un) => {
+            // All the fields of a union start at its beginning.
+            let ty = type_of::in_memory_type_of(bcx.ccx(), un.fields[ix]);
+            if bcx.is_unreachable() { return C_undef(ty.ptr_to()); }
+            bcx.pointercast(val.value, ty.ptr_to())
+        }
         RawNullablePointer { nndiscr, ref nullfields, .. } |
         StructWrappedNullablePointer { nndiscr, ref nullfields, .. } if discr != nndiscr => {
             // The unit-like case might have a nonzero number of unit-like fields.
@@ -1362,6 +1485,15 @@ pub fn trans_const<'a, 'tcx>(ccx: &CrateContext<'a, 'tcx>, r: &Repr<'tcx>, discr
             let contents = build_const_struct(ccx, st, vals);
             C_struct(ccx, &contents[..], st.packed)
         }
+        UntaggedUnion(ref un) => {
+            // Only the initialized field is given; it is stored at the
+            // beginning of the union and followed by undefined padding.
+            assert_eq!(discr, Disr(0));
+            assert_eq!(vals.len(), 1);
+            let size = roundup(un.min_size, un.align);
+            let val_size = machine::llsize_of_alloc(ccx, val_ty(vals[0]));
+            C_struct(ccx, &[vals[0], padding(ccx, size - val_size)], false)
+        }
         RawNullablePointer { nndiscr, nnty, .. } => {
             if discr == nndiscr {
                 assert_eq!(vals.len(), 1);
@@ -1473,7 +1605,7 @@ pub fn const_get_discrim(r: &Repr, val: ValueRef) -> Disr {
                 attr::UnsignedInt(..) => Disr(const_to_uint(const_get_elt(val, &[0])))
             }
         }
-        Univariant(..) => Disr(0),
+        Univariant(..) | UntaggedUnion(..) => Disr(0),
         RawNullablePointer { .. } | StructWrappedNullablePointer { .. } => {
             bug!("const discrim access of non c-like enum")
         }
@@ -1491,6 +1623,7 @@ pub fn const_get_field(r: &Repr, val: ValueRef, _discr: Disr,
         CEnum(..) => bug!("element access in C-like enum const"),
         Univariant(..) => const_struct_field(val, ix),
         General(..) => const_struct_field(val, ix + 1),
+        UntaggedUnion(..) => bug!("field access in union const"),
         RawNullablePointer { .. } => {
             assert_eq!(ix, 0);
             val
@@ -35,8 +35,16 @@ pub struct LinkerInfo {
 impl<'a, 'tcx> LinkerInfo {
     pub fn new(scx: &SharedCrateContext<'a, 'tcx>,
                reachable: &[String]) -> LinkerInfo {
+        // `proc-macro` crates can't be mixed with other crate types, so they
+        // share the dylib export list.
+        let dylib_type = if scx.sess().crate_types.borrow()
+                               .contains(&CrateType::CrateTypeProcMacro) {
+            CrateType::CrateTypeProcMacro
+        } else {
+            CrateType::CrateTypeDylib
+        };
         LinkerInfo {
-            dylib_exports: exported_symbols(scx, reachable, CrateType::CrateTypeDylib),
+            dylib_exports: exported_symbols(scx, reachable, dylib_type),
             cdylib_exports: exported_symbols(scx, reachable, CrateType::CrateTypeCdylib)
         }
     }
@@ -243,7 +251,8 @@ impl<'a> Linker for GnuLinker<'a> {
         // exported symbols to ensure we don't expose any more. The object files
         // have far more public symbols than we actually want to export, so we
         // hide them all here.
-        if crate_type == CrateType::CrateTypeDylib {
+        if crate_type == CrateType::CrateTypeDylib ||
+           crate_type == CrateType::CrateTypeProcMacro {
             return
         }
 
@@ -436,7 +445,8 @@ fn exported_symbols(scx: &SharedCrateContext,
 
     // See explanation in GnuLinker::export_symbols, for
     // why we don't ever need dylib symbols on non-MSVC.
-    if crate_type == CrateType::CrateTypeDylib {
+    if crate_type == CrateType::CrateTypeDylib ||
+       crate_type == CrateType::CrateTypeProcMacro {
         if !scx.sess().target.target.options.is_like_msvc {
             return vec![];
         }
@@ -450,7 +460,8 @@ fn exported_symbols(scx: &SharedCrateContext,
     // For dylibs, however, we need to take a look at how all upstream crates
     // are linked into this dynamic library. For all statically linked
     // libraries we take all their reachable symbols and emit them as well.
-    if crate_type != CrateType::CrateTypeDylib {
+    if crate_type != CrateType::CrateTypeDylib &&
+       crate_type != CrateType::CrateTypeProcMacro {
         return symbols
     }
 
@@ -57,6 +57,7 @@ use callee::{Callee, CallArgs, ArgExprs, ArgVals};
 use cleanup::{self, CleanupMethods, DropHint};
 use closure;
 use common::{Block, C_bool, C_bytes_in_context, C_i32, C_int, C_uint, C_integral};
+use common::C_big_integral;
 use collector::{self, TransItemCollectionMode};
 use common::{C_null, C_struct_in_context, C_u64, C_u8, C_undef};
 use common::{CrateContext, DropFlagHintsMap, Field, FunctionContext};
@@ -99,6 +100,7 @@ use std::collections::{HashMap, HashSet};
 use std::rc::Rc;
 use std::str;
 use std::{i8, i16, i32, i64};
+use rustc_i128::{i128, u128};
 use syntax_pos::{Span, DUMMY_SP};
 use syntax::parse::token::InternedString;
 use syntax::attr::AttrMetaMethods;
@@ -739,17 +741,18 @@ fn cast_shift_rhs<F, G>(op: hir::BinOp_,
 
 pub fn llty_and_min_for_signed_ty<'blk, 'tcx>(cx: Block<'blk, 'tcx>,
                                               val_t: Ty<'tcx>)
-                                              -> (Type, u64) {
+                                              -> (Type, u128) {
     match val_t.sty {
         ty::TyInt(t) => {
             let llty = Type::int_from_ty(cx.ccx(), t);
             let min = match t {
-                ast::IntTy::Is if llty == Type::i32(cx.ccx()) => i32::MIN as u64,
-                ast::IntTy::Is => i64::MIN as u64,
-                ast::IntTy::I8 => i8::MIN as u64,
-                ast::IntTy::I16 => i16::MIN as u64,
-                ast::IntTy::I32 => i32::MIN as u64,
-                ast::IntTy::I64 => i64::MIN as u64,
+                ast::IntTy::Is if llty == Type::i32(cx.ccx()) => i32::MIN as u128,
+                ast::IntTy::Is => i64::MIN as u128,
+                ast::IntTy::I8 => i8::MIN as u128,
+                ast::IntTy::I16 => i16::MIN as u128,
+                ast::IntTy::I32 => i32::MIN as u128,
+                ast::IntTy::I64 => i64::MIN as u128,
+                ast::IntTy::I128 => i128::min_value() as u128,
             };
             (llty, min)
         }
@@ -814,13 +817,13 @@ pub fn fail_if_zero_or_overflows<'blk, 'tcx>(cx: Block<'blk, 'tcx>,
         let minus_one = ICmp(bcx,
                              llvm::IntEQ,
                              rhs,
-                             C_integral(llty, !0, false),
+                             C_integral(llty, !0, true),
                              debug_loc);
         with_cond(bcx, minus_one, |bcx| {
             let is_min = ICmp(bcx,
                               llvm::IntEQ,
                               lhs,
-                              C_integral(llty, min, true),
+                              C_big_integral(llty, min, true),
                               debug_loc);
             with_cond(bcx, is_min, |bcx| {
                 controlflow::trans_fail(bcx, call_info,
@@ -1467,6 +1470,7 @@ impl<'blk, 'tcx> FunctionContext<'blk, 'tcx> {
             landingpad_alloca: Cell::new(None),
             lllocals: RefCell::new(NodeMap()),
             llupvars: RefCell::new(NodeMap()),
+            loop_dests: RefCell::new(NodeMap()),
             lldropflag_hints: RefCell::new(DropFlagHintsMap::new()),
             fn_ty: fn_ty,
             param_substs: param_substs,
@@ -2598,7 +2602,8 @@ pub fn trans_crate<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
         reachable_symbols.push("main".to_string());
     }
 
-    if sess.crate_types.borrow().contains(&config::CrateTypeDylib) {
+    if sess.crate_types.borrow().contains(&config::CrateTypeDylib) ||
+       sess.crate_types.borrow().contains(&config::CrateTypeProcMacro) {
         reachable_symbols.push(shared_ccx.metadata_symbol_name());
     }
 
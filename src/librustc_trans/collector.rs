@@ -795,6 +795,7 @@ fn find_drop_glue_neighbors<'a, 'tcx>(scx: &SharedCrateContext<'a, 'tcx>,
         }
         ty::TyProjection(_) |
         ty::TyParam(_)      |
+        ty::TyAnon(..)      |
         ty::TyInfer(_)      |
         ty::TyError         => {
             bug!("encountered unexpected type");
@@ -1092,7 +1093,8 @@ impl<'b, 'a, 'v> hir_visit::Visitor<'v> for RootCollector<'b, 'a, 'v> {
             }
 
             hir::ItemEnum(_, ref generics)        |
-            hir::ItemStruct(_, ref generics)      => {
+            hir::ItemStruct(_, ref generics)      |
+            hir::ItemUnion(_, ref generics)       => {
                 if !generics.is_parameterized() {
                     let ty = {
                         let tables = self.scx.tcx().tables.borrow();
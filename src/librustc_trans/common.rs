@@ -33,6 +33,7 @@ use consts;
 use datum;
 use debuginfo::{self, DebugLoc};
 use declare;
+use expr;
 use machine;
 use mir::CachedMir;
 use monomorphize;
@@ -50,6 +51,9 @@ use libc::{c_uint, c_char};
 use std::ops::Deref;
 use std::ffi::CString;
 use std::cell::{Cell, RefCell};
+use std::mem;
+
+use rustc_i128::u128;
 
 use syntax::ast;
 use syntax::parse::token::InternedString;
@@ -374,6 +378,10 @@ pub struct FunctionContext<'a, 'tcx: 'a> {
     // Same as above, but for closure upvars
     pub llupvars: RefCell<NodeMap<ValueRef>>,
 
+    // The destinations of the `loop` expressions being translated, which
+    // a `break` with a value stores it into.
+    pub loop_dests: RefCell<NodeMap<expr::Dest>>,
+
     // Carries info about drop-flags for local bindings (longer term,
     // paths) for the code being compiled.
     pub lldropflag_hints: RefCell<DropFlagHintsMap<'tcx>>,
@@ -883,6 +891,20 @@ pub fn C_integral(t: Type, u: u64, sign_extend: bool) -> ValueRef {
     }
 }
 
+/// Creates an integer constant that may be wider than 64 bits, such as an
+/// `i128` or `u128` value. When the compiler itself was built without 128-bit
+/// integers the value only carries 64 bits, which are extended as requested.
+pub fn C_big_integral(t: Type, u: u128, sign_extend: bool) -> ValueRef {
+    if mem::size_of::<u128>() == 16 {
+        let words = [u as u64, u.wrapping_shr(64) as u64];
+        unsafe {
+            llvm::LLVMConstIntOfArbitraryPrecision(t.to_ref(), 2, words.as_ptr())
+        }
+    } else {
+        C_integral(t, u as u64, sign_extend)
+    }
+}
+
 pub fn C_floating(s: &str, t: Type) -> ValueRef {
     unsafe {
         let s = CString::new(s).unwrap();
@@ -25,6 +25,7 @@ use trans_item::TransItem;
 use common::{type_is_sized, C_nil, const_get_elt};
 use common::{CrateContext, C_integral, C_floating, C_bool, C_str_slice, C_bytes, val_ty};
 use common::{C_struct, C_undef, const_to_opt_int, const_to_opt_uint, VariantInfo, C_uint};
+use common::C_big_integral;
 use common::{type_is_fat_ptr, Field, C_vector, C_array, C_null};
 use datum::{Datum, Lvalue};
 use declare;
@@ -40,6 +41,7 @@ use rustc::ty::{self, Ty, TyCtxt};
 use rustc::ty::cast::{CastTy,IntTy};
 use util::nodemap::NodeMap;
 use rustc_const_math::{ConstInt, ConstUsize, ConstIsize};
+use rustc_i128::{i128, u128};
 
 use rustc::hir;
 
@@ -61,20 +63,22 @@ pub fn const_lit(cx: &CrateContext, e: &hir::Expr, lit: &ast::Lit)
     match lit.node {
         LitKind::Byte(b) => C_integral(Type::uint_from_ty(cx, ast::UintTy::U8), b as u64, false),
         LitKind::Char(i) => C_integral(Type::char(cx), i as u64, false),
+        // Literals hold the magnitude of the value, so they are never sign
+        // extended.
         LitKind::Int(i, ast::LitIntType::Signed(t)) => {
-            C_integral(Type::int_from_ty(cx, t), i, true)
+            C_big_integral(Type::int_from_ty(cx, t), i, false)
         }
         LitKind::Int(u, ast::LitIntType::Unsigned(t)) => {
-            C_integral(Type::uint_from_ty(cx, t), u, false)
+            C_big_integral(Type::uint_from_ty(cx, t), u, false)
         }
         LitKind::Int(i, ast::LitIntType::Unsuffixed) => {
             let lit_int_ty = cx.tcx().node_id_to_type(e.id);
             match lit_int_ty.sty {
                 ty::TyInt(t) => {
-                    C_integral(Type::int_from_ty(cx, t), i as u64, true)
+                    C_big_integral(Type::int_from_ty(cx, t), i, false)
                 }
                 ty::TyUint(t) => {
-                    C_integral(Type::uint_from_ty(cx, t), i as u64, false)
+                    C_big_integral(Type::uint_from_ty(cx, t), i, false)
                 }
                 _ => span_bug!(lit.span,
                         "integer literal has type {:?} (expected int \
@@ -487,6 +491,9 @@ pub fn to_const_int(value: ValueRef, t: Ty, tcx: TyCtxt) -> Option<ConstInt> {
             ast::IntTy::I64 => {
                 Some(ConstInt::I64(input))
             },
+            ast::IntTy::I128 => {
+                Some(ConstInt::I128(input as i128))
+            },
             ast::IntTy::Is => {
                 ConstIsize::new(input, tcx.sess.target.int_type)
                     .ok().map(ConstInt::Isize)
@@ -508,6 +515,9 @@ pub fn to_const_int(value: ValueRef, t: Ty, tcx: TyCtxt) -> Option<ConstInt> {
             ast::UintTy::U64 => {
                 Some(ConstInt::U64(input))
             },
+            ast::UintTy::U128 => {
+                Some(ConstInt::U128(input as u128))
+            },
             ast::UintTy::Us => {
                 ConstUsize::new(input, tcx.sess.target.uint_type)
                     .ok().map(ConstInt::Usize)
@@ -831,6 +841,11 @@ fn const_expr_unadjusted<'a, 'tcx>(cx: &CrateContext<'a, 'tcx>,
             };
 
             let VariantInfo { discr, fields } = VariantInfo::of_node(cx.tcx(), ety, e.id);
+            if ety.is_union() {
+                // A union literal gives exactly one field.
+                let (val, _) = const_expr(cx, &fs[0].expr, param_substs, fn_args, trueconst)?;
+                return Ok(adt::trans_const(cx, &repr, discr, &[val]));
+            }
             let cs = fields.iter().enumerate().map(|(ix, &Field(f_name, _))| {
                 match (fs.iter().find(|f| f_name == f.name.node), base_val) {
                     (Some(ref f), _) => {
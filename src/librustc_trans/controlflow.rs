@@ -260,7 +260,8 @@ pub fn trans_while<'blk, 'tcx>(bcx: Block<'blk, 'tcx>,
 
 pub fn trans_loop<'blk, 'tcx>(bcx: Block<'blk, 'tcx>,
                               loop_expr: &hir::Expr,
-                              body: &hir::Block)
+                              body: &hir::Block,
+                              dest: expr::Dest)
                               -> Block<'blk, 'tcx> {
     let _icx = push_ctxt("trans_loop");
 
@@ -279,17 +280,20 @@ pub fn trans_loop<'blk, 'tcx>(bcx: Block<'blk, 'tcx>,
     // next_bcx
     //
     // Links between body_bcx_in and next_bcx are created by
-    // break statements.
+    // break statements, which store the value of the loop into
+    // `dest` first.
 
     let next_bcx_in = bcx.fcx.new_id_block("loop_exit", loop_expr.id);
     let body_bcx_in = bcx.fcx.new_id_block("loop_body", body.id);
 
     fcx.push_loop_cleanup_scope(loop_expr.id, [next_bcx_in, body_bcx_in]);
+    fcx.loop_dests.borrow_mut().insert(loop_expr.id, dest);
 
     Br(bcx, body_bcx_in.llbb, loop_expr.debug_loc());
     let body_bcx_out = trans_block(body_bcx_in, body, expr::Ignore);
     Br(body_bcx_out, body_bcx_in.llbb, DebugLoc::None);
 
+    fcx.loop_dests.borrow_mut().remove(&loop_expr.id);
     fcx.pop_loop_cleanup_scope(loop_expr.id);
 
     // If there are no predecessors for the next block, we just translated an endless loop and the
@@ -315,8 +319,21 @@ pub fn trans_break_cont<'blk, 'tcx>(bcx: Block<'blk, 'tcx>,
     let fcx = bcx.fcx;
 
     // Locate loop that we will break to
-    let loop_id = match opt_label {
-        None => fcx.top_loop_scope(),
+    let loop_id = target_loop(bcx, expr, opt_label);
+
+    // Generate appropriate cleanup code and branch
+    let cleanup_llbb = fcx.normal_exit_block(loop_id, exit);
+    Br(bcx, cleanup_llbb, expr.debug_loc());
+    Unreachable(bcx); // anything afterwards should be ignored
+    return bcx;
+}
+
+fn target_loop<'blk, 'tcx>(bcx: Block<'blk, 'tcx>,
+                           expr: &hir::Expr,
+                           opt_label: Option<ast::Name>)
+                           -> ast::NodeId {
+    match opt_label {
+        None => bcx.fcx.top_loop_scope(),
         Some(_) => {
             match bcx.tcx().expect_def(expr.id) {
                 Def::Label(loop_id) => loop_id,
@@ -325,19 +342,21 @@ pub fn trans_break_cont<'blk, 'tcx>(bcx: Block<'blk, 'tcx>,
                 }
             }
         }
-    };
-
-    // Generate appropriate cleanup code and branch
-    let cleanup_llbb = fcx.normal_exit_block(loop_id, exit);
-    Br(bcx, cleanup_llbb, expr.debug_loc());
-    Unreachable(bcx); // anything afterwards should be ignored
-    return bcx;
+    }
 }
 
 pub fn trans_break<'blk, 'tcx>(bcx: Block<'blk, 'tcx>,
                                expr: &hir::Expr,
-                               label_opt: Option<ast::Name>)
+                               label_opt: Option<ast::Name>,
+                               value_opt: Option<&hir::Expr>)
                                -> Block<'blk, 'tcx> {
+    let mut bcx = bcx;
+    if let Some(value) = value_opt {
+        // Only a `loop` has a destination; `while` loops cannot be left with a value.
+        let loop_id = target_loop(bcx, expr, label_opt);
+        let dest = bcx.fcx.loop_dests.borrow().get(&loop_id).cloned().unwrap_or(expr::Ignore);
+        bcx = expr::trans_into(bcx, value, dest);
+    }
     return trans_break_cont(bcx, expr, label_opt, cleanup::EXIT_BREAK);
 }
 
@@ -1103,6 +1103,7 @@ struct StructMemberDescriptionFactory<'tcx> {
     variant: ty::VariantDef<'tcx>,
     substs: &'tcx subst::Substs<'tcx>,
     is_simd: bool,
+    is_union: bool,
     span: Span,
 }
 
@@ -1135,6 +1136,9 @@ impl<'tcx> StructMemberDescriptionFactory<'tcx> {
 
             let offset = if self.is_simd {
                 FixedMemberOffset { bytes: i * field_size.unwrap() }
+            } else if self.is_union {
+                // All fields of a union overlap at the start of its storage.
+                FixedMemberOffset { bytes: 0 }
             } else {
                 ComputedMemberOffset
             };
@@ -1159,8 +1163,8 @@ fn prepare_struct_metadata<'a, 'tcx>(cx: &CrateContext<'a, 'tcx>,
     let struct_name = compute_debuginfo_type_name(cx, struct_type, false);
     let struct_llvm_type = type_of::in_memory_type_of(cx, struct_type);
 
-    let (struct_def_id, variant, substs) = match struct_type.sty {
-        ty::TyStruct(def, substs) => (def.did, def.struct_variant(), substs),
+    let (struct_def_id, variant, substs, is_union) = match struct_type.sty {
+        ty::TyStruct(def, substs) => (def.did, def.struct_variant(), substs, def.is_union()),
         _ => bug!("prepare_struct_metadata on a non-struct")
     };
 
@@ -1182,6 +1186,7 @@ fn prepare_struct_metadata<'a, 'tcx>(cx: &CrateContext<'a, 'tcx>,
             variant: variant,
             substs: substs,
             is_simd: struct_type.is_simd(),
+            is_union: is_union,
             span: span,
         })
     )
@@ -1451,7 +1456,9 @@ impl<'tcx> EnumMemberDescriptionFactory<'tcx> {
                     }
                 ]
             },
-            adt::CEnum(..) => span_bug!(self.span, "This should be unreachable.")
+            adt::CEnum(..) | adt::UntaggedUnion(..) => {
+                span_bug!(self.span, "This should be unreachable.")
+            }
         }
     }
 }
@@ -1651,6 +1658,7 @@ fn prepare_enum_metadata<'a, 'tcx>(cx: &CrateContext<'a, 'tcx>,
         adt::StructWrappedNullablePointer { .. } |
         adt::Univariant(..)                      => None,
         adt::General(inttype, _, _) => Some(discriminant_type_metadata(inttype)),
+        adt::UntaggedUnion(..) => bug!("union {:?} described as an enum", enum_type),
     };
 
     let enum_llvm_type = type_of::type_of(cx, enum_type);
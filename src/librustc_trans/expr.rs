@@ -928,8 +928,9 @@ fn trans_rvalue_stmt_unadjusted<'blk, 'tcx>(bcx: Block<'blk, 'tcx>,
     expr.debug_loc().apply(bcx.fcx);
 
     match expr.node {
-        hir::ExprBreak(label_opt) => {
-            controlflow::trans_break(bcx, expr, label_opt.map(|l| l.node))
+        hir::ExprBreak(label_opt, ref value_opt) => {
+            controlflow::trans_break(bcx, expr, label_opt.map(|l| l.node),
+                                     value_opt.as_ref().map(|e| &**e))
         }
         hir::ExprType(ref e, _) => {
             trans_into(bcx, &e, Ignore)
@@ -965,9 +966,6 @@ fn trans_rvalue_stmt_unadjusted<'blk, 'tcx>(bcx: Block<'blk, 'tcx>,
         hir::ExprWhile(ref cond, ref body, _) => {
             controlflow::trans_while(bcx, expr, &cond, &body)
         }
-        hir::ExprLoop(ref body, _) => {
-            controlflow::trans_loop(bcx, expr, &body)
-        }
         hir::ExprAssign(ref dst, ref src) => {
             let src_datum = unpack_datum!(bcx, trans(bcx, &src));
             let dst_datum = unpack_datum!(bcx, trans_to_lvalue(bcx, &dst, "assign"));
@@ -1085,6 +1083,9 @@ fn trans_rvalue_dps_unadjusted<'blk, 'tcx>(bcx: Block<'blk, 'tcx>,
         hir::ExprBlock(ref blk) => {
             controlflow::trans_block(bcx, &blk, dest)
         }
+        hir::ExprLoop(ref body, _, _) => {
+            controlflow::trans_loop(bcx, expr, &body, dest)
+        }
         hir::ExprStruct(_, ref fields, ref base) => {
             trans_struct(bcx,
                          &fields[..],
@@ -1295,7 +1296,8 @@ fn trans_struct<'blk, 'tcx>(bcx: Block<'blk, 'tcx>,
                                  fields: leftovers })
         }
         None => {
-            if need_base.iter().any(|b| *b) {
+            // Only one field of a union is ever given.
+            if !ty.is_union() && need_base.iter().any(|b| *b) {
                 span_bug!(expr_span, "missing fields and no base expr")
             }
             None
@@ -1509,7 +1511,7 @@ fn trans_unary<'blk, 'tcx>(bcx: Block<'blk, 'tcx>,
                     let bcx = if bcx.ccx().check_overflow() && is_signed {
                         let (llty, min) = base::llty_and_min_for_signed_ty(bcx, un_ty);
                         let is_min = ICmp(bcx, llvm::IntEQ, val,
-                                          C_integral(llty, min, true), debug_loc);
+                                          C_big_integral(llty, min, true), debug_loc);
                         with_cond(bcx, is_min, |bcx| {
                             let msg = InternedString::new(
                                 "attempted to negate with overflow");
@@ -2191,11 +2193,15 @@ impl OverflowOpViaIntrinsic {
                 TyInt(I16) => "llvm.sadd.with.overflow.i16",
                 TyInt(I32) => "llvm.sadd.with.overflow.i32",
                 TyInt(I64) => "llvm.sadd.with.overflow.i64",
+                TyInt(I128) => "llvm.sadd.with.overflow.i128",
+            TyInt(I128) => "llvm.sadd.with.overflow.i128",
 
                 TyUint(U8) => "llvm.uadd.with.overflow.i8",
                 TyUint(U16) => "llvm.uadd.with.overflow.i16",
                 TyUint(U32) => "llvm.uadd.with.overflow.i32",
                 TyUint(U64) => "llvm.uadd.with.overflow.i64",
+                TyUint(U128) => "llvm.uadd.with.overflow.i128",
+            TyUint(U128) => "llvm.uadd.with.overflow.i128",
 
                 _ => bug!(),
             },
@@ -2204,11 +2210,15 @@ impl OverflowOpViaIntrinsic {
                 TyInt(I16) => "llvm.ssub.with.overflow.i16",
                 TyInt(I32) => "llvm.ssub.with.overflow.i32",
                 TyInt(I64) => "llvm.ssub.with.overflow.i64",
+                TyInt(I128) => "llvm.ssub.with.overflow.i128",
+            TyInt(I128) => "llvm.ssub.with.overflow.i128",
 
                 TyUint(U8) => "llvm.usub.with.overflow.i8",
                 TyUint(U16) => "llvm.usub.with.overflow.i16",
                 TyUint(U32) => "llvm.usub.with.overflow.i32",
                 TyUint(U64) => "llvm.usub.with.overflow.i64",
+                TyUint(U128) => "llvm.usub.with.overflow.i128",
+            TyUint(U128) => "llvm.usub.with.overflow.i128",
 
                 _ => bug!(),
             },
@@ -2217,11 +2227,15 @@ impl OverflowOpViaIntrinsic {
                 TyInt(I16) => "llvm.smul.with.overflow.i16",
                 TyInt(I32) => "llvm.smul.with.overflow.i32",
                 TyInt(I64) => "llvm.smul.with.overflow.i64",
+                TyInt(I128) => "llvm.smul.with.overflow.i128",
+            TyInt(I128) => "llvm.smul.with.overflow.i128",
 
                 TyUint(U8) => "llvm.umul.with.overflow.i8",
                 TyUint(U16) => "llvm.umul.with.overflow.i16",
                 TyUint(U32) => "llvm.umul.with.overflow.i32",
                 TyUint(U64) => "llvm.umul.with.overflow.i64",
+                TyUint(U128) => "llvm.umul.with.overflow.i128",
+            TyUint(U128) => "llvm.umul.with.overflow.i128",
 
                 _ => bug!(),
             },
@@ -2434,6 +2448,7 @@ fn expr_kind<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>, expr: &hir::Expr) -> ExprKin
         hir::ExprMatch(..) |
         hir::ExprClosure(..) |
         hir::ExprBlock(..) |
+        hir::ExprLoop(..) |
         hir::ExprRepeat(..) |
         hir::ExprVec(..) => {
             ExprKind::RvalueDps
@@ -2447,7 +2462,6 @@ fn expr_kind<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>, expr: &hir::Expr) -> ExprKin
         hir::ExprAgain(..) |
         hir::ExprRet(..) |
         hir::ExprWhile(..) |
-        hir::ExprLoop(..) |
         hir::ExprAssign(..) |
         hir::ExprInlineAsm(..) |
         hir::ExprAssignOp(..) => {
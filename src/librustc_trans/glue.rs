@@ -557,6 +557,10 @@ fn make_drop_glue<'blk, 'tcx>(bcx: Block<'blk, 'tcx>, v0: ValueRef, g: DropGlueK
                 (ty::TraitDtor(false), false) => {
                     trans_struct_drop(bcx, t, v0)
                 }
+                (ty::NoDtor, _) | (_, true) if def.is_union() => {
+                    // The fields of a union are never dropped.
+                    bcx
+                }
                 (ty::NoDtor, _) | (_, true) => {
                     // No dtor? Just the default case
                     iter_structural_ty(bcx, v0, t, |bb, vv, tt| drop_ty(bb, vv, tt, DebugLoc::None))
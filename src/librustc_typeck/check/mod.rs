@@ -96,7 +96,7 @@ use rustc::ty::{LvaluePreference, NoPreference, PreferMutLvalue};
 use rustc::ty::{self, ToPolyTraitRef, Ty, TyCtxt, Visibility};
 use rustc::ty::{MethodCall, MethodCallee};
 use rustc::ty::adjustment;
-use rustc::ty::fold::TypeFoldable;
+use rustc::ty::fold::{BottomUpFolder, TypeFoldable};
 use rustc::ty::util::{Representability, IntTypeExt};
 use require_c_abi_if_variadic;
 use rscope::{ElisionFailureInfo, RegionScope};
@@ -172,6 +172,16 @@ pub struct Inherited<'a, 'gcx: 'a+'tcx, 'tcx: 'a> {
     deferred_call_resolutions: RefCell<DefIdMap<Vec<DeferredCallResolutionHandler<'gcx, 'tcx>>>>,
 
     deferred_cast_checks: RefCell<Vec<cast::CastCheck<'tcx>>>,
+
+    // Anonymized types found in explicit return types and their
+    // associated fresh inference variable. Writeback resolves these
+    // variables to get the concrete type, which can be used to
+    // deanonymize TyAnon, after typeck is done with all functions.
+    anon_types: RefCell<DefIdMap<Ty<'tcx>>>,
+
+    // Obligations which will have to be checked at the end of
+    // type-checking, after all functions have been inferred.
+    deferred_obligations: RefCell<Vec<traits::DeferredObligation<'tcx>>>,
 }
 
 impl<'a, 'gcx, 'tcx> Deref for Inherited<'a, 'gcx, 'tcx> {
@@ -345,6 +355,18 @@ impl UnsafetyState {
     }
 }
 
+/// A loop enclosing the expression being checked, which `break` may leave.
+#[derive(Copy, Clone)]
+struct LoopCtxt<'tcx> {
+    /// The id of the loop expression, which labelled breaks resolve to.
+    id: ast::NodeId,
+    /// The kind of loop, as written in the source, for diagnostics.
+    descr: &'static str,
+    /// The type of the loop, which the value of every `break` leaving it
+    /// must agree with; `None` if the loop cannot be left with a value.
+    break_ty: Option<Ty<'tcx>>,
+}
+
 #[derive(Clone)]
 pub struct FnCtxt<'a, 'gcx: 'a+'tcx, 'tcx: 'a> {
     ast_ty_to_ty_cache: RefCell<NodeMap<Ty<'tcx>>>,
@@ -365,6 +387,9 @@ pub struct FnCtxt<'a, 'gcx: 'a+'tcx, 'tcx: 'a> {
 
     ps: RefCell<UnsafetyState>,
 
+    // The loops enclosing the expression being checked, innermost last.
+    loops: RefCell<Vec<LoopCtxt<'tcx>>>,
+
     inh: &'a Inherited<'a, 'gcx, 'tcx>,
 }
 
@@ -408,6 +433,8 @@ impl<'a, 'gcx, 'tcx> InheritedBuilder<'a, 'gcx, 'tcx> {
                 locals: RefCell::new(NodeMap()),
                 deferred_call_resolutions: RefCell::new(DefIdMap()),
                 deferred_cast_checks: RefCell::new(Vec::new()),
+                anon_types: RefCell::new(DefIdMap()),
+                deferred_obligations: RefCell::new(Vec::new()),
             })
         })
     }
@@ -475,6 +502,31 @@ pub fn check_item_bodies(ccx: &CrateCtxt) -> CompileResult {
     ccx.tcx.sess.track_errors(|| {
         let mut visit = CheckItemBodiesVisitor { ccx: ccx };
         ccx.tcx.visit_all_items_in_krate(DepNode::TypeckItemBody, &mut visit);
+
+        // Process deferred obligations, now that all functions
+        // bodies have been fully inferred.
+        for (&item_id, obligations) in ccx.deferred_obligations.borrow().iter() {
+            // Use the same DepNode as for the body of the original function/item.
+            let def_id = ccx.tcx.map.local_def_id(item_id);
+            let _task = ccx.tcx.dep_graph.in_task(DepNode::TypeckItemBody(def_id));
+
+            let param_env = ParameterEnvironment::for_item(ccx.tcx, item_id);
+            ccx.tcx.infer_ctxt(None, Some(param_env),
+                               ProjectionMode::AnyFinal).enter(|infcx| {
+                let mut fulfillment_cx = traits::FulfillmentContext::new();
+                for obligation in obligations.iter().map(|o| o.to_obligation()) {
+                    fulfillment_cx.register_predicate_obligation(&infcx, obligation);
+                }
+
+                if let Err(errors) = fulfillment_cx.select_all_or_error(&infcx) {
+                    infcx.report_fulfillment_errors(&errors);
+                }
+
+                if let Err(errors) = fulfillment_cx.select_rfc1592_obligations(&infcx) {
+                    infcx.report_fulfillment_errors_as_warnings(&errors, item_id);
+                }
+            });
+        }
     })
 }
 
@@ -530,7 +582,7 @@ fn check_bare_fn<'a, 'tcx>(ccx: &CrateCtxt<'a, 'tcx>,
         fcx.select_all_obligations_or_error(); // Casts can introduce new obligations.
 
         fcx.regionck_fn(fn_id, fn_span, decl, body);
-        fcx.resolve_type_vars_in_fn(decl, body);
+        fcx.resolve_type_vars_in_fn(decl, body, fn_id);
     });
 }
 
@@ -641,16 +693,25 @@ fn check_fn<'a, 'gcx, 'tcx>(inherited: &'a Inherited<'a, 'gcx, 'tcx>,
 
     // Create the function context.  This is either derived from scratch or,
     // in the case of function expressions, based on the outer context.
-    let fcx = FnCtxt::new(inherited, ret_ty, body.id);
+    let mut fcx = FnCtxt::new(inherited, ret_ty, body.id);
     *fcx.ps.borrow_mut() = UnsafetyState::function(unsafety, unsafety_id);
 
     if let ty::FnConverging(ret_ty) = ret_ty {
         fcx.require_type_is_sized(ret_ty, decl.output.span(), traits::ReturnType);
     }
 
+    fcx.ret_ty = fcx.instantiate_anon_types(fn_id, &ret_ty);
+    let ret_ty = fcx.ret_ty;
+
+    let fn_sig = ty::FnSig {
+        inputs: arg_tys.clone(),
+        output: ret_ty,
+        variadic: fn_sig.variadic
+    };
+
     debug!("fn-sig-map: fn_id={} fn_sig={:?}", fn_id, fn_sig);
 
-    inherited.tables.borrow_mut().liberated_fn_sigs.insert(fn_id, fn_sig.clone());
+    inherited.tables.borrow_mut().liberated_fn_sigs.insert(fn_id, fn_sig);
 
     {
         let mut visit = GatherLocalsVisitor { fcx: &fcx, };
@@ -700,6 +761,17 @@ pub fn check_struct(ccx: &CrateCtxt, id: ast::NodeId, span: Span) {
     }
 }
 
+fn check_union(ccx: &CrateCtxt, id: ast::NodeId, span: Span) {
+    let tcx = ccx.tcx;
+
+    check_representable(tcx, span, id, "union");
+
+    let def = tcx.lookup_adt_def(tcx.map.local_def_id(id));
+    if def.struct_variant().fields.is_empty() {
+        tcx.sess.span_err(span, "unions cannot have zero fields");
+    }
+}
+
 pub fn check_item_type<'a,'tcx>(ccx: &CrateCtxt<'a,'tcx>, it: &'tcx hir::Item) {
     debug!("check_item_type(it.id={}, it.name={})",
            it.id,
@@ -745,6 +817,9 @@ pub fn check_item_type<'a,'tcx>(ccx: &CrateCtxt<'a,'tcx>, it: &'tcx hir::Item) {
       hir::ItemStruct(..) => {
         check_struct(ccx, it.id, it.span);
       }
+      hir::ItemUnion(..) => {
+        check_union(ccx, it.id, it.span);
+      }
       hir::ItemTy(_, ref generics) => {
         let pty_ty = ccx.tcx.node_id_to_type(it.id);
         check_bounds_are_used(ccx, &generics.ty_params, pty_ty);
@@ -1144,7 +1219,8 @@ fn check_const_in_type<'a,'tcx>(ccx: &'a CrateCtxt<'a,'tcx>,
                                 expected_type: Ty<'tcx>) {
     ccx.inherited(None).enter(|inh| {
         let fcx = FnCtxt::new(&inh, ty::FnConverging(expected_type), expr.id);
-        fcx.check_const_with_ty(expr.span, expr, expected_type);
+        let item_id = ccx.tcx.map.get_parent(expr.id);
+        fcx.check_const_with_ty(expr.span, expr, expected_type, item_id);
     });
 }
 
@@ -1158,7 +1234,7 @@ fn check_const<'a,'tcx>(ccx: &CrateCtxt<'a,'tcx>,
         let fcx = FnCtxt::new(&inh, ty::FnConverging(rty), e.id);
         let declty = fcx.tcx.lookup_item_type(ccx.tcx.map.local_def_id(id)).ty;
         fcx.require_type_is_sized(declty, e.span, traits::ConstSized);
-        fcx.check_const_with_ty(sp, e, declty);
+        fcx.check_const_with_ty(sp, e, declty, id);
     });
 }
 
@@ -1221,13 +1297,19 @@ pub fn check_enum_variants<'a,'tcx>(ccx: &CrateCtxt<'a,'tcx>,
                                     vs: &'tcx [hir::Variant],
                                     id: ast::NodeId) {
     let def_id = ccx.tcx.map.local_def_id(id);
-    let hint = *ccx.tcx.lookup_repr_hints(def_id).get(0).unwrap_or(&attr::ReprAny);
+    let hint = ccx.tcx.lookup_enum_repr_hint(def_id);
 
     if hint != attr::ReprAny && vs.is_empty() {
         span_err!(ccx.tcx.sess, sp, E0084,
             "unsupported representation for zero-variant enum");
     }
 
+    if ccx.tcx.lookup_repr_align(def_id).is_some() &&
+       vs.iter().any(|v| v.node.disr_expr.is_some()) {
+        span_err!(ccx.tcx.sess, sp, E0567,
+            "`#[repr(align)]` is not supported on enums with explicit discriminants");
+    }
+
     ccx.inherited(None).enter(|inh| {
         let rty = ccx.tcx.node_id_to_type(id);
         let fcx = FnCtxt::new(&inh, ty::FnConverging(rty), id);
@@ -1235,7 +1317,7 @@ pub fn check_enum_variants<'a,'tcx>(ccx: &CrateCtxt<'a,'tcx>,
         let repr_type_ty = ccx.tcx.enum_repr_type(Some(&hint)).to_ty(ccx.tcx);
         for v in vs {
             if let Some(ref e) = v.node.disr_expr {
-                fcx.check_const_with_ty(e.span, e, repr_type_ty);
+                fcx.check_const_with_ty(e.span, e, repr_type_ty, id);
             }
         }
 
@@ -1436,6 +1518,7 @@ impl<'a, 'gcx, 'tcx> FnCtxt<'a, 'gcx, 'tcx> {
             err_count_on_creation: inh.tcx.sess.err_count(),
             ret_ty: rty,
             ps: RefCell::new(UnsafetyState::function(hir::Unsafety::Normal, 0)),
+            loops: RefCell::new(Vec::new()),
             inh: inh,
         }
     }
@@ -1702,6 +1785,50 @@ impl<'a, 'gcx, 'tcx> FnCtxt<'a, 'gcx, 'tcx> {
             .register_predicate_obligation(self, obligation);
     }
 
+    /// Replace all anonymized types defined by the function `fn_id`
+    /// with fresh inference variables and record them for writeback.
+    fn instantiate_anon_types<T: TypeFoldable<'tcx>>(&self, fn_id: ast::NodeId, value: &T) -> T {
+        value.fold_with(&mut BottomUpFolder { tcx: self.tcx, fldop: |ty| {
+            if let ty::TyAnon(def_id, substs) = ty.sty {
+                // Anonymized types of other functions (e.g. in the signature
+                // of a closure calling them) stay opaque.
+                let parent = self.tcx.map.as_local_node_id(def_id)
+                                 .map(|id| self.tcx.map.get_parent(id));
+                if parent != Some(fn_id) {
+                    return ty;
+                }
+
+                // Use the same type variable if the exact same TyAnon appears more
+                // than once in the return type (e.g. if it's passed to a type alias).
+                if let Some(&ty_var) = self.anon_types.borrow().get(&def_id) {
+                    return ty_var;
+                }
+                let ty_var = self.next_ty_var();
+                self.anon_types.borrow_mut().insert(def_id, ty_var);
+
+                let item_predicates = self.tcx.lookup_predicates(def_id);
+                let bounds = item_predicates.instantiate(self.tcx, substs);
+
+                let span = self.tcx.map.def_id_span(def_id, syntax_pos::DUMMY_SP);
+                for predicate in bounds.predicates {
+                    // Change the predicate to refer to the type variable,
+                    // which will be the concrete type, instead of the TyAnon.
+                    // This also instantiates nested `impl Trait`.
+                    let predicate = self.instantiate_anon_types(fn_id, &predicate);
+
+                    // Require that the predicate holds for the concrete type.
+                    let cause = traits::ObligationCause::new(span, self.body_id,
+                                                             traits::ReturnType);
+                    self.register_predicate(traits::Obligation::new(cause, predicate));
+                }
+
+                ty_var
+            } else {
+                ty
+            }
+        }})
+    }
+
     pub fn to_ty(&self, ast_t: &hir::Ty) -> Ty<'tcx> {
         let t = AstConv::ast_ty_to_ty(self, self, ast_t);
         self.register_wf_obligation(t, ast_t.span, traits::MiscObligation);
@@ -2157,6 +2284,12 @@ impl<'a, 'gcx, 'tcx> FnCtxt<'a, 'gcx, 'tcx> {
         self.select_all_obligations_and_apply_defaults();
 
         let mut fulfillment_cx = self.fulfillment_cx.borrow_mut();
+
+        // Steal the deferred obligations before the fulfillment
+        // context can turn all of them into errors.
+        let obligations = fulfillment_cx.take_deferred_obligations();
+        self.deferred_obligations.borrow_mut().extend(obligations);
+
         match fulfillment_cx.select_all_or_error(self) {
             Ok(()) => { }
             Err(errors) => { self.report_fulfillment_errors(&errors); }
@@ -3034,6 +3167,9 @@ impl<'a, 'gcx, 'tcx> FnCtxt<'a, 'gcx, 'tcx> {
             |actual| if let ty::TyEnum(..) = ty.sty {
                 format!("struct variant `{}::{}` has no field named `{}`",
                         actual, variant.name.as_str(), field.name.node)
+            } else if ty.is_union() {
+                format!("union `{}` has no field named `{}`",
+                        actual, field.name.node)
             } else {
                 format!("structure `{}` has no field named `{}`",
                         actual, field.name.node)
@@ -3088,11 +3224,16 @@ impl<'a, 'gcx, 'tcx> FnCtxt<'a, 'gcx, 'tcx> {
             self.check_expr_coercable_to_type(&field.expr, expected_field_type);
         }
 
-            // Make sure the programmer specified all the fields.
-        if check_completeness &&
+        if adt_ty.is_union() {
+            // A union literal initializes exactly one of its fields.
+            if ast_fields.len() != 1 {
+                tcx.sess.span_err(span, "union expressions should have exactly one field");
+            }
+        } else if check_completeness &&
             !error_happened &&
             !remaining_fields.is_empty()
         {
+            // Make sure the programmer specified all the fields.
             span_err!(tcx.sess, span, E0063,
                       "missing field{} {} in initializer of `{}`",
                       if remaining_fields.len() == 1 {""} else {"s"},
@@ -3177,7 +3318,7 @@ impl<'a, 'gcx, 'tcx> FnCtxt<'a, 'gcx, 'tcx> {
         if let &Some(ref base_expr) = base_expr {
             self.check_expr_has_type(base_expr, expr_ty);
             match expr_ty.sty {
-                ty::TyStruct(adt, substs) => {
+                ty::TyStruct(adt, substs) if !adt.is_union() => {
                     self.tables.borrow_mut().fru_field_types.insert(
                         expr.id,
                         adt.struct_variant().fields.iter().map(|f| {
@@ -3368,7 +3509,10 @@ impl<'a, 'gcx, 'tcx> FnCtxt<'a, 'gcx, 'tcx> {
               }
               self.write_nil(id);
           }
-          hir::ExprBreak(_) => { self.write_ty(id, self.next_diverging_ty_var()); }
+          hir::ExprBreak(opt_label, ref opt_expr) => {
+            self.check_break(expr, opt_label, opt_expr.as_ref().map(|e| &**e));
+            self.write_ty(id, self.next_diverging_ty_var());
+          }
           hir::ExprAgain(_) => { self.write_ty(id, self.next_diverging_ty_var()); }
           hir::ExprRet(ref expr_opt) => {
             match self.ret_ty {
@@ -3425,7 +3569,7 @@ impl<'a, 'gcx, 'tcx> FnCtxt<'a, 'gcx, 'tcx> {
           }
           hir::ExprWhile(ref cond, ref body, _) => {
             self.check_expr_has_type(&cond, tcx.types.bool);
-            self.check_block_no_value(&body);
+            self.with_loop_ctxt(id, "while", None, || self.check_block_no_value(&body));
             let cond_ty = self.expr_ty(&cond);
             let body_ty = self.node_ty(body.id);
             if cond_ty.references_error() || body_ty.references_error() {
@@ -3435,12 +3579,17 @@ impl<'a, 'gcx, 'tcx> FnCtxt<'a, 'gcx, 'tcx> {
                 self.write_nil(id);
             }
           }
-          hir::ExprLoop(ref body, _) => {
-            self.check_block_no_value(&body);
+          hir::ExprLoop(ref body, _, source) => {
+            let (descr, break_ty) = match source {
+                hir::LoopSource::Loop => ("loop", Some(self.next_ty_var())),
+                hir::LoopSource::WhileLet => ("while let", None),
+                hir::LoopSource::ForLoop => ("for", None),
+            };
+            self.with_loop_ctxt(id, descr, break_ty, || self.check_block_no_value(&body));
             if !may_break(tcx, expr.id, &body) {
                 self.write_ty(id, self.next_diverging_ty_var());
             } else {
-                self.write_nil(id);
+                self.write_ty(id, break_ty.unwrap_or(tcx.mk_nil()));
             }
           }
           hir::ExprMatch(ref discrim, ref arms, match_src) => {
@@ -3850,6 +3999,64 @@ impl<'a, 'gcx, 'tcx> FnCtxt<'a, 'gcx, 'tcx> {
         }
     }
 
+    fn with_loop_ctxt<F>(&self,
+                         id: ast::NodeId,
+                         descr: &'static str,
+                         break_ty: Option<Ty<'tcx>>,
+                         f: F)
+        where F: FnOnce()
+    {
+        self.loops.borrow_mut().push(LoopCtxt { id: id, descr: descr, break_ty: break_ty });
+        f();
+        self.loops.borrow_mut().pop();
+    }
+
+    /// Checks a `break` against the loop it leaves, unifying its value (or `()` for a
+    /// `break` without one) with the type of a `loop`.
+    fn check_break(&self,
+                   expr: &'gcx hir::Expr,
+                   opt_label: Option<Spanned<ast::Name>>,
+                   opt_value: Option<&'gcx hir::Expr>) {
+        let target = {
+            let loops = self.loops.borrow();
+            match opt_label {
+                Some(_) => match self.tcx.expect_def(expr.id) {
+                    Def::Label(loop_id) => loops.iter().rev().find(|l| l.id == loop_id).cloned(),
+                    _ => None,
+                },
+                None => loops.last().cloned(),
+            }
+        };
+
+        // A `break` outside of any loop is reported by the loops pass.
+        let target = match target {
+            Some(target) => target,
+            None => {
+                if let Some(value) = opt_value {
+                    self.check_expr(value);
+                }
+                return;
+            }
+        };
+
+        match (target.break_ty, opt_value) {
+            (Some(break_ty), Some(value)) => {
+                self.check_expr_coercable_to_type(value, break_ty);
+            }
+            (Some(break_ty), None) => {
+                self.demand_eqtype(expr.span, break_ty, self.tcx.mk_nil());
+            }
+            (None, Some(value)) => {
+                struct_span_err!(self.tcx.sess, value.span, E0571,
+                                 "`break` with a value in a `{}` loop", target.descr)
+                    .span_label(expr.span, &"can only break with a value inside `loop`")
+                    .emit();
+                self.check_expr(value);
+            }
+            (None, None) => {}
+        }
+    }
+
     pub fn check_block_no_value(&self, blk: &'gcx hir::Block)  {
         self.check_block_with_expected(blk, ExpectHasType(self.tcx.mk_nil()));
         let blkty = self.node_ty(blk.id);
@@ -3942,7 +4149,8 @@ impl<'a, 'gcx, 'tcx> FnCtxt<'a, 'gcx, 'tcx> {
     fn check_const_with_ty(&self,
                            _: Span,
                            e: &'gcx hir::Expr,
-                           declty: Ty<'tcx>) {
+                           declty: Ty<'tcx>,
+                           item_id: ast::NodeId) {
         // Gather locals in statics (because of block expressions).
         // This is technically unnecessary because locals in static items are forbidden,
         // but prevents type checking from blowing up before const checking can properly
@@ -3958,7 +4166,7 @@ impl<'a, 'gcx, 'tcx> FnCtxt<'a, 'gcx, 'tcx> {
         self.select_all_obligations_or_error();
 
         self.regionck_expr(e);
-        self.resolve_type_vars_in_expr(e);
+        self.resolve_type_vars_in_expr(e, item_id);
     }
 
     // Returns the type parameter count and the type for the given definition.
@@ -4551,14 +4759,14 @@ pub fn may_break(tcx: TyCtxt, id: ast::NodeId, b: &hir::Block) -> bool {
     // inside the loop?
     (loop_query(&b, |e| {
         match *e {
-            hir::ExprBreak(None) => true,
+            hir::ExprBreak(None, _) => true,
             _ => false
         }
     })) ||
     // Second: is there a labeled break with label
     // <id> nested anywhere inside the loop?
     (block_query(b, |e| {
-        if let hir::ExprBreak(Some(_)) = e.node {
+        if let hir::ExprBreak(Some(_), _) = e.node {
             tcx.expect_def(e.id) == Def::Label(id)
         } else {
             false
@@ -562,10 +562,19 @@ fn convert_method<'a, 'tcx>(ccx: &CrateCtxt<'a, 'tcx>,
     let ty_generic_predicates =
         ty_generic_predicates_for_fn(ccx, &sig.generics, rcvr_ty_predicates);
 
-    let (fty, explicit_self_category) =
+    let (fty, explicit_self_category) = {
+        // `impl Trait` is only allowed in the return type of inherent methods.
+        let anon_scope = match container {
+            ImplContainer(impl_def_id) if ccx.tcx.impl_trait_ref(impl_def_id).is_none() => {
+                Some(AnonTypeScope::new(&ty_generics))
+            }
+            _ => None
+        };
         AstConv::ty_of_method(&ccx.icx(&(rcvr_ty_predicates, &sig.generics)),
                               sig,
-                              untransformed_rcvr_ty);
+                              untransformed_rcvr_ty,
+                              anon_scope)
+    };
 
     let def_id = ccx.tcx.map.local_def_id(id);
     let substs = mk_item_substs(ccx, &ty_generics);
@@ -928,6 +937,17 @@ fn convert_item(ccx: &CrateCtxt, it: &hir::Item) {
                 convert_variant_ctor(ccx, struct_def.id(), variant, scheme, predicates);
             }
         },
+        hir::ItemUnion(ref vdata, _) => {
+            let (scheme, predicates) = convert_typed_item(ccx, it);
+            write_ty_to_tcx(ccx, it.id, scheme.ty);
+
+            let it_def_id = ccx.tcx.map.local_def_id(it.id);
+            let variant = tcx.lookup_adt_def_master(it_def_id).struct_variant();
+
+            for (f, ty_f) in vdata.fields().iter().zip(variant.fields.iter()) {
+                convert_field(ccx, &scheme.generics, &predicates, f, ty_f)
+            }
+        },
         hir::ItemTy(_, ref generics) => {
             ensure_no_ty_param_bounds(ccx, it.span, generics, "type");
             let (scheme, _) = convert_typed_item(ccx, it);
@@ -1053,6 +1073,16 @@ fn convert_struct_def<'a, 'tcx>(ccx: &CrateCtxt<'a, 'tcx>,
     adt
 }
 
+fn convert_union_def<'a, 'tcx>(ccx: &CrateCtxt<'a, 'tcx>,
+                               it: &hir::Item,
+                               def: &hir::VariantData)
+                               -> ty::AdtDefMaster<'tcx>
+{
+    let did = ccx.tcx.map.local_def_id(it.id);
+    let variants = vec![convert_struct_variant(ccx, did, it.name, ConstInt::Infer(0), def)];
+    ccx.tcx.intern_adt_def(did, ty::AdtKind::Union, variants)
+}
+
     fn evaluate_disr_expr(ccx: &CrateCtxt, repr_ty: attr::IntType, e: &hir::Expr)
                           -> Option<ty::Disr> {
         debug!("disr expr, checking {}", pprust::expr_to_string(e));
@@ -1193,6 +1223,7 @@ fn ensure_super_predicates_step(ccx: &CrateCtxt,
                                     self_param_ty,
                                     bounds,
                                     SizedByDefault::No,
+                                    None,
                                     item.span);
 
         let superbounds1 = superbounds1.predicates(tcx, self_param_ty);
@@ -1403,6 +1434,7 @@ fn convert_trait_predicates<'a, 'tcx>(ccx: &CrateCtxt<'a, 'tcx>, it: &hir::Item)
                                         assoc_ty,
                                         bounds,
                                         SizedByDefault::Yes,
+                                        None,
                                         trait_item.span);
 
             bounds.predicates(ccx.tcx, assoc_ty).into_iter()
@@ -1459,7 +1491,10 @@ fn compute_type_scheme_of_item<'a,'tcx>(ccx: &CrateCtxt<'a,'tcx>,
         }
         hir::ItemFn(ref decl, unsafety, _, abi, ref generics, _) => {
             let ty_generics = ty_generics_for_fn(ccx, generics, &ty::Generics::empty());
-            let tofd = AstConv::ty_of_bare_fn(&ccx.icx(generics), unsafety, abi, &decl);
+            let tofd = {
+                let anon_scope = Some(AnonTypeScope::new(&ty_generics));
+                AstConv::ty_of_bare_fn(&ccx.icx(generics), unsafety, abi, &decl, anon_scope)
+            };
             let def_id = ccx.tcx.map.local_def_id(it.id);
             let substs = mk_item_substs(ccx, &ty_generics);
             let ty = tcx.mk_fn_def(def_id, substs, tofd);
@@ -1484,6 +1519,13 @@ fn compute_type_scheme_of_item<'a,'tcx>(ccx: &CrateCtxt<'a,'tcx>,
             let t = tcx.mk_struct(def, substs);
             ty::TypeScheme { ty: t, generics: ty_generics }
         }
+        hir::ItemUnion(ref un, ref generics) => {
+            let def = convert_union_def(ccx, it, un);
+            let ty_generics = ty_generics_for_type(ccx, generics);
+            let substs = mk_item_substs(ccx, &ty_generics);
+            let t = tcx.mk_struct(def, substs);
+            ty::TypeScheme { ty: t, generics: ty_generics }
+        }
         hir::ItemDefaultImpl(..) |
         hir::ItemTrait(..) |
         hir::ItemImpl(..) |
@@ -1520,7 +1562,8 @@ fn convert_typed_item<'a, 'tcx>(ccx: &CrateCtxt<'a, 'tcx>,
         hir::ItemEnum(_, ref generics) => {
             ty_generic_predicates_for_type_or_impl(ccx, generics)
         }
-        hir::ItemStruct(_, ref generics) => {
+        hir::ItemStruct(_, ref generics) |
+        hir::ItemUnion(_, ref generics) => {
             ty_generic_predicates_for_type_or_impl(ccx, generics)
         }
         hir::ItemDefaultImpl(..) |
@@ -1692,10 +1735,10 @@ fn ty_generic_predicates_for_fn<'a,'tcx>(ccx: &CrateCtxt<'a,'tcx>,
 }
 
 // Add the Sized bound, unless the type parameter is marked as `?Sized`.
-fn add_unsized_bound<'tcx>(astconv: &AstConv<'tcx, 'tcx>,
-                           bounds: &mut ty::BuiltinBounds,
-                           ast_bounds: &[hir::TyParamBound],
-                           span: Span)
+fn add_unsized_bound<'gcx: 'tcx, 'tcx>(astconv: &AstConv<'gcx, 'tcx>,
+                                       bounds: &mut ty::BuiltinBounds,
+                                       ast_bounds: &[hir::TyParamBound],
+                                       span: Span)
 {
     let tcx = astconv.tcx();
 
@@ -1773,6 +1816,7 @@ fn ty_generic_predicates<'a,'tcx>(ccx: &CrateCtxt<'a,'tcx>,
                                     param_ty,
                                     &param.bounds,
                                     SizedByDefault::Yes,
+                                    None,
                                     param.span);
         let predicates = bounds.predicates(ccx.tcx, param_ty);
         result.predicates.extend(space, predicates.into_iter());
@@ -1815,7 +1859,8 @@ fn ty_generic_predicates<'a,'tcx>(ccx: &CrateCtxt<'a,'tcx>,
                                 conv_poly_trait_ref(&ccx.icx(&(base_predicates, ast_generics)),
                                                     ty,
                                                     poly_trait_ref,
-                                                    &mut projections);
+                                                    &mut projections,
+                                                    None);
 
                             result.predicates.push(space, trait_ref.to_predicate());
 
@@ -2033,23 +2078,25 @@ fn compute_object_lifetime_default<'a,'tcx>(ccx: &CrateCtxt<'a,'tcx>,
     }
 }
 
-enum SizedByDefault { Yes, No, }
+pub enum SizedByDefault { Yes, No, }
 
 /// Translate the AST's notion of ty param bounds (which are an enum consisting of a newtyped Ty or
 /// a region) to ty's notion of ty param bounds, which can either be user-defined traits, or the
 /// built-in trait (formerly known as kind): Send.
-fn compute_bounds<'tcx>(astconv: &AstConv<'tcx, 'tcx>,
-                        param_ty: ty::Ty<'tcx>,
-                        ast_bounds: &[hir::TyParamBound],
-                        sized_by_default: SizedByDefault,
-                        span: Span)
-                        -> Bounds<'tcx>
+pub fn compute_bounds<'gcx: 'tcx, 'tcx>(astconv: &AstConv<'gcx, 'tcx>,
+                                        param_ty: ty::Ty<'tcx>,
+                                        ast_bounds: &[hir::TyParamBound],
+                                        sized_by_default: SizedByDefault,
+                                        anon_scope: Option<AnonTypeScope>,
+                                        span: Span)
+                                        -> Bounds<'tcx>
 {
     let mut bounds =
         conv_param_bounds(astconv,
                           span,
                           param_ty,
-                          ast_bounds);
+                          ast_bounds,
+                          anon_scope);
 
     if let SizedByDefault::Yes = sized_by_default {
         add_unsized_bound(astconv,
@@ -2076,7 +2123,7 @@ fn predicates_from_bound<'tcx>(astconv: &AstConv<'tcx, 'tcx>,
     match *bound {
         hir::TraitTyParamBound(ref tr, hir::TraitBoundModifier::None) => {
             let mut projections = Vec::new();
-            let pred = conv_poly_trait_ref(astconv, param_ty, tr, &mut projections);
+            let pred = conv_poly_trait_ref(astconv, param_ty, tr, &mut projections, None);
             projections.into_iter()
                        .map(|p| p.to_predicate())
                        .chain(Some(pred.to_predicate()))
@@ -2093,24 +2140,26 @@ fn predicates_from_bound<'tcx>(astconv: &AstConv<'tcx, 'tcx>,
     }
 }
 
-fn conv_poly_trait_ref<'tcx>(astconv: &AstConv<'tcx, 'tcx>,
-                             param_ty: Ty<'tcx>,
-                             trait_ref: &hir::PolyTraitRef,
-                             projections: &mut Vec<ty::PolyProjectionPredicate<'tcx>>)
-                             -> ty::PolyTraitRef<'tcx>
+fn conv_poly_trait_ref<'gcx: 'tcx, 'tcx>(astconv: &AstConv<'gcx, 'tcx>,
+                                         param_ty: Ty<'tcx>,
+                                         trait_ref: &hir::PolyTraitRef,
+                                         projections: &mut Vec<ty::PolyProjectionPredicate<'tcx>>,
+                                         anon_scope: Option<AnonTypeScope>)
+                                         -> ty::PolyTraitRef<'tcx>
 {
     AstConv::instantiate_poly_trait_ref(astconv,
-                                        &ExplicitRscope,
+                                        &MaybeWithAnonTypes::new(ExplicitRscope, anon_scope),
                                         trait_ref,
                                         Some(param_ty),
                                         projections)
 }
 
-fn conv_param_bounds<'a,'tcx>(astconv: &AstConv<'tcx, 'tcx>,
-                              span: Span,
-                              param_ty: ty::Ty<'tcx>,
-                              ast_bounds: &[hir::TyParamBound])
-                              -> Bounds<'tcx>
+fn conv_param_bounds<'gcx: 'tcx, 'tcx>(astconv: &AstConv<'gcx, 'tcx>,
+                                       span: Span,
+                                       param_ty: ty::Ty<'tcx>,
+                                       ast_bounds: &[hir::TyParamBound],
+                                       anon_scope: Option<AnonTypeScope>)
+                                       -> Bounds<'tcx>
 {
     let tcx = astconv.tcx();
     let PartitionedBounds {
@@ -2126,7 +2175,8 @@ fn conv_param_bounds<'a,'tcx>(astconv: &AstConv<'tcx, 'tcx>,
                     .map(|bound| conv_poly_trait_ref(astconv,
                                                      param_ty,
                                                      *bound,
-                                                     &mut projection_bounds))
+                                                     &mut projection_bounds,
+                                                     anon_scope))
                     .collect();
 
     let region_bounds: Vec<ty::Region> =
@@ -27,7 +27,7 @@ pub fn parameters_for_type<'tcx>(ty: Ty<'tcx>,
                                  include_projections: bool) -> Vec<Parameter> {
     let mut result = vec![];
     ty.maybe_walk(|t| match t.sty {
-        ty::TyProjection(..) if !include_projections => {
+        ty::TyProjection(..) | ty::TyAnon(..) if !include_projections => {
 
             false // projections are not injective.
         }
@@ -69,6 +69,8 @@ fn parameters_for_type_shallow<'tcx>(ty: Ty<'tcx>) -> Vec<Parameter> {
             parameters_for_regions_in_substs(&data.principal.skip_binder().substs),
         ty::TyProjection(ref pi) =>
             parameters_for_regions_in_substs(&pi.trait_ref.substs),
+        ty::TyAnon(_, substs) =>
+            parameters_for_regions_in_substs(substs),
         ty::TyBool | ty::TyChar | ty::TyInt(..) | ty::TyUint(..) |
         ty::TyFloat(..) | ty::TyBox(..) | ty::TyStr |
         ty::TyArray(..) | ty::TySlice(..) |
@@ -4029,6 +4029,40 @@ impl SpaceLlama for i32 {
 ```
 "##,
 
+E0571: r##"
+A `break` statement with a value was found in a `while`, `while let` or `for`
+loop. Erroneous code example:
+
+```compile_fail,E0571
+#![feature(loop_break_value)]
+
+let mut i = 1;
+let result = while i < 10 {
+    if i % 7 == 0 {
+        break i; // error: `break` with a value in a `while` loop
+    }
+    i += 1;
+};
+```
+
+These loops can end without reaching a `break`, so they cannot produce a value.
+Only a `loop` expression can be left with `break EXPR`, and all of its `break`
+expressions then determine the type of the `loop`:
+
+```
+#![feature(loop_break_value)]
+
+let mut i = 1;
+let result = loop {
+    if i % 7 == 0 {
+        break i; // ok!
+    }
+    i += 1;
+};
+assert_eq!(result, 7);
+```
+"##,
+
 }
 
 register_diagnostics! {
@@ -4101,4 +4135,10 @@ register_diagnostics! {
     E0528, // expected at least {} elements, found {}
     E0529, // slice pattern expects array or slice, not `{}`
     E0533, // `{}` does not name a unit variant, unit struct or a constant
+    E0562, // `impl Trait` not allowed outside of function
+           // and inherent method return types
+    E0563, // cannot determine a type for this `impl Trait`: {}
+    E0564, // only named lifetimes are allowed in `impl Trait`,
+           // but `{}` was found in the type `{}`
+    E0567, // `#[repr(align)]` enum with explicit discriminants
 }
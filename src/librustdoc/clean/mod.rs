@@ -1469,12 +1469,15 @@ pub enum Type {
 
     // for<'a> Foo(&'a)
     PolyTraitRef(Vec<TyParamBound>),
+
+    // impl TraitA+TraitB
+    ImplTrait(Vec<TyParamBound>),
 }
 
 #[derive(Clone, RustcEncodable, RustcDecodable, PartialEq, Eq, Hash, Copy, Debug)]
 pub enum PrimitiveType {
-    Isize, I8, I16, I32, I64,
-    Usize, U8, U16, U32, U64,
+    Isize, I8, I16, I32, I64, I128,
+    Usize, U8, U16, U32, U64, U128,
     F32, F64,
     Char,
     Bool,
@@ -1547,11 +1550,13 @@ impl PrimitiveType {
             "i16" => Some(I16),
             "i32" => Some(I32),
             "i64" => Some(I64),
+            "i128" => Some(I128),
             "usize" => Some(Usize),
             "u8" => Some(U8),
             "u16" => Some(U16),
             "u32" => Some(U32),
             "u64" => Some(U64),
+            "u128" => Some(U128),
             "bool" => Some(Bool),
             "char" => Some(Char),
             "str" => Some(Str),
@@ -1585,11 +1590,13 @@ impl PrimitiveType {
             I16 => "i16",
             I32 => "i32",
             I64 => "i64",
+            I128 => "i128",
             Usize => "usize",
             U8 => "u8",
             U16 => "u16",
             U32 => "u32",
             U64 => "u64",
+            U128 => "u128",
             F32 => "f32",
             F64 => "f64",
             Str => "str",
@@ -1662,6 +1669,7 @@ impl Clean<Type> for hir::Ty {
             }
             TyBareFn(ref barefn) => BareFunction(box barefn.clean(cx)),
             TyPolyTraitRef(ref bounds) => PolyTraitRef(bounds.clean(cx)),
+            TyImplTrait(ref bounds) => ImplTrait(bounds.clean(cx)),
             TyInfer => Infer,
             TyTypeof(..) => panic!("Unimplemented type {:?}", self.node),
         }
@@ -1678,11 +1686,13 @@ impl<'tcx> Clean<Type> for ty::Ty<'tcx> {
             ty::TyInt(ast::IntTy::I16) => Primitive(I16),
             ty::TyInt(ast::IntTy::I32) => Primitive(I32),
             ty::TyInt(ast::IntTy::I64) => Primitive(I64),
+            ty::TyInt(ast::IntTy::I128) => Primitive(I128),
             ty::TyUint(ast::UintTy::Us) => Primitive(Usize),
             ty::TyUint(ast::UintTy::U8) => Primitive(U8),
             ty::TyUint(ast::UintTy::U16) => Primitive(U16),
             ty::TyUint(ast::UintTy::U32) => Primitive(U32),
             ty::TyUint(ast::UintTy::U64) => Primitive(U64),
+            ty::TyUint(ast::UintTy::U128) => Primitive(U128),
             ty::TyFloat(ast::FloatTy::F32) => Primitive(F32),
             ty::TyFloat(ast::FloatTy::F64) => Primitive(F64),
             ty::TyStr => Primitive(Str),
@@ -1746,6 +1756,24 @@ impl<'tcx> Clean<Type> for ty::Ty<'tcx> {
 
             ty::TyProjection(ref data) => data.clean(cx),
 
+            ty::TyAnon(def_id, substs) => {
+                // Grab the "TraitA + TraitB" from `impl TraitA + TraitB`,
+                // by looking up the predicates associated with the def_id.
+                let tcx = cx.tcx();
+                let item_predicates = tcx.lookup_predicates(def_id);
+                let substs = tcx.lift(&substs).unwrap();
+                let bounds = item_predicates.instantiate(tcx, substs);
+                ImplTrait(bounds.predicates.into_vec().into_iter().filter_map(|predicate| {
+                    predicate.to_opt_poly_trait_ref().and_then(|trait_ref| {
+                        if Some(trait_ref.def_id()) == tcx.lang_items.sized_trait() {
+                            None
+                        } else {
+                            Some(trait_ref.clean(cx))
+                        }
+                    })
+                }).collect())
+            }
+
             ty::TyParam(ref p) => Generic(p.name.to_string()),
 
             ty::TyClosure(..) => Tuple(vec![]), // FIXME(pcwalton)
@@ -2318,11 +2346,13 @@ fn build_deref_target_impls(cx: &DocContext,
             I16 => tcx.lang_items.i16_impl(),
             I32 => tcx.lang_items.i32_impl(),
             I64 => tcx.lang_items.i64_impl(),
+            I128 => tcx.lang_items.i128_impl(),
             Usize => tcx.lang_items.usize_impl(),
             U8 => tcx.lang_items.u8_impl(),
             U16 => tcx.lang_items.u16_impl(),
             U32 => tcx.lang_items.u32_impl(),
             U64 => tcx.lang_items.u64_impl(),
+            U128 => tcx.lang_items.u128_impl(),
             F32 => tcx.lang_items.f32_impl(),
             F64 => tcx.lang_items.f64_impl(),
             Char => tcx.lang_items.char_impl(),
@@ -2642,11 +2672,13 @@ fn resolve_type(cx: &DocContext,
             hir::TyInt(ast::IntTy::I16) => return Primitive(I16),
             hir::TyInt(ast::IntTy::I32) => return Primitive(I32),
             hir::TyInt(ast::IntTy::I64) => return Primitive(I64),
+            hir::TyInt(ast::IntTy::I128) => return Primitive(I128),
             hir::TyUint(ast::UintTy::Us) => return Primitive(Usize),
             hir::TyUint(ast::UintTy::U8) => return Primitive(U8),
             hir::TyUint(ast::UintTy::U16) => return Primitive(U16),
             hir::TyUint(ast::UintTy::U32) => return Primitive(U32),
             hir::TyUint(ast::UintTy::U64) => return Primitive(U64),
+            hir::TyUint(ast::UintTy::U128) => return Primitive(U128),
             hir::TyFloat(ast::FloatTy::F32) => return Primitive(F32),
             hir::TyFloat(ast::FloatTy::F64) => return Primitive(F64),
         },
@@ -34,6 +34,7 @@ Core encoding and decoding interfaces.
 #![feature(staged_api)]
 #![feature(unicode)]
 #![feature(question_mark)]
+#![cfg_attr(not(stage0), feature(i128_type))]
 #![cfg_attr(test, feature(test))]
 
 // test harness access
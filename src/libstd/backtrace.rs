@@ -0,0 +1,283 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Capturing and inspecting stack backtraces.
+//!
+//! A [`Backtrace`] records the call stack of the current thread at the point
+//! where it was created, and turns the recorded addresses into symbol names,
+//! file names and line numbers right away. Symbolication is comparatively
+//! slow, so backtraces are best captured where a failure is actually going to
+//! be reported.
+//!
+//! How much information is available depends on the platform and on how the
+//! program was built. Symbol names require a symbol table, and file and line
+//! information requires debug info. Any of these may be missing, in which case
+//! the corresponding accessor returns `None`.
+//!
+//! [`Backtrace`]: struct.Backtrace.html
+//!
+//! # Examples
+//!
+//! ```
+//! #![feature(backtrace)]
+//!
+//! use std::backtrace::Backtrace;
+//!
+//! let bt = Backtrace::new();
+//! for frame in bt.frames() {
+//!     for symbol in frame.symbols() {
+//!         if let Some(name) = symbol.name() {
+//!             println!("{}", name);
+//!         }
+//!     }
+//! }
+//! ```
+
+#![unstable(feature = "backtrace", issue = "0")]
+
+use fmt;
+use os::raw::c_void;
+use path::{Path, PathBuf};
+use str;
+use sys_common::backtrace::{self, Symbol, HEX_WIDTH};
+
+/// A captured stack backtrace of a thread.
+///
+/// The frames and their symbols are recorded when the backtrace is created
+/// with [`new`], and can be inspected with [`frames`] or printed with `{:?}`.
+///
+/// `Backtrace` is `Send` and `Sync`, so it can be stored in error types which
+/// are sent across threads.
+///
+/// [`new`]: #method.new
+/// [`frames`]: #method.frames
+#[unstable(feature = "backtrace", issue = "0")]
+pub struct Backtrace {
+    frames: Vec<BacktraceFrame>,
+}
+
+/// A single frame of a [`Backtrace`].
+///
+/// [`Backtrace`]: struct.Backtrace.html
+#[unstable(feature = "backtrace", issue = "0")]
+pub struct BacktraceFrame {
+    ip: usize,
+    symbol_address: usize,
+    symbols: Vec<BacktraceSymbol>,
+}
+
+/// Symbol information for a [`BacktraceFrame`].
+///
+/// [`BacktraceFrame`]: struct.BacktraceFrame.html
+#[unstable(feature = "backtrace", issue = "0")]
+pub struct BacktraceSymbol {
+    name: Option<String>,
+    filename: Option<PathBuf>,
+    lineno: Option<u32>,
+}
+
+impl Backtrace {
+    /// Captures a backtrace of the current thread and resolves its symbols,
+    /// which may be slow.
+    ///
+    /// The first frames of the backtrace belong to this function and to the
+    /// platform's unwinding machinery.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(backtrace)]
+    ///
+    /// use std::backtrace::Backtrace;
+    ///
+    /// let bt = Backtrace::new();
+    /// println!("{:?}", bt);
+    /// ```
+    #[inline(never)] // so this function shows up as a frame of its own
+    #[unstable(feature = "backtrace", issue = "0")]
+    pub fn new() -> Backtrace {
+        let mut raw = Vec::new();
+        // Errors while tracing leave us with the frames found so far, which
+        // is the best we can do.
+        let _ = backtrace::trace(&mut raw);
+        let mut frames = raw.iter().map(|frame| {
+            BacktraceFrame {
+                ip: frame.exact_position as usize,
+                symbol_address: frame.symbol_addr as usize,
+                symbols: Vec::new(),
+            }
+        }).collect::<Vec<_>>();
+        // Symbols which couldn't be resolved are simply left out.
+        let _ = backtrace::resolve(&raw, &mut |i, symbol| {
+            frames[i].symbols.push(BacktraceSymbol::new(symbol));
+        });
+        Backtrace { frames: frames }
+    }
+
+    /// Returns the frames of this backtrace, innermost first.
+    #[unstable(feature = "backtrace", issue = "0")]
+    pub fn frames(&self) -> &[BacktraceFrame] {
+        &self.frames
+    }
+}
+
+impl BacktraceFrame {
+    /// Returns the address of the instruction this frame is executing.
+    #[unstable(feature = "backtrace", issue = "0")]
+    pub fn ip(&self) -> *mut c_void {
+        self.ip as *mut c_void
+    }
+
+    /// Returns the starting address of the function this frame belongs to.
+    ///
+    /// On platforms where this can't be determined this is the same as
+    /// [`ip`](#method.ip).
+    #[unstable(feature = "backtrace", issue = "0")]
+    pub fn symbol_address(&self) -> *mut c_void {
+        self.symbol_address as *mut c_void
+    }
+
+    /// Returns the symbols resolved for this frame.
+    ///
+    /// This is usually a single symbol, but a frame into which other
+    /// functions were inlined resolves to one symbol per inlined call,
+    /// innermost first. It is empty if nothing could be resolved.
+    #[unstable(feature = "backtrace", issue = "0")]
+    pub fn symbols(&self) -> &[BacktraceSymbol] {
+        &self.symbols
+    }
+}
+
+impl BacktraceSymbol {
+    fn new(symbol: Symbol) -> BacktraceSymbol {
+        let name = symbol.name.as_ref().and_then(|s| str::from_utf8(s).ok()).map(|s| {
+            let mut demangled = Vec::new();
+            match backtrace::demangle(&mut demangled, s) {
+                Ok(()) => String::from_utf8_lossy(&demangled).into_owned(),
+                Err(..) => s.to_string(),
+            }
+        });
+        BacktraceSymbol {
+            name: name,
+            filename: symbol.filename.map(path_from_bytes),
+            lineno: symbol.lineno,
+        }
+    }
+
+    /// Returns the demangled name of this symbol.
+    #[unstable(feature = "backtrace", issue = "0")]
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_ref().map(|s| &s[..])
+    }
+
+    /// Returns the name of the source file this symbol was defined in.
+    #[unstable(feature = "backtrace", issue = "0")]
+    pub fn filename(&self) -> Option<&Path> {
+        self.filename.as_ref().map(|p| p.as_path())
+    }
+
+    /// Returns the line number in the source file this symbol is executing.
+    #[unstable(feature = "backtrace", issue = "0")]
+    pub fn lineno(&self) -> Option<u32> {
+        self.lineno
+    }
+}
+
+#[cfg(unix)]
+fn path_from_bytes(bytes: Vec<u8>) -> PathBuf {
+    use ffi::OsString;
+    use os::unix::ffi::OsStringExt;
+    PathBuf::from(OsString::from_vec(bytes))
+}
+
+#[cfg(windows)]
+fn path_from_bytes(bytes: Vec<u8>) -> PathBuf {
+    PathBuf::from(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+#[unstable(feature = "backtrace", issue = "0")]
+impl fmt::Debug for Backtrace {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "stack backtrace:")?;
+        for (idx, frame) in self.frames().iter().enumerate() {
+            write!(f, "  {:2}: {:2$?}", idx, frame.ip(), HEX_WIDTH)?;
+            if frame.symbols().is_empty() {
+                writeln!(f, " - <unknown>")?;
+            }
+            for (i, symbol) in frame.symbols().iter().enumerate() {
+                if i != 0 {
+                    // prior line: "  ##: {:2$}"
+                    write!(f, "      {:1$}", "", HEX_WIDTH)?;
+                }
+                writeln!(f, " - {}", symbol.name().unwrap_or("<unknown>"))?;
+                if let (Some(file), Some(line)) = (symbol.filename(), symbol.lineno()) {
+                    writeln!(f, "      {:3$}at {}:{}", "", file.display(), line, HEX_WIDTH)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[unstable(feature = "backtrace", issue = "0")]
+impl fmt::Debug for BacktraceFrame {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("BacktraceFrame")
+         .field("ip", &self.ip())
+         .field("symbol_address", &self.symbol_address())
+         .field("symbols", &self.symbols)
+         .finish()
+    }
+}
+
+#[unstable(feature = "backtrace", issue = "0")]
+impl fmt::Debug for BacktraceSymbol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("BacktraceSymbol")
+         .field("name", &self.name)
+         .field("filename", &self.filename)
+         .field("lineno", &self.lineno)
+         .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::v1::*;
+
+    use super::Backtrace;
+    use thread;
+
+    #[test]
+    fn capture() {
+        let bt = Backtrace::new();
+        assert!(!bt.frames().is_empty());
+        assert!(bt.frames().iter().all(|frame| !frame.ip().is_null()));
+    }
+
+    #[test]
+    fn debug() {
+        let bt = Backtrace::new();
+        let s = format!("{:?}", bt);
+        assert!(s.starts_with("stack backtrace:\n"));
+        assert!(s.lines().filter(|l| l.contains(" - ")).count() >= bt.frames().len());
+    }
+
+    #[test]
+    fn send_across_threads() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<Backtrace>();
+
+        let bt = Backtrace::new();
+        let n = bt.frames().len();
+        let bt = thread::spawn(move || bt).join().unwrap();
+        assert_eq!(bt.frames().len(), n);
+    }
+}
@@ -17,13 +17,15 @@
 
 #![stable(feature = "rust1", since = "1.0.0")]
 
+use cmp::Ordering;
 use fmt;
 use ffi::OsString;
-use io::{self, SeekFrom, Seek, Read, Write};
+use io::{self, SeekFrom, Seek, Read, Write, IoSlice, IoSliceMut};
 use path::{Path, PathBuf};
 use sys::fs as fs_imp;
 use sys_common::{AsInnerMut, FromInner, AsInner, IntoInner};
-use vec::Vec;
+use usize;
+use vec::{self, Vec};
 use time::SystemTime;
 
 /// A reference to an open file on the filesystem.
@@ -153,6 +155,55 @@ pub struct DirBuilder {
     recursive: bool,
 }
 
+/// A builder used to configure a recursive walk over a directory tree.
+///
+/// By default the walk has no depth limit, does not follow symbolic links
+/// and yields the entries of each directory in whatever order the platform
+/// returns them.
+#[unstable(feature = "fs_walk_dir", issue = "0")]
+#[derive(Clone, Debug)]
+pub struct WalkDirBuilder {
+    max_depth: usize,
+    follow_links: bool,
+    sort: bool,
+}
+
+/// An iterator over the entries of a directory tree, created by `walk_dir`
+/// or `WalkDirBuilder::walk`.
+///
+/// Entries are yielded depth first, each directory's entries being followed
+/// immediately by its descendants. The root of the walk itself is not
+/// yielded.
+///
+/// Errors are reported per entry: an error reading one directory (or a
+/// filesystem loop found while following links) is yielded in place and the
+/// walk carries on with the rest of the tree.
+#[unstable(feature = "fs_walk_dir", issue = "0")]
+pub struct WalkDir {
+    opts: WalkDirBuilder,
+    stack: Vec<WalkLevel>,
+    // The directory yielded last, which is only opened on the next call to
+    // `next` so that `skip_current_dir` can prune it first.
+    pending: Option<PathBuf>,
+    depth: usize,
+}
+
+struct WalkLevel {
+    entries: WalkEntries,
+    id: Option<FileId>,
+}
+
+enum WalkEntries {
+    Unsorted(ReadDir),
+    Sorted(vec::IntoIter<io::Result<DirEntry>>),
+}
+
+// What identifies a directory when looking for filesystem loops.
+#[cfg(unix)]
+type FileId = (u64, u64);
+#[cfg(windows)]
+type FileId = PathBuf;
+
 impl File {
     /// Attempts to open a file in read-only mode.
     ///
@@ -310,6 +361,158 @@ impl File {
             inner: self.inner.duplicate()?
         })
     }
+
+    /// Acquires a shared (read) lock on the file, blocking until it can be
+    /// acquired.
+    ///
+    /// Any number of handles may hold a shared lock at the same time, but
+    /// none of them while some handle holds an exclusive lock. The lock is
+    /// released by `unlock` or when the file is closed.
+    ///
+    /// Locks are advisory: they only exclude other callers of these locking
+    /// methods, not reads or writes.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This corresponds to `flock` with `LOCK_SH` on Unix and to `LockFileEx`
+    /// on Windows.
+    ///
+    /// On Unix the lock belongs to the underlying open file, so it is shared
+    /// by every handle created from this one with `try_clone`, and locking
+    /// again through any of them converts the existing lock rather than
+    /// blocking. On Windows the lock belongs to this handle, and locking
+    /// again while already holding a lock through it or a clone of it may
+    /// deadlock.
+    ///
+    /// Unix targets without `flock`, such as Solaris and Emscripten, return
+    /// an error of kind `Other` from all of the locking methods.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_lock)]
+    /// use std::fs::File;
+    ///
+    /// # fn foo() -> std::io::Result<()> {
+    /// let f = try!(File::open("foo.txt"));
+    /// try!(f.lock_shared());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[unstable(feature = "file_lock", issue = "0")]
+    pub fn lock_shared(&self) -> io::Result<()> {
+        self.inner.lock_shared()
+    }
+
+    /// Acquires an exclusive (write) lock on the file, blocking until it can
+    /// be acquired.
+    ///
+    /// No other handle may hold any lock on the file while an exclusive lock
+    /// is held. The lock is released by `unlock` or when the file is closed.
+    ///
+    /// See `lock_shared` for how locks interact with `try_clone` handles.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This corresponds to `flock` with `LOCK_EX` on Unix and to `LockFileEx`
+    /// with `LOCKFILE_EXCLUSIVE_LOCK` on Windows.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_lock)]
+    /// use std::fs::File;
+    ///
+    /// # fn foo() -> std::io::Result<()> {
+    /// let f = try!(File::create("foo.txt"));
+    /// try!(f.lock_exclusive());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[unstable(feature = "file_lock", issue = "0")]
+    pub fn lock_exclusive(&self) -> io::Result<()> {
+        self.inner.lock_exclusive()
+    }
+
+    /// Attempts to acquire a shared lock on the file without blocking.
+    ///
+    /// Returns `Ok(true)` if the lock was acquired and `Ok(false)` if another
+    /// handle holds an exclusive lock. See `lock_shared` for the semantics of
+    /// the lock itself.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_lock)]
+    /// use std::fs::File;
+    ///
+    /// # fn foo() -> std::io::Result<()> {
+    /// let f = try!(File::open("foo.txt"));
+    /// if !try!(f.try_lock_shared()) {
+    ///     println!("someone else is writing to foo.txt");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[unstable(feature = "file_lock", issue = "0")]
+    pub fn try_lock_shared(&self) -> io::Result<bool> {
+        self.inner.try_lock_shared()
+    }
+
+    /// Attempts to acquire an exclusive lock on the file without blocking.
+    ///
+    /// Returns `Ok(true)` if the lock was acquired and `Ok(false)` if another
+    /// handle holds any lock on the file. See `lock_exclusive` for the
+    /// semantics of the lock itself.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_lock)]
+    /// use std::fs::File;
+    ///
+    /// # fn foo() -> std::io::Result<()> {
+    /// let f = try!(File::create("foo.txt"));
+    /// if !try!(f.try_lock_exclusive()) {
+    ///     println!("foo.txt is in use");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[unstable(feature = "file_lock", issue = "0")]
+    pub fn try_lock_exclusive(&self) -> io::Result<bool> {
+        self.inner.try_lock_exclusive()
+    }
+
+    /// Releases any lock held on the file.
+    ///
+    /// On Unix this also releases the lock for every `try_clone` handle
+    /// sharing it. Locks are released automatically when the file is closed,
+    /// so calling this is only needed to release one early.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This corresponds to `flock` with `LOCK_UN` on Unix and to `UnlockFile`
+    /// on Windows.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_lock)]
+    /// use std::fs::File;
+    ///
+    /// # fn foo() -> std::io::Result<()> {
+    /// let f = try!(File::create("foo.txt"));
+    /// try!(f.lock_exclusive());
+    /// // ...
+    /// try!(f.unlock());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[unstable(feature = "file_lock", issue = "0")]
+    pub fn unlock(&self) -> io::Result<()> {
+        self.inner.unlock()
+    }
 }
 
 impl AsInner<fs_imp::File> for File {
@@ -338,6 +541,9 @@ impl Read for File {
     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
         self.inner.read(buf)
     }
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut]) -> io::Result<usize> {
+        self.inner.read_vectored(bufs)
+    }
     fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
         self.inner.read_to_end(buf)
     }
@@ -347,6 +553,9 @@ impl Write for File {
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
         self.inner.write(buf)
     }
+    fn write_vectored(&mut self, bufs: &[IoSlice]) -> io::Result<usize> {
+        self.inner.write_vectored(bufs)
+    }
     fn flush(&mut self) -> io::Result<()> { self.inner.flush() }
 }
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -360,6 +569,9 @@ impl<'a> Read for &'a File {
     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
         self.inner.read(buf)
     }
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut]) -> io::Result<usize> {
+        self.inner.read_vectored(bufs)
+    }
     fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
         self.inner.read_to_end(buf)
     }
@@ -369,6 +581,9 @@ impl<'a> Write for &'a File {
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
         self.inner.write(buf)
     }
+    fn write_vectored(&mut self, bufs: &[IoSlice]) -> io::Result<usize> {
+        self.inner.write_vectored(bufs)
+    }
     fn flush(&mut self) -> io::Result<()> { self.inner.flush() }
 }
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -1459,13 +1674,279 @@ impl AsInnerMut<fs_imp::DirBuilder> for DirBuilder {
     }
 }
 
+/// Returns an iterator over the entries within a directory and, recursively,
+/// within all of its subdirectories.
+///
+/// This is the same as `WalkDirBuilder::new().walk(path)`; use the builder
+/// to limit the depth, follow symbolic links or sort the entries.
+///
+/// # Errors
+///
+/// This function will return an error if `path` can't be read as a
+/// directory, for the same reasons as `read_dir`. Errors further down the
+/// tree are returned by the iterator.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(fs_walk_dir)]
+/// use std::io;
+/// use std::fs;
+///
+/// # fn foo() -> io::Result<()> {
+/// for entry in try!(fs::walk_dir(".")) {
+///     let entry = try!(entry);
+///     println!("{}", entry.path().display());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[unstable(feature = "fs_walk_dir", issue = "0")]
+pub fn walk_dir<P: AsRef<Path>>(path: P) -> io::Result<WalkDir> {
+    WalkDirBuilder::new().walk(path)
+}
+
+impl WalkDirBuilder {
+    /// Creates a new set of options for walking a directory tree, with no
+    /// depth limit, no link following and no sorting.
+    #[unstable(feature = "fs_walk_dir", issue = "0")]
+    pub fn new() -> WalkDirBuilder {
+        WalkDirBuilder {
+            max_depth: usize::MAX,
+            follow_links: false,
+            sort: false,
+        }
+    }
+
+    /// Sets the maximum depth of entries to yield.
+    ///
+    /// The entries directly within the root are at depth 1, so a maximum
+    /// depth of 1 walks the same entries as `read_dir` and a maximum depth of
+    /// 0 walks nothing.
+    ///
+    /// This option defaults to no limit.
+    #[unstable(feature = "fs_walk_dir", issue = "0")]
+    pub fn max_depth(&mut self, depth: usize) -> &mut Self {
+        self.max_depth = depth;
+        self
+    }
+
+    /// Indicates whether symbolic links to directories should be walked into.
+    ///
+    /// Links are yielded either way, as `DirEntry`s describing the link
+    /// itself. When following them, a link back to a directory which is
+    /// already being walked is reported as an error rather than walked into
+    /// again.
+    ///
+    /// This option defaults to `false`.
+    #[unstable(feature = "fs_walk_dir", issue = "0")]
+    pub fn follow_links(&mut self, follow: bool) -> &mut Self {
+        self.follow_links = follow;
+        self
+    }
+
+    /// Indicates whether the entries of each directory should be yielded in
+    /// order of their file names.
+    ///
+    /// Sorting requires reading each directory in full before yielding any
+    /// of its entries. Errors reading a directory are yielded after its
+    /// entries.
+    ///
+    /// This option defaults to `false`.
+    #[unstable(feature = "fs_walk_dir", issue = "0")]
+    pub fn sort_by_file_name(&mut self, sort: bool) -> &mut Self {
+        self.sort = sort;
+        self
+    }
+
+    /// Starts walking the directory tree rooted at `path` with the options
+    /// configured in this builder.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(fs_walk_dir)]
+    /// use std::fs::WalkDirBuilder;
+    ///
+    /// let walk = WalkDirBuilder::new()
+    ///     .max_depth(2)
+    ///     .sort_by_file_name(true)
+    ///     .walk("/tmp").unwrap();
+    /// for entry in walk {
+    ///     println!("{}", entry.unwrap().path().display());
+    /// }
+    /// ```
+    #[unstable(feature = "fs_walk_dir", issue = "0")]
+    pub fn walk<P: AsRef<Path>>(&self, path: P) -> io::Result<WalkDir> {
+        let mut walk = WalkDir {
+            opts: self.clone(),
+            stack: Vec::new(),
+            pending: None,
+            depth: 0,
+        };
+        if self.max_depth > 0 {
+            walk.push(path.as_ref())?;
+        }
+        Ok(walk)
+    }
+}
+
+impl WalkDir {
+    /// Returns the depth of the entry most recently returned by `next`.
+    ///
+    /// Entries directly within the root are at depth 1. An error is reported
+    /// at the depth of the directory it concerns.
+    #[unstable(feature = "fs_walk_dir", issue = "0")]
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Skips the descendants of the entry most recently returned by `next`.
+    ///
+    /// If that entry is a directory which would otherwise be walked into, the
+    /// iterator moves on to its next sibling instead. Otherwise this does
+    /// nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(fs_walk_dir)]
+    /// use std::fs;
+    ///
+    /// let mut walk = fs::walk_dir(".").unwrap();
+    /// while let Some(entry) = walk.next() {
+    ///     let entry = entry.unwrap();
+    ///     if entry.path().ends_with(".git") {
+    ///         walk.skip_current_dir();
+    ///         continue
+    ///     }
+    ///     println!("{}", entry.path().display());
+    /// }
+    /// ```
+    #[unstable(feature = "fs_walk_dir", issue = "0")]
+    pub fn skip_current_dir(&mut self) {
+        self.pending = None;
+    }
+
+    fn push(&mut self, path: &Path) -> io::Result<()> {
+        let id = if self.opts.follow_links {
+            let id = file_id(path)?;
+            if self.stack.iter().any(|level| level.id.as_ref() == Some(&id)) {
+                return Err(io::Error::new(io::ErrorKind::Other,
+                                          format!("file system loop found: {} \
+                                                   points to an ancestor",
+                                                  path.display())));
+            }
+            Some(id)
+        } else {
+            None
+        };
+
+        let dir = read_dir(path)?;
+        let entries = if self.opts.sort {
+            let mut entries = dir.collect::<Vec<_>>();
+            entries.sort_by(|a, b| {
+                match (a, b) {
+                    (&Ok(ref a), &Ok(ref b)) => a.file_name().cmp(&b.file_name()),
+                    (&Ok(_), &Err(_)) => Ordering::Less,
+                    (&Err(_), &Ok(_)) => Ordering::Greater,
+                    (&Err(_), &Err(_)) => Ordering::Equal,
+                }
+            });
+            WalkEntries::Sorted(entries.into_iter())
+        } else {
+            WalkEntries::Unsorted(dir)
+        };
+        self.stack.push(WalkLevel { entries: entries, id: id });
+        Ok(())
+    }
+
+    fn is_dir(&self, entry: &DirEntry) -> io::Result<bool> {
+        let ty = entry.file_type()?;
+        if ty.is_symlink() && self.opts.follow_links {
+            // A dangling link is still yielded, it just isn't walked into.
+            Ok(metadata(entry.path()).map(|m| m.is_dir()).unwrap_or(false))
+        } else {
+            Ok(ty.is_dir())
+        }
+    }
+}
+
+#[unstable(feature = "fs_walk_dir", issue = "0")]
+impl Iterator for WalkDir {
+    type Item = io::Result<DirEntry>;
+
+    fn next(&mut self) -> Option<io::Result<DirEntry>> {
+        if let Some(path) = self.pending.take() {
+            self.depth = self.stack.len();
+            if let Err(e) = self.push(&path) {
+                return Some(Err(e))
+            }
+        }
+
+        loop {
+            let next = match self.stack.last_mut() {
+                Some(level) => match level.entries {
+                    WalkEntries::Unsorted(ref mut dir) => dir.next(),
+                    WalkEntries::Sorted(ref mut entries) => entries.next(),
+                },
+                None => return None,
+            };
+            let entry = match next {
+                Some(entry) => entry,
+                None => {
+                    self.stack.pop();
+                    continue
+                }
+            };
+
+            self.depth = self.stack.len();
+            let entry = match entry {
+                Ok(entry) => entry,
+                Err(e) => return Some(Err(e)),
+            };
+            if self.depth < self.opts.max_depth {
+                match self.is_dir(&entry) {
+                    Ok(true) => self.pending = Some(entry.path()),
+                    Ok(false) => {}
+                    Err(e) => return Some(Err(e)),
+                }
+            }
+            return Some(Ok(entry))
+        }
+    }
+}
+
+#[unstable(feature = "fs_walk_dir", issue = "0")]
+impl fmt::Debug for WalkDir {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("WalkDir")
+            .field("opts", &self.opts)
+            .field("depth", &self.depth)
+            .finish()
+    }
+}
+
+#[cfg(unix)]
+fn file_id(path: &Path) -> io::Result<FileId> {
+    use os::unix::fs::MetadataExt;
+
+    let meta = metadata(path)?;
+    Ok((meta.dev(), meta.ino()))
+}
+
+#[cfg(windows)]
+fn file_id(path: &Path) -> io::Result<FileId> {
+    canonicalize(path)
+}
+
 #[cfg(test)]
 mod tests {
     use prelude::v1::*;
     use io::prelude::*;
 
-    use fs::{self, File, OpenOptions};
-    use io::{ErrorKind, SeekFrom};
+    use fs::{self, File, OpenOptions, WalkDir, WalkDirBuilder};
+    use io::{self, ErrorKind, SeekFrom, IoSlice, IoSliceMut};
     use path::Path;
     use rand::{StdRng, Rng};
     use str;
@@ -1539,6 +2020,77 @@ mod tests {
         check!(fs::remove_file(filename));
     }
 
+    #[test]
+    fn file_test_io_vectored() {
+        let tmpdir = tmpdir();
+        let filename = &tmpdir.join("file_rt_io_file_test_vectored.txt");
+        {
+            let mut write_stream = check!(File::create(filename));
+            let bufs = [IoSlice::new(b"hello"), IoSlice::new(b" "), IoSlice::new(b"world")];
+            assert_eq!(check!(write_stream.write_vectored(&bufs)), 11);
+        }
+        {
+            let mut read_stream = check!(File::open(filename));
+            let mut first = [0; 6];
+            let mut second = [0; 16];
+            let n = {
+                let mut bufs = [IoSliceMut::new(&mut first), IoSliceMut::new(&mut second)];
+                check!(read_stream.read_vectored(&mut bufs))
+            };
+            // Platforms without native support may only fill the first buffer.
+            assert!(n == 6 || n == 11);
+            assert_eq!(&first, b"hello ");
+            if n == 11 {
+                assert_eq!(&second[..5], b"world");
+            }
+        }
+        check!(fs::remove_file(filename));
+    }
+
+    #[test]
+    // These targets have no `flock`, so locking is unsupported
+    #[cfg_attr(any(target_os = "solaris", target_os = "emscripten",
+                   target_env = "newlib"), ignore)]
+    fn file_lock_multiple_handles() {
+        let tmpdir = tmpdir();
+        let filename = &tmpdir.join("file_lock_multiple_handles.txt");
+        let f1 = check!(File::create(filename));
+        let f2 = check!(File::open(filename));
+
+        check!(f1.lock_exclusive());
+        assert!(!check!(f2.try_lock_shared()));
+        assert!(!check!(f2.try_lock_exclusive()));
+        check!(f1.unlock());
+
+        check!(f1.lock_shared());
+        assert!(check!(f2.try_lock_shared()));
+        check!(f2.unlock());
+        assert!(!check!(f2.try_lock_exclusive()));
+        check!(f1.unlock());
+
+        assert!(check!(f2.try_lock_exclusive()));
+        drop(f2);
+        assert!(check!(f1.try_lock_exclusive()));
+    }
+
+    #[test]
+    #[cfg_attr(any(target_os = "solaris", target_os = "emscripten",
+                   target_env = "newlib"), ignore)]
+    #[cfg(unix)]
+    fn file_lock_shared_with_clone() {
+        let tmpdir = tmpdir();
+        let filename = &tmpdir.join("file_lock_shared_with_clone.txt");
+        let f1 = check!(File::create(filename));
+        let clone = check!(f1.try_clone());
+        let f2 = check!(File::open(filename));
+
+        check!(f1.lock_exclusive());
+        assert!(!check!(f2.try_lock_exclusive()));
+        // The clone refers to the same open file, and so to the same lock.
+        check!(clone.unlock());
+        assert!(check!(f2.try_lock_exclusive()));
+    }
+
     #[test]
     fn invalid_path_raises() {
         let tmpdir = tmpdir();
@@ -1815,6 +2367,102 @@ mod tests {
         check!(fs::create_dir_all(&Path::new("/")));
     }
 
+    // Collects the paths yielded by a walk, relative to its root, along with
+    // the depth each was reported at.
+    fn walk_paths(root: &Path, walk: WalkDir) -> Vec<(String, usize)> {
+        let mut walk = walk;
+        let mut paths = Vec::new();
+        while let Some(entry) = walk.next() {
+            let path = check!(entry).path();
+            let rel = path.strip_prefix(root).unwrap().to_str().unwrap().replace("\\", "/");
+            paths.push((rel, walk.depth()));
+        }
+        paths
+    }
+
+    #[test]
+    fn walk_dir_recursive() {
+        let tmpdir = tmpdir();
+        let root = tmpdir.join("walk");
+        check!(fs::create_dir_all(root.join("a/b")));
+        check!(fs::create_dir_all(root.join("c")));
+        check!(File::create(root.join("a/b/f1")));
+        check!(File::create(root.join("a/f2")));
+        check!(File::create(root.join("f3")));
+
+        let walk = check!(WalkDirBuilder::new().sort_by_file_name(true).walk(&root));
+        let paths = walk_paths(&root, walk);
+        let expected = [("a", 1), ("a/b", 2), ("a/b/f1", 3), ("a/f2", 2), ("c", 1), ("f3", 1)];
+        assert_eq!(paths.len(), expected.len());
+        for (&(ref path, depth), &(epath, edepth)) in paths.iter().zip(expected.iter()) {
+            assert_eq!((&path[..], depth), (epath, edepth));
+        }
+
+        // Unsorted, the same entries come out in some order.
+        let mut unsorted = walk_paths(&root, check!(fs::walk_dir(&root)));
+        unsorted.sort();
+        let mut sorted = paths.clone();
+        sorted.sort();
+        assert_eq!(unsorted, sorted);
+
+        let walk = check!(WalkDirBuilder::new().max_depth(1).walk(&root));
+        assert_eq!(walk_paths(&root, walk).len(), 3);
+        let walk = check!(WalkDirBuilder::new().max_depth(0).walk(&root));
+        assert_eq!(walk_paths(&root, walk).len(), 0);
+
+        assert!(fs::walk_dir(root.join("f3")).is_err());
+    }
+
+    #[test]
+    fn walk_dir_skip_current_dir() {
+        let tmpdir = tmpdir();
+        let root = tmpdir.join("walk");
+        check!(fs::create_dir_all(root.join("a/b")));
+        check!(fs::create_dir_all(root.join("c/d")));
+
+        let mut walk = check!(WalkDirBuilder::new().sort_by_file_name(true).walk(&root));
+        let mut names = Vec::new();
+        while let Some(entry) = walk.next() {
+            let entry = check!(entry);
+            if entry.path().ends_with("a") {
+                walk.skip_current_dir();
+            }
+            names.push(entry.file_name().into_string().unwrap());
+        }
+        assert_eq!(names, ["a", "c", "d"]);
+    }
+
+    #[test]
+    fn walk_dir_symlinks() {
+        let tmpdir = tmpdir();
+        if !got_symlink_permission(&tmpdir) { return };
+
+        let root = tmpdir.join("walk");
+        check!(fs::create_dir_all(root.join("a")));
+        check!(File::create(root.join("a/f")));
+        check!(symlink_dir(&root, root.join("a/loop")));
+
+        // Not followed by default, the link is just another entry.
+        let walk = check!(WalkDirBuilder::new().sort_by_file_name(true).walk(&root));
+        let paths = walk_paths(&root, walk);
+        assert_eq!(paths, [("a".to_string(), 1), ("a/f".to_string(), 2),
+                           ("a/loop".to_string(), 2)]);
+
+        // Followed, the link leads back to the root and is reported.
+        let mut walk = check!(WalkDirBuilder::new().follow_links(true)
+                                                   .sort_by_file_name(true)
+                                                   .walk(&root));
+        let mut errors = 0;
+        let mut entries = 0;
+        while let Some(entry) = walk.next() {
+            match entry {
+                Ok(_) => entries += 1,
+                Err(_) => errors += 1,
+            }
+        }
+        assert_eq!((entries, errors), (3, 1));
+    }
+
     #[test]
     fn recursive_rmdir() {
         let tmpdir = tmpdir();
@@ -1942,6 +2590,76 @@ mod tests {
                    check!(out.metadata()).permissions());
     }
 
+    #[test]
+    fn copy_file_large() {
+        let tmpdir = tmpdir();
+        let input = tmpdir.join("in.bin");
+        let out = tmpdir.join("out.bin");
+
+        let mut bytes = vec![0; 3 * 1024 * 1024 + 17];
+        StdRng::new().unwrap().fill_bytes(&mut bytes);
+        check!(check!(File::create(&input)).write_all(&bytes));
+        assert_eq!(check!(fs::copy(&input, &out)), bytes.len() as u64);
+        let mut v = Vec::new();
+        check!(check!(File::open(&out)).read_to_end(&mut v));
+        assert!(v == bytes);
+    }
+
+    #[test]
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    fn copy_file_from_procfs() {
+        // procfs files report a size of zero, and the kernel copy paths may
+        // see them as empty, but they still have to be copied in full.
+        let tmpdir = tmpdir();
+        let out = tmpdir.join("cmdline");
+        let mut expected = Vec::new();
+        check!(check!(File::open("/proc/self/cmdline")).read_to_end(&mut expected));
+        assert!(!expected.is_empty());
+
+        assert_eq!(check!(fs::copy("/proc/self/cmdline", &out)), expected.len() as u64);
+        let mut v = Vec::new();
+        check!(check!(File::open(&out)).read_to_end(&mut v));
+        assert!(v == expected);
+
+        let mut reader = check!(File::open("/proc/self/cmdline"));
+        let mut writer = check!(File::create(&out));
+        assert_eq!(check!(io::copy(&mut reader, &mut writer)), expected.len() as u64);
+        let mut v = Vec::new();
+        check!(check!(File::open(&out)).read_to_end(&mut v));
+        assert!(v == expected);
+    }
+
+    #[test]
+    fn io_copy_between_files() {
+        let tmpdir = tmpdir();
+        let input = tmpdir.join("in.txt");
+        let out = tmpdir.join("out.txt");
+        check!(check!(File::create(&input)).write_all(b"hello world"));
+
+        // The copy starts at the reader's position and leaves it at the end.
+        let mut reader = check!(File::open(&input));
+        check!(reader.seek(SeekFrom::Start(6)));
+        let mut writer = check!(File::create(&out));
+        check!(writer.write_all(b"hello "));
+        assert_eq!(check!(io::copy(&mut reader, &mut writer)), 5);
+        assert_eq!(check!(reader.read(&mut [0; 4])), 0);
+        check!(writer.write_all(b"!"));
+        drop(writer);
+
+        let mut v = Vec::new();
+        check!(check!(File::open(&out)).read_to_end(&mut v));
+        assert_eq!(v, b"hello world!");
+
+        // Appending works too, even where the kernel refuses to copy into an
+        // append-only descriptor.
+        let mut reader = check!(File::open(&input));
+        let mut writer = check!(OpenOptions::new().append(true).open(&out));
+        assert_eq!(check!(io::copy(&mut reader, &mut writer)), 11);
+        let mut v = Vec::new();
+        check!(check!(File::open(&out)).read_to_end(&mut v));
+        assert_eq!(v, b"hello world!hello world");
+    }
+
     #[test]
     fn copy_file_dst_dir() {
         let tmpdir = tmpdir();
@@ -17,7 +17,7 @@ use marker::Reflect;
 use cmp;
 use error;
 use fmt;
-use io::{self, DEFAULT_BUF_SIZE, Error, ErrorKind, SeekFrom};
+use io::{self, DEFAULT_BUF_SIZE, Error, ErrorKind, SeekFrom, IoSlice};
 use memchr;
 
 /// The `BufReader` struct adds buffering to any reader.
@@ -473,6 +473,22 @@ impl<W: Write> Write for BufWriter<W> {
             Write::write(&mut self.buf, &buf[..amt])
         }
     }
+
+    fn write_vectored(&mut self, bufs: &[IoSlice]) -> io::Result<usize> {
+        let total_len = bufs.iter().map(|b| b.len()).sum::<usize>();
+        if self.buf.len() + total_len > self.buf.capacity() {
+            self.flush_buf()?;
+        }
+        if total_len >= self.buf.capacity() {
+            self.panicked = true;
+            let r = self.inner.as_mut().unwrap().write_vectored(bufs);
+            self.panicked = false;
+            r
+        } else {
+            Write::write_vectored(&mut self.buf, bufs)
+        }
+    }
+
     fn flush(&mut self) -> io::Result<()> {
         self.flush_buf().and_then(|()| self.get_mut().flush())
     }
@@ -790,7 +806,7 @@ impl<W: Write> fmt::Debug for LineWriter<W> where W: fmt::Debug {
 mod tests {
     use prelude::v1::*;
     use io::prelude::*;
-    use io::{self, BufReader, BufWriter, LineWriter, SeekFrom};
+    use io::{self, BufReader, BufWriter, LineWriter, SeekFrom, IoSlice};
     use sync::atomic::{AtomicUsize, Ordering};
     use thread;
     use test;
@@ -942,6 +958,26 @@ mod tests {
         assert_eq!(*writer.get_ref(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11]);
     }
 
+    #[test]
+    fn test_buffered_writer_vectored() {
+        let mut writer = BufWriter::with_capacity(4, Vec::new());
+
+        let bufs = [IoSlice::new(&[0]), IoSlice::new(&[1, 2])];
+        assert_eq!(writer.write_vectored(&bufs).unwrap(), 3);
+        assert_eq!(*writer.get_ref(), []);
+
+        // Doesn't fit alongside what's buffered, and is at least as large as
+        // the buffer, so it flushes and goes straight through.
+        let bufs = [IoSlice::new(&[3, 4]), IoSlice::new(&[5, 6])];
+        assert_eq!(writer.write_vectored(&bufs).unwrap(), 4);
+        assert_eq!(*writer.get_ref(), [0, 1, 2, 3, 4, 5, 6]);
+
+        let bufs = [IoSlice::new(&[]), IoSlice::new(&[7])];
+        assert_eq!(writer.write_vectored(&bufs).unwrap(), 1);
+        writer.flush().unwrap();
+        assert_eq!(*writer.get_ref(), [0, 1, 2, 3, 4, 5, 6, 7]);
+    }
+
     #[test]
     fn test_buffered_writer_inner_flushes() {
         let mut w = BufWriter::with_capacity(3, Vec::new());
@@ -12,7 +12,7 @@ use prelude::v1::*;
 use io::prelude::*;
 
 use cmp;
-use io::{self, SeekFrom, Error, ErrorKind};
+use io::{self, SeekFrom, Error, ErrorKind, IoSlice, IoSliceMut};
 
 /// A `Cursor` wraps another type and provides it with a
 /// [`Seek`](trait.Seek.html) implementation.
@@ -217,6 +217,18 @@ impl<T> Read for Cursor<T> where T: AsRef<[u8]> {
         self.pos += n as u64;
         Ok(n)
     }
+
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut]) -> io::Result<usize> {
+        let mut nread = 0;
+        for buf in bufs {
+            let n = self.read(buf)?;
+            nread += n;
+            if n < buf.len() {
+                break;
+            }
+        }
+        Ok(nread)
+    }
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -237,6 +249,20 @@ impl<'a> Write for Cursor<&'a mut [u8]> {
         self.pos += amt as u64;
         Ok(amt)
     }
+
+    #[inline]
+    fn write_vectored(&mut self, bufs: &[IoSlice]) -> io::Result<usize> {
+        let mut nwritten = 0;
+        for buf in bufs {
+            let n = self.write(buf)?;
+            nwritten += n;
+            if n < buf.len() {
+                break;
+            }
+        }
+        Ok(nwritten)
+    }
+
     fn flush(&mut self) -> io::Result<()> { Ok(()) }
 }
 
@@ -265,6 +291,15 @@ impl Write for Cursor<Vec<u8>> {
         self.set_position(pos + buf.len() as u64);
         Ok(buf.len())
     }
+
+    fn write_vectored(&mut self, bufs: &[IoSlice]) -> io::Result<usize> {
+        let mut nwritten = 0;
+        for buf in bufs {
+            nwritten += self.write(buf)?;
+        }
+        Ok(nwritten)
+    }
+
     fn flush(&mut self) -> io::Result<()> { Ok(()) }
 }
 
@@ -277,13 +312,27 @@ impl Write for Cursor<Box<[u8]>> {
         self.pos += amt as u64;
         Ok(amt)
     }
+
+    #[inline]
+    fn write_vectored(&mut self, bufs: &[IoSlice]) -> io::Result<usize> {
+        let mut nwritten = 0;
+        for buf in bufs {
+            let n = self.write(buf)?;
+            nwritten += n;
+            if n < buf.len() {
+                break;
+            }
+        }
+        Ok(nwritten)
+    }
+
     fn flush(&mut self) -> io::Result<()> { Ok(()) }
 }
 
 #[cfg(test)]
 mod tests {
     use io::prelude::*;
-    use io::{Cursor, SeekFrom};
+    use io::{Cursor, SeekFrom, IoSlice, IoSliceMut};
     use vec::Vec;
 
     #[test]
@@ -296,6 +345,46 @@ mod tests {
         assert_eq!(writer, b);
     }
 
+    #[test]
+    fn test_vec_writer_vectored() {
+        let mut writer = Cursor::new(Vec::new());
+        assert_eq!(writer.write_vectored(&[IoSlice::new(&[0])]).unwrap(), 1);
+        assert_eq!(writer.write_vectored(&[IoSlice::new(&[1, 2, 3]),
+                                           IoSlice::new(&[4, 5, 6, 7])]).unwrap(), 7);
+        let b: &[_] = &[0, 1, 2, 3, 4, 5, 6, 7];
+        assert_eq!(&writer.get_ref()[..], b);
+        assert_eq!(writer.position(), 8);
+    }
+
+    #[test]
+    fn test_slice_writer_vectored() {
+        let mut buf = [0; 5];
+        let mut writer = Cursor::new(&mut buf[..]);
+        assert_eq!(writer.write_vectored(&[IoSlice::new(&[1, 2]),
+                                           IoSlice::new(&[3, 4, 5, 6])]).unwrap(), 5);
+        assert_eq!(writer.write_vectored(&[IoSlice::new(&[7])]).unwrap(), 0);
+        assert_eq!(writer.position(), 5);
+        let b: &[_] = &[1, 2, 3, 4, 5];
+        assert_eq!(&**writer.get_ref(), b);
+    }
+
+    #[test]
+    fn test_mem_reader_vectored() {
+        let mut reader = Cursor::new(vec!(0, 1, 2, 3, 4, 5, 6, 7));
+        let mut buf1 = [0; 1];
+        let mut buf2 = [0; 4];
+        assert_eq!(reader.read_vectored(&mut [IoSliceMut::new(&mut buf1),
+                                              IoSliceMut::new(&mut buf2)]).unwrap(), 5);
+        assert_eq!(reader.position(), 5);
+        assert_eq!(buf1, [0]);
+        assert_eq!(buf2, [1, 2, 3, 4]);
+        assert_eq!(reader.read_vectored(&mut [IoSliceMut::new(&mut buf1),
+                                              IoSliceMut::new(&mut buf2)]).unwrap(), 3);
+        assert_eq!(buf1, [5]);
+        assert_eq!(&buf2[..2], [6, 7]);
+        assert_eq!(reader.read_vectored(&mut [IoSliceMut::new(&mut buf2)]).unwrap(), 0);
+    }
+
     #[test]
     fn test_mem_writer() {
         let mut writer = Cursor::new(Vec::new());
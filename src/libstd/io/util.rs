@@ -27,6 +27,15 @@ use io::{self, Read, Write, ErrorKind, BufRead};
 /// `write` returns an error. All instances of `ErrorKind::Interrupted` are
 /// handled by this function and the underlying operation is retried.
 ///
+/// # Platform-specific behavior
+///
+/// On Linux, when both `reader` and `writer` are files, sockets or child
+/// process pipes, the data may be copied by the kernel using
+/// `copy_file_range`, `sendfile` or `splice` rather than through a buffer.
+/// Note that this [may change in the future][changes].
+///
+/// [changes]: index.html#platform-specific-behavior
+///
 /// # Examples
 ///
 /// ```
@@ -46,8 +55,16 @@ use io::{self, Read, Write, ErrorKind, BufRead};
 pub fn copy<R: ?Sized, W: ?Sized>(reader: &mut R, writer: &mut W) -> io::Result<u64>
     where R: Read, W: Write
 {
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    let written = match ::sys::kernel_copy::copy(reader, writer) {
+        ::sys::kernel_copy::CopyResult::Ended(result) => return result,
+        ::sys::kernel_copy::CopyResult::Fallback(written) => written,
+    };
+    #[cfg(not(any(target_os = "linux", target_os = "android")))]
+    let written = 0;
+
     let mut buf = [0; super::DEFAULT_BUF_SIZE];
-    let mut written = 0;
+    let mut written = written;
     loop {
         let len = match reader.read(&mut buf) {
             Ok(0) => return Ok(written),
@@ -259,6 +259,7 @@
 #![feature(slice_bytes)]
 #![feature(slice_concat_ext)]
 #![feature(slice_patterns)]
+#![feature(specialization)]
 #![feature(staged_api)]
 #![feature(stmt_expr_attributes)]
 #![feature(str_char)]
@@ -274,6 +275,7 @@
 #![feature(vec_push_all)]
 #![feature(zero_one)]
 #![feature(question_mark)]
+#![cfg_attr(not(stage0), feature(i128))]
 #![feature(try_from)]
 #![feature(needs_panic_runtime)]
 
@@ -401,6 +403,9 @@ pub use core::i16;
 pub use core::i32;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::i64;
+#[cfg(not(stage0))]
+#[unstable(feature = "i128", issue = "35118")]
+pub use core::i128;
 
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::usize;
@@ -412,6 +417,9 @@ pub use core::u16;
 pub use core::u32;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::u64;
+#[cfg(not(stage0))]
+#[unstable(feature = "i128", issue = "35118")]
+pub use core::u128;
 
 #[path = "num/f32.rs"]   pub mod f32;
 #[path = "num/f64.rs"]   pub mod f64;
@@ -427,6 +435,7 @@ pub mod num;
 #[macro_use]
 pub mod thread;
 
+pub mod backtrace;
 pub mod collections;
 pub mod env;
 pub mod ffi;
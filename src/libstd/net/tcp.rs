@@ -12,7 +12,7 @@ use prelude::v1::*;
 use io::prelude::*;
 
 use fmt;
-use io;
+use io::{self, IoSlice, IoSliceMut};
 use net::{ToSocketAddrs, SocketAddr, Shutdown};
 use sys_common::net as net_imp;
 use sys_common::{AsInner, FromInner, IntoInner};
@@ -93,6 +93,34 @@ impl TcpStream {
         super::each_addr(addr, net_imp::TcpStream::connect).map(TcpStream)
     }
 
+    /// Opens a TCP connection to a remote host with a timeout.
+    ///
+    /// Unlike `connect`, `connect_timeout` takes a single `SocketAddr` since
+    /// timeout must be applied to individual addresses.
+    ///
+    /// If the connection is not established within `timeout`, an error of
+    /// kind `TimedOut` is returned. It is an error to pass a zero `Duration`
+    /// to this function.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(tcpstream_connect_timeout)]
+    ///
+    /// use std::net::{SocketAddr, TcpStream};
+    /// use std::time::Duration;
+    ///
+    /// let addr: SocketAddr = "10.0.0.1:8080".parse().unwrap();
+    /// match TcpStream::connect_timeout(&addr, Duration::from_secs(2)) {
+    ///     Ok(_) => println!("connected"),
+    ///     Err(e) => println!("couldn't connect: {}", e),
+    /// }
+    /// ```
+    #[unstable(feature = "tcpstream_connect_timeout", issue = "0")]
+    pub fn connect_timeout(addr: &SocketAddr, timeout: Duration) -> io::Result<TcpStream> {
+        net_imp::TcpStream::connect_timeout(addr, timeout).map(TcpStream)
+    }
+
     /// Returns the socket address of the remote peer of this TCP connection.
     #[stable(feature = "rust1", since = "1.0.0")]
     pub fn peer_addr(&self) -> io::Result<SocketAddr> {
@@ -182,6 +210,29 @@ impl TcpStream {
         self.0.write_timeout()
     }
 
+    /// Receives data on the socket from the remote address to which it is
+    /// connected, without removing that data from the queue. On success,
+    /// returns the number of bytes peeked.
+    ///
+    /// Successive calls return the same data. This is accomplished by passing
+    /// `MSG_PEEK` as a flag to the underlying `recv` system call.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(peek)]
+    ///
+    /// use std::net::TcpStream;
+    ///
+    /// let stream = TcpStream::connect("127.0.0.1:8000").unwrap();
+    /// let mut buf = [0; 10];
+    /// let len = stream.peek(&mut buf).expect("peek failed");
+    /// ```
+    #[unstable(feature = "peek", issue = "0")]
+    pub fn peek(&self, buf: &mut [u8]) -> io::Result<usize> {
+        self.0.peek(buf)
+    }
+
     /// Sets the value of the `TCP_NODELAY` option on this socket.
     ///
     /// If set, this option disables the Nagle algorithm. This means that
@@ -246,6 +297,9 @@ impl TcpStream {
 #[stable(feature = "rust1", since = "1.0.0")]
 impl Read for TcpStream {
     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> { self.0.read(buf) }
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut]) -> io::Result<usize> {
+        self.0.read_vectored(bufs)
+    }
     fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
         self.0.read_to_end(buf)
     }
@@ -253,11 +307,17 @@ impl Read for TcpStream {
 #[stable(feature = "rust1", since = "1.0.0")]
 impl Write for TcpStream {
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> { self.0.write(buf) }
+    fn write_vectored(&mut self, bufs: &[IoSlice]) -> io::Result<usize> {
+        self.0.write_vectored(bufs)
+    }
     fn flush(&mut self) -> io::Result<()> { Ok(()) }
 }
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<'a> Read for &'a TcpStream {
     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> { self.0.read(buf) }
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut]) -> io::Result<usize> {
+        self.0.read_vectored(bufs)
+    }
     fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
         self.0.read_to_end(buf)
     }
@@ -265,6 +325,9 @@ impl<'a> Read for &'a TcpStream {
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<'a> Write for &'a TcpStream {
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> { self.0.write(buf) }
+    fn write_vectored(&mut self, bufs: &[IoSlice]) -> io::Result<usize> {
+        self.0.write_vectored(bufs)
+    }
     fn flush(&mut self) -> io::Result<()> { Ok(()) }
 }
 
@@ -435,7 +498,7 @@ impl fmt::Debug for TcpListener {
 mod tests {
     use prelude::v1::*;
 
-    use io::ErrorKind;
+    use io::{ErrorKind, IoSlice, IoSliceMut};
     use io::prelude::*;
     use net::*;
     use net::test::{next_test_ip4, next_test_ip6};
@@ -1142,4 +1205,91 @@ mod tests {
             Err(e) => panic!("unexpected error {}", e),
         }
     }
+
+    #[test]
+    fn connect_timeout_loopback() {
+        each_ip(&mut |addr| {
+            let listener = t!(TcpListener::bind(&addr));
+            let _t = thread::spawn(move || {
+                t!(listener.accept());
+            });
+
+            let stream = t!(TcpStream::connect_timeout(&addr, Duration::from_secs(60)));
+            assert_eq!(t!(stream.peer_addr()), addr);
+
+            // Nothing listens here any more.
+            _t.join().unwrap();
+            assert!(TcpStream::connect_timeout(&addr, Duration::from_secs(60)).is_err());
+        })
+    }
+
+    #[test]
+    fn connect_timeout_zero() {
+        let addr = next_test_ip4();
+        let _listener = t!(TcpListener::bind(&addr));
+        // A loopback connection may complete right away, before the timeout
+        // is even looked at.
+        match TcpStream::connect_timeout(&addr, Duration::new(0, 0)) {
+            Ok(_) => {}
+            Err(e) => assert_eq!(e.kind(), ErrorKind::InvalidInput),
+        }
+    }
+
+    #[test]
+    fn read_write_vectored() {
+        each_ip(&mut |addr| {
+            let acceptor = t!(TcpListener::bind(&addr));
+            let _t = thread::spawn(move|| {
+                let mut stream = t!(TcpStream::connect(&addr));
+                let bufs = [IoSlice::new(&[1, 2]), IoSlice::new(&[]), IoSlice::new(&[3])];
+                assert_eq!(t!(stream.write_vectored(&bufs)), 3);
+            });
+
+            let mut stream = t!(acceptor.accept()).0;
+            let mut first = [0; 1];
+            let mut second = [0; 2];
+            let mut nread = 0;
+            while nread < 3 {
+                let mut bufs = [IoSliceMut::new(&mut first[..]), IoSliceMut::new(&mut second)];
+                let n = t!(stream.read_vectored(&mut bufs));
+                assert!(n > 0);
+                nread += n;
+            }
+            assert_eq!(first, [1]);
+            assert_eq!(second, [2, 3]);
+        })
+    }
+
+    #[test]
+    fn peek() {
+        each_ip(&mut |addr| {
+            let (txdone, rxdone) = channel();
+
+            let srv = t!(TcpListener::bind(&addr));
+            let _t = thread::spawn(move|| {
+                let mut cl = t!(srv.accept()).0;
+                cl.write(&[1, 3, 3, 7]).unwrap();
+                t!(rxdone.recv());
+            });
+
+            let mut c = t!(TcpStream::connect(&addr));
+            let mut b = [0; 10];
+            for _ in 1..3 {
+                let len = c.peek(&mut b).unwrap();
+                assert_eq!(len, 4);
+                assert_eq!(&b[..4], [1, 3, 3, 7]);
+            }
+            let len = c.read(&mut b).unwrap();
+            assert_eq!(len, 4);
+            assert_eq!(&b[..4], [1, 3, 3, 7]);
+
+            t!(c.set_nonblocking(true));
+            match c.peek(&mut b) {
+                Ok(_) => panic!("expected error"),
+                Err(ref e) if e.kind() == ErrorKind::WouldBlock => {}
+                Err(e) => panic!("unexpected error {}", e),
+            }
+            t!(txdone.send(()));
+        })
+    }
 }
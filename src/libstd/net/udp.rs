@@ -62,6 +62,17 @@ impl UdpSocket {
         self.0.recv_from(buf)
     }
 
+    /// Receives data from the socket without removing it from the queue. On
+    /// success, returns the number of bytes read and the address from whence
+    /// the data came.
+    ///
+    /// Successive calls return the same data. This is accomplished by passing
+    /// `MSG_PEEK` as a flag to the underlying `recvfrom` system call.
+    #[unstable(feature = "peek", issue = "0")]
+    pub fn peek_from(&self, buf: &mut [u8]) -> io::Result<(usize, SocketAddr)> {
+        self.0.peek_from(buf)
+    }
+
     /// Sends data on the socket to the given address. On success, returns the
     /// number of bytes written.
     ///
@@ -324,6 +335,20 @@ impl UdpSocket {
         self.0.recv(buf)
     }
 
+    /// Receives data on the socket from the remote address to which it is
+    /// connected, without removing that data from the queue. On success,
+    /// returns the number of bytes peeked.
+    ///
+    /// Successive calls return the same data. This is accomplished by passing
+    /// `MSG_PEEK` as a flag to the underlying `recv` system call.
+    ///
+    /// The `connect` method will connect this socket to a remote address. This
+    /// method will fail if the socket is not connected.
+    #[unstable(feature = "peek", issue = "0")]
+    pub fn peek(&self, buf: &mut [u8]) -> io::Result<usize> {
+        self.0.peek(buf)
+    }
+
     /// Moves this UDP socket into or out of nonblocking mode.
     ///
     /// On Unix this corresponds to calling fcntl, and on Windows this
@@ -628,4 +653,41 @@ mod tests {
         t!(stream.set_nonblocking(true));
         t!(stream.set_nonblocking(false));
     }
+
+    #[test]
+    fn peek() {
+        each_ip(&mut |addr1, addr2| {
+            let sock1 = t!(UdpSocket::bind(&addr1));
+            let sock2 = t!(UdpSocket::bind(&addr2));
+            t!(sock1.connect(addr2));
+            t!(sock2.connect(addr1));
+
+            t!(sock2.send(b"hello world"));
+
+            let mut buf = [0; 11];
+            for _ in 1..3 {
+                assert_eq!(t!(sock1.peek(&mut buf)), 11);
+                assert_eq!(b"hello world", &buf[..]);
+            }
+            assert_eq!(t!(sock1.recv(&mut buf)), 11);
+            assert_eq!(b"hello world", &buf[..]);
+        })
+    }
+
+    #[test]
+    fn peek_from() {
+        each_ip(&mut |addr1, addr2| {
+            let sock1 = t!(UdpSocket::bind(&addr1));
+            let sock2 = t!(UdpSocket::bind(&addr2));
+
+            t!(sock2.send_to(b"hello", &addr1));
+
+            let mut buf = [0; 5];
+            for _ in 1..3 {
+                assert_eq!(t!(sock1.peek_from(&mut buf)), (5, addr2));
+                assert_eq!(b"hello", &buf[..]);
+            }
+            assert_eq!(t!(sock1.recv_from(&mut buf)), (5, addr2));
+        })
+    }
 }
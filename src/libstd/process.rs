@@ -17,12 +17,14 @@ use io::prelude::*;
 
 use ffi::OsStr;
 use fmt;
-use io;
+use fs;
+use io::{self, IoSlice};
 use path::Path;
 use str;
 use sys::pipe::{read2, AnonPipe};
 use sys::process as imp;
 use sys_common::{AsInner, AsInnerMut, FromInner, IntoInner};
+use time::Duration;
 
 /// Representation of a running or exited child process.
 ///
@@ -82,6 +84,10 @@ impl AsInner<imp::Process> for Child {
     fn as_inner(&self) -> &imp::Process { &self.handle }
 }
 
+impl AsInnerMut<imp::Process> for Child {
+    fn as_inner_mut(&mut self) -> &mut imp::Process { &mut self.handle }
+}
+
 impl FromInner<(imp::Process, imp::StdioPipes)> for Child {
     fn from_inner((handle, io): (imp::Process, imp::StdioPipes)) -> Child {
         Child {
@@ -113,6 +119,10 @@ impl Write for ChildStdin {
         self.inner.write(buf)
     }
 
+    fn write_vectored(&mut self, bufs: &[IoSlice]) -> io::Result<usize> {
+        self.inner.write_vectored(bufs)
+    }
+
     fn flush(&mut self) -> io::Result<()> {
         Ok(())
     }
@@ -597,6 +607,83 @@ impl FromInner<imp::Stdio> for Stdio {
     }
 }
 
+/// Connects the child's stdio to the write end of another child's stdin
+/// pipe.
+///
+/// The pipe is owned by the `Command` it is passed to, and the parent's copy
+/// is closed once that `Command` is dropped.
+#[stable(feature = "stdio_from", since = "1.12.0")]
+impl From<ChildStdin> for Stdio {
+    fn from(child: ChildStdin) -> Stdio {
+        Stdio::from_inner(child.into_inner().into())
+    }
+}
+
+/// Connects the child's stdio to the read end of another child's stdout
+/// pipe, which is how pipelines like `a | b` are built.
+///
+/// The pipe is owned by the `Command` it is passed to, and the parent's copy
+/// is closed once that `Command` is dropped.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::process::{Command, Stdio};
+///
+/// let ls = Command::new("ls")
+///                  .stdout(Stdio::piped())
+///                  .spawn()
+///                  .expect("failed to execute ls");
+///
+/// let output = Command::new("wc")
+///                      .arg("-l")
+///                      .stdin(Stdio::from(ls.stdout.unwrap()))
+///                      .output()
+///                      .expect("failed to execute wc");
+///
+/// println!("{} files", String::from_utf8_lossy(&output.stdout).trim());
+/// ```
+#[stable(feature = "stdio_from", since = "1.12.0")]
+impl From<ChildStdout> for Stdio {
+    fn from(child: ChildStdout) -> Stdio {
+        Stdio::from_inner(child.into_inner().into())
+    }
+}
+
+/// Connects the child's stdio to the read end of another child's stderr
+/// pipe.
+///
+/// The pipe is owned by the `Command` it is passed to, and the parent's copy
+/// is closed once that `Command` is dropped.
+#[stable(feature = "stdio_from", since = "1.12.0")]
+impl From<ChildStderr> for Stdio {
+    fn from(child: ChildStderr) -> Stdio {
+        Stdio::from_inner(child.into_inner().into())
+    }
+}
+
+/// Connects the child's stdio to an open file.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::fs::File;
+/// use std::process::{Command, Stdio};
+///
+/// let log = File::create("build.log").expect("failed to create log");
+///
+/// Command::new("make")
+///         .stdout(Stdio::from(log))
+///         .status()
+///         .expect("failed to execute make");
+/// ```
+#[stable(feature = "stdio_from", since = "1.12.0")]
+impl From<fs::File> for Stdio {
+    fn from(file: fs::File) -> Stdio {
+        Stdio::from_inner(file.into_inner().into())
+    }
+}
+
 /// Describes the result of a process after it has terminated.
 #[derive(PartialEq, Eq, Clone, Copy, Debug)]
 #[stable(feature = "process", since = "1.0.0")]
@@ -712,6 +799,75 @@ impl Child {
         self.handle.wait().map(ExitStatus)
     }
 
+    /// Attempts to collect the exit status of the child if it has already
+    /// exited.
+    ///
+    /// This function will not block the calling thread and will only
+    /// check to see if the child process has exited or not. If the child has
+    /// exited then on Unix the process id is reaped. This function is
+    /// guaranteed to repeatedly return a successful exit status so long as the
+    /// child has already exited.
+    ///
+    /// If the child has exited, then `Ok(Some(status))` is returned. If the
+    /// exit status is not available at this time then `Ok(None)` is returned.
+    /// If an error occurs, then that error is returned.
+    ///
+    /// Note that unlike `wait`, this function will not attempt to drop stdin.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```no_run
+    /// #![feature(process_try_wait)]
+    ///
+    /// use std::process::Command;
+    ///
+    /// let mut child = Command::new("ls").spawn().unwrap();
+    ///
+    /// match child.try_wait() {
+    ///     Ok(Some(status)) => println!("exited with: {}", status),
+    ///     Ok(None) => {
+    ///         println!("status not ready yet, let's really wait");
+    ///         let res = child.wait();
+    ///         println!("result: {:?}", res);
+    ///     }
+    ///     Err(e) => println!("error attempting to wait: {}", e),
+    /// }
+    /// ```
+    #[unstable(feature = "process_try_wait", issue = "0")]
+    pub fn try_wait(&mut self) -> io::Result<Option<ExitStatus>> {
+        Ok(self.handle.try_wait()?.map(ExitStatus))
+    }
+
+    /// Waits up to `dur` for the child to exit, returning its exit status if
+    /// it did.
+    ///
+    /// This behaves like `try_wait`, except that the calling thread is blocked
+    /// until either the child exits or `dur` has elapsed, in which case
+    /// `Ok(None)` is returned. The stdin handle is not closed either, so a
+    /// child waiting for input will only exit once the timeout has run out.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(process_try_wait)]
+    ///
+    /// use std::process::Command;
+    /// use std::time::Duration;
+    ///
+    /// let mut child = Command::new("sleep").arg("5").spawn().unwrap();
+    ///
+    /// match child.wait_timeout(Duration::from_secs(1)).unwrap() {
+    ///     Some(status) => println!("exited with: {}", status),
+    ///     None => child.kill().unwrap(),
+    /// }
+    /// ```
+    #[unstable(feature = "process_try_wait", issue = "0")]
+    pub fn wait_timeout(&mut self, dur: Duration) -> io::Result<Option<ExitStatus>> {
+        Ok(self.handle.wait_timeout(dur)?.map(ExitStatus))
+    }
+
     /// Simultaneously waits for the child to exit and collect all remaining
     /// output on the stdout/stderr handles, returning an `Output`
     /// instance.
@@ -799,6 +955,7 @@ mod tests {
     use io::ErrorKind;
     use str;
     use super::{Command, Output, Stdio};
+    use time::Duration;
 
     // FIXME(#10380) these tests should not all be ignored on android.
 
@@ -848,6 +1005,92 @@ mod tests {
         }
     }
 
+    #[test]
+    #[cfg_attr(target_os = "android", ignore)]
+    fn try_wait_exited() {
+        let mut p = Command::new("true").spawn().unwrap();
+        let status = p.wait().unwrap();
+        assert_eq!(p.try_wait().unwrap(), Some(status));
+        assert_eq!(p.wait_timeout(Duration::from_millis(10)).unwrap(), Some(status));
+    }
+
+    #[test]
+    #[cfg_attr(any(windows, target_os = "android"), ignore)]
+    fn try_wait_running_then_killed() {
+        let mut p = Command::new("/bin/sh")
+                            .arg("-c").arg("read a")
+                            .stdin(Stdio::piped())
+                            .spawn().unwrap();
+        assert_eq!(p.try_wait().unwrap(), None);
+        assert_eq!(p.wait_timeout(Duration::from_millis(50)).unwrap(), None);
+
+        p.kill().unwrap();
+        let status = p.wait_timeout(Duration::from_secs(60)).unwrap().unwrap();
+        assert!(!status.success());
+        assert_eq!(p.try_wait().unwrap(), Some(status));
+        assert_eq!(p.wait().unwrap(), status);
+    }
+
+    #[test]
+    #[cfg_attr(any(windows, target_os = "android"), ignore)]
+    fn wait_timeout_exits_in_time() {
+        let mut p = Command::new("/bin/sh")
+                            .arg("-c").arg("sleep 0.1; exit 3")
+                            .spawn().unwrap();
+        let status = p.wait_timeout(Duration::from_secs(60)).unwrap().unwrap();
+        assert_eq!(status.code(), Some(3));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    #[cfg_attr(target_os = "android", ignore)]
+    fn process_group_and_kill_group() {
+        use libc;
+        use os::unix::process::{ChildExt, CommandExt, ExitStatusExt};
+
+        let mut p = Command::new("/bin/sh")
+                            .arg("-c").arg("sleep 1000 & wait")
+                            .process_group(0)
+                            .spawn().unwrap();
+        let pid = p.id() as libc::pid_t;
+        assert_eq!(unsafe { libc::getpgid(pid) }, pid);
+        p.kill_group().unwrap();
+        assert_eq!(p.wait().unwrap().signal(), Some(9));
+        assert_eq!(p.kill_group().unwrap_err().kind(), ErrorKind::InvalidInput);
+
+        // A child in our own process group is refused.
+        let mut p = Command::new("sleep").arg("1000").spawn().unwrap();
+        assert_eq!(p.kill_group().unwrap_err().kind(), ErrorKind::InvalidInput);
+        p.kill().unwrap();
+        p.wait().unwrap();
+    }
+
+    #[test]
+    #[cfg(unix)]
+    #[cfg_attr(target_os = "android", ignore)]
+    fn setsid_starts_new_session() {
+        use libc;
+        use os::unix::process::CommandExt;
+
+        let mut p = Command::new("sleep").arg("1000").setsid(true).spawn().unwrap();
+        let pid = p.id() as libc::pid_t;
+        assert_eq!(unsafe { libc::getsid(pid) }, pid);
+        assert_eq!(unsafe { libc::getpgid(pid) }, pid);
+        p.kill().unwrap();
+        p.wait().unwrap();
+    }
+
+    #[test]
+    #[cfg(unix)]
+    #[cfg_attr(target_os = "android", ignore)]
+    fn arg0_is_overridden() {
+        use os::unix::process::CommandExt;
+
+        let mut cmd = Command::new("/bin/sh");
+        cmd.arg0("custom-name").arg("-c").arg("echo $0").stdout(Stdio::piped());
+        assert_eq!(run_output(cmd), "custom-name\n");
+    }
+
     pub fn run_output(mut cmd: Command) -> String {
         let p = cmd.spawn();
         assert!(p.is_ok());
@@ -893,6 +1136,48 @@ mod tests {
         assert_eq!(out, "foobar\n");
     }
 
+    #[test]
+    #[cfg_attr(any(windows, target_os = "android"), ignore)]
+    fn stdio_from_child_stdout() {
+        let mut first = Command::new("/bin/sh")
+                                .arg("-c").arg("echo foo; echo bar")
+                                .stdout(Stdio::piped())
+                                .spawn().unwrap();
+        let mut second = Command::new("/bin/sh")
+                                 .arg("-c").arg("while read line; do echo \"< $line\"; done")
+                                 .stdin(Stdio::from(first.stdout.take().unwrap()))
+                                 .stdout(Stdio::piped())
+                                 .spawn().unwrap();
+        let mut out = String::new();
+        second.stdout.as_mut().unwrap().read_to_string(&mut out).unwrap();
+        assert!(first.wait().unwrap().success());
+        assert!(second.wait().unwrap().success());
+        assert_eq!(out, "< foo\n< bar\n");
+    }
+
+    #[test]
+    #[cfg_attr(any(windows, target_os = "android"), ignore)]
+    fn stdio_from_file() {
+        use fs::File;
+        use sys_common::io::test::tmpdir;
+
+        let dir = tmpdir();
+        let path = dir.path().join("out.txt");
+        let status = Command::new("echo").arg("foobar")
+                             .stdout(Stdio::from(File::create(&path).unwrap()))
+                             .status().unwrap();
+        assert!(status.success());
+
+        let mut out = String::new();
+        File::open(&path).unwrap().read_to_string(&mut out).unwrap();
+        assert_eq!(out, "foobar\n");
+
+        let output = Command::new("cat")
+                             .stdin(Stdio::from(File::open(&path).unwrap()))
+                             .output().unwrap();
+        assert_eq!(output.stdout, b"foobar\n");
+    }
+
 
     #[test]
     #[cfg_attr(target_os = "android", ignore)]
@@ -17,7 +17,25 @@ use libc;
 use str;
 use sync::atomic::{self, Ordering};
 
-pub use sys::backtrace::write;
+pub use sys::backtrace::{write, trace, resolve};
+
+/// A single frame of a captured stack trace.
+#[derive(Clone, Copy)]
+pub struct Frame {
+    /// Address of the instruction the frame is currently executing.
+    pub exact_position: *const libc::c_void,
+    /// Address of the start of the function the frame belongs to, if known,
+    /// and `exact_position` otherwise.
+    pub symbol_addr: *const libc::c_void,
+}
+
+/// Symbol information for a frame. A frame which has had calls inlined into
+/// it resolves to more than one of these, innermost first.
+pub struct Symbol {
+    pub name: Option<Vec<u8>>,
+    pub filename: Option<Vec<u8>>,
+    pub lineno: Option<u32>,
+}
 
 #[cfg(target_pointer_width = "64")]
 pub const HEX_WIDTH: usize = 18;
@@ -10,9 +10,25 @@
 use prelude::v1::*;
 use io;
 use io::ErrorKind;
-use io::Read;
+use io::{IoSlice, IoSliceMut, Read};
 use slice::from_raw_parts_mut;
 
+// The fallback for `read_vectored` and `write_vectored` on readers and writers
+// which can't do any better: operate on the first nonempty buffer only.
+pub fn default_read_vectored<F>(read: F, bufs: &mut [IoSliceMut]) -> io::Result<usize>
+    where F: FnOnce(&mut [u8]) -> io::Result<usize>
+{
+    let buf = bufs.iter_mut().find(|b| !b.is_empty()).map_or(&mut [][..], |b| &mut **b);
+    read(buf)
+}
+
+pub fn default_write_vectored<F>(write: F, bufs: &[IoSlice]) -> io::Result<usize>
+    where F: FnOnce(&[u8]) -> io::Result<usize>
+{
+    let buf = bufs.iter().find(|b| !b.is_empty()).map_or(&[][..], |b| &**b);
+    write(buf)
+}
+
 // Provides read_to_end functionality over an uninitialized buffer.
 // This function is unsafe because it calls the underlying
 // read function with a slice into uninitialized memory. The default
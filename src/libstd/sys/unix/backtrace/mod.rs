@@ -83,9 +83,42 @@
 /// to symbols. This is a bit of a hokey implementation as-is, but it works for
 /// all unix platforms we support right now, so it at least gets the job done.
 
-pub use self::tracing::write;
+pub use self::tracing::{write, trace};
+
+use prelude::v1::*;
+
+use io;
+use sys_common::backtrace::{Frame, Symbol};
+use sys_common::mutex::Mutex;
 
 // tracing impls:
 mod tracing;
 // symbol resolvers:
 mod printing;
+
+// libbacktrace keeps global state and isn't safe to call from more than one
+// thread at a time, so both printing and resolving frames happen under this
+// lock.
+static LOCK: Mutex = Mutex::new();
+
+/// Resolves the symbols of each frame in `frames`, calling `cb` with the index
+/// of the frame and each symbol found for it.
+pub fn resolve(frames: &[Frame], cb: &mut FnMut(usize, Symbol)) -> io::Result<()> {
+    // The symbols are collected first and only handed to `cb` once the lock
+    // has been released, so a panicking callback can't leave it held.
+    let mut symbols = Vec::new();
+    let ret = unsafe {
+        LOCK.lock();
+        let mut ret = Ok(());
+        for (i, frame) in frames.iter().enumerate() {
+            ret = printing::resolve(frame, &mut |symbol| symbols.push((i, symbol)));
+            if ret.is_err() { break }
+        }
+        LOCK.unlock();
+        ret
+    };
+    for (i, symbol) in symbols {
+        cb(i, symbol);
+    }
+    ret
+}
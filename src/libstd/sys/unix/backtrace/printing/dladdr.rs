@@ -11,6 +11,19 @@
 use io;
 use io::prelude::*;
 use libc;
+use sys_common::backtrace::{Frame, Symbol};
+
+#[repr(C)]
+struct Dl_info {
+    dli_fname: *const libc::c_char,
+    dli_fbase: *mut libc::c_void,
+    dli_sname: *const libc::c_char,
+    dli_saddr: *mut libc::c_void,
+}
+extern {
+    fn dladdr(addr: *const libc::c_void,
+              info: *mut Dl_info) -> libc::c_int;
+}
 
 pub fn print(w: &mut Write, idx: isize, addr: *mut libc::c_void,
              _symaddr: *mut libc::c_void) -> io::Result<()> {
@@ -18,18 +31,6 @@ pub fn print(w: &mut Write, idx: isize, addr: *mut libc::c_void,
     use intrinsics;
     use ffi::CStr;
 
-    #[repr(C)]
-    struct Dl_info {
-        dli_fname: *const libc::c_char,
-        dli_fbase: *mut libc::c_void,
-        dli_sname: *const libc::c_char,
-        dli_saddr: *mut libc::c_void,
-    }
-    extern {
-        fn dladdr(addr: *const libc::c_void,
-                  info: *mut Dl_info) -> libc::c_int;
-    }
-
     let mut info: Dl_info = unsafe { intrinsics::init() };
     if unsafe { dladdr(addr, &mut info) == 0 } {
         output(w, idx,addr, None)
@@ -39,3 +40,18 @@ pub fn print(w: &mut Write, idx: isize, addr: *mut libc::c_void,
         }))
     }
 }
+
+pub fn resolve(frame: &Frame, cb: &mut FnMut(Symbol)) -> io::Result<()> {
+    use intrinsics;
+    use ffi::CStr;
+
+    let mut info: Dl_info = unsafe { intrinsics::init() };
+    let name = if unsafe { dladdr(frame.exact_position, &mut info) == 0 } ||
+                  info.dli_sname.is_null() {
+        None
+    } else {
+        Some(unsafe { CStr::from_ptr(info.dli_sname).to_bytes().to_vec() })
+    };
+    cb(Symbol { name: name, filename: None, lineno: None });
+    Ok(())
+}
@@ -8,7 +8,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-pub use self::imp::print;
+pub use self::imp::{print, resolve};
 
 #[cfg(any(target_os = "macos", target_os = "ios",
           target_os = "emscripten"))]
@@ -22,27 +22,28 @@ use io::prelude::*;
 use io;
 use libc;
 use mem;
-use sys::mutex::Mutex;
+use sys_common::backtrace::Frame;
 
+use super::super::LOCK;
 use super::super::printing::print;
 
+extern {
+    fn backtrace(buf: *mut *mut libc::c_void,
+                 sz: libc::c_int) -> libc::c_int;
+}
+
+// 100 lines should be enough
+const SIZE: usize = 100;
+
 #[inline(never)]
 pub fn write(w: &mut Write) -> io::Result<()> {
-    extern {
-        fn backtrace(buf: *mut *mut libc::c_void,
-                     sz: libc::c_int) -> libc::c_int;
-    }
-
     // while it doesn't requires lock for work as everything is
     // local, it still displays much nicer backtraces when a
     // couple of threads panic simultaneously
-    static LOCK: Mutex = Mutex::new();
     unsafe {
         LOCK.lock();
 
         writeln!(w, "stack backtrace:")?;
-        // 100 lines should be enough
-        const SIZE: usize = 100;
         let mut buf: [*mut libc::c_void; SIZE] = mem::zeroed();
         let cnt = backtrace(buf.as_mut_ptr(), SIZE as libc::c_int) as usize;
 
@@ -54,3 +55,15 @@ pub fn write(w: &mut Write) -> io::Result<()> {
     }
     Ok(())
 }
+
+#[inline(never)]
+pub fn trace(frames: &mut Vec<Frame>) -> io::Result<()> {
+    unsafe {
+        let mut buf: [*mut libc::c_void; SIZE] = mem::zeroed();
+        let cnt = backtrace(buf.as_mut_ptr(), SIZE as libc::c_int) as usize;
+        frames.extend(buf[..cnt].iter().map(|&ip| {
+            Frame { exact_position: ip, symbol_addr: ip }
+        }));
+    }
+    Ok(())
+}
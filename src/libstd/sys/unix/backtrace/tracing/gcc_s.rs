@@ -12,8 +12,9 @@ use io;
 use io::prelude::*;
 use libc;
 use mem;
-use sys_common::mutex::Mutex;
+use sys_common::backtrace::Frame;
 
+use super::super::LOCK;
 use super::super::printing::print;
 use unwind as uw;
 
@@ -31,7 +32,6 @@ pub fn write(w: &mut Write) -> io::Result<()> {
     // is semi-reasonable in terms of printing anyway, and we know that all
     // I/O done here is blocking I/O, not green I/O, so we don't have to
     // worry about this being a native vs green mutex.
-    static LOCK: Mutex = Mutex::new();
     unsafe {
         LOCK.lock();
 
@@ -57,31 +57,7 @@ pub fn write(w: &mut Write) -> io::Result<()> {
     extern fn trace_fn(ctx: *mut uw::_Unwind_Context,
                        arg: *mut libc::c_void) -> uw::_Unwind_Reason_Code {
         let cx: &mut Context = unsafe { mem::transmute(arg) };
-        let mut ip_before_insn = 0;
-        let mut ip = unsafe {
-            uw::_Unwind_GetIPInfo(ctx, &mut ip_before_insn) as *mut libc::c_void
-        };
-        if !ip.is_null() && ip_before_insn == 0 {
-            // this is a non-signaling frame, so `ip` refers to the address
-            // after the calling instruction. account for that.
-            ip = (ip as usize - 1) as *mut _;
-        }
-
-        // dladdr() on osx gets whiny when we use FindEnclosingFunction, and
-        // it appears to work fine without it, so we only use
-        // FindEnclosingFunction on non-osx platforms. In doing so, we get a
-        // slightly more accurate stack trace in the process.
-        //
-        // This is often because panic involves the last instruction of a
-        // function being "call std::rt::begin_unwind", with no ret
-        // instructions after it. This means that the return instruction
-        // pointer points *outside* of the calling function, and by
-        // unwinding it we go back to the original function.
-        let symaddr = if cfg!(target_os = "macos") || cfg!(target_os = "ios") {
-            ip
-        } else {
-            unsafe { uw::_Unwind_FindEnclosingFunction(ip) }
-        };
+        let (ip, symaddr) = unsafe { frame_addrs(ctx) };
 
         // Don't print out the first few frames (they're not user frames)
         cx.idx += 1;
@@ -107,3 +83,53 @@ pub fn write(w: &mut Write) -> io::Result<()> {
         uw::_URC_NO_REASON
     }
 }
+
+#[inline(never)] // see `write`
+pub fn trace(frames: &mut Vec<Frame>) -> io::Result<()> {
+    unsafe {
+        uw::_Unwind_Backtrace(trace_fn,
+                              frames as *mut Vec<Frame> as *mut libc::c_void);
+    }
+    return Ok(());
+
+    extern fn trace_fn(ctx: *mut uw::_Unwind_Context,
+                       arg: *mut libc::c_void) -> uw::_Unwind_Reason_Code {
+        let frames = unsafe { &mut *(arg as *mut Vec<Frame>) };
+        let (ip, symaddr) = unsafe { frame_addrs(ctx) };
+        frames.push(Frame {
+            exact_position: ip,
+            symbol_addr: symaddr,
+        });
+        uw::_URC_NO_REASON
+    }
+}
+
+// Returns the instruction pointer of the frame and the address of the
+// function enclosing it.
+unsafe fn frame_addrs(ctx: *mut uw::_Unwind_Context)
+                      -> (*mut libc::c_void, *mut libc::c_void) {
+    let mut ip_before_insn = 0;
+    let mut ip = uw::_Unwind_GetIPInfo(ctx, &mut ip_before_insn) as *mut libc::c_void;
+    if !ip.is_null() && ip_before_insn == 0 {
+        // this is a non-signaling frame, so `ip` refers to the address
+        // after the calling instruction. account for that.
+        ip = (ip as usize - 1) as *mut _;
+    }
+
+    // dladdr() on osx gets whiny when we use FindEnclosingFunction, and
+    // it appears to work fine without it, so we only use
+    // FindEnclosingFunction on non-osx platforms. In doing so, we get a
+    // slightly more accurate stack trace in the process.
+    //
+    // This is often because panic involves the last instruction of a
+    // function being "call std::rt::begin_unwind", with no ret
+    // instructions after it. This means that the return instruction
+    // pointer points *outside* of the calling function, and by
+    // unwinding it we go back to the original function.
+    let symaddr = if cfg!(target_os = "macos") || cfg!(target_os = "ios") {
+        ip
+    } else {
+        uw::_Unwind_FindEnclosingFunction(ip)
+    };
+    (ip, symaddr)
+}
@@ -28,6 +28,12 @@ use time::Duration;
 use sys::cvt;
 use sys::net::Socket;
 use sys_common::{AsInner, FromInner, IntoInner};
+#[cfg(any(target_os = "linux", target_os = "android"))]
+use ptr;
+#[cfg(any(target_os = "linux", target_os = "android"))]
+use sys::fd::FileDesc;
+#[cfg(any(target_os = "linux", target_os = "android"))]
+use sys_common::net::getsockopt;
 
 fn sun_path_offset() -> usize {
     unsafe {
@@ -68,6 +74,112 @@ unsafe fn sockaddr_un(path: &Path) -> io::Result<(libc::sockaddr_un, libc::sockl
     Ok((addr, len as libc::socklen_t))
 }
 
+// The largest number of file descriptors Linux accepts in one SCM_RIGHTS
+// message.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+const SCM_MAX_FD: usize = 253;
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn cmsg_align(len: usize) -> usize {
+    let align = mem::size_of::<usize>();
+    (len + align - 1) & !(align - 1)
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn cmsg_space(fds: usize) -> usize {
+    cmsg_align(mem::size_of::<libc::cmsghdr>()) + cmsg_align(fds * mem::size_of::<RawFd>())
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn send_with_fds(sock: &Socket, buf: &[u8], fds: &[RawFd]) -> io::Result<usize> {
+    if fds.len() > SCM_MAX_FD {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput,
+                                  "too many file descriptors in one message"));
+    }
+
+    unsafe {
+        let mut iov = libc::iovec {
+            iov_base: buf.as_ptr() as *mut libc::c_void,
+            iov_len: buf.len(),
+        };
+        let mut msg: libc::msghdr = mem::zeroed();
+        msg.msg_iov = &mut iov;
+        msg.msg_iovlen = 1;
+
+        // Backed by `usize`s so the control message header is aligned.
+        let space = cmsg_space(fds.len());
+        let mut control = vec![0usize; space / mem::size_of::<usize>()];
+        if !fds.is_empty() {
+            let hdr_len = cmsg_align(mem::size_of::<libc::cmsghdr>());
+            let cmsg = control.as_mut_ptr() as *mut libc::cmsghdr;
+            (*cmsg).cmsg_level = libc::SOL_SOCKET;
+            (*cmsg).cmsg_type = libc::SCM_RIGHTS;
+            (*cmsg).cmsg_len = (hdr_len + fds.len() * mem::size_of::<RawFd>()) as _;
+            let data = (cmsg as *mut u8).offset(hdr_len as isize) as *mut RawFd;
+            ptr::copy_nonoverlapping(fds.as_ptr(), data, fds.len());
+
+            msg.msg_control = control.as_mut_ptr() as *mut libc::c_void;
+            msg.msg_controllen = space as _;
+        }
+
+        let ret = cvt(libc::sendmsg(*sock.as_inner(), &msg, 0))?;
+        Ok(ret as usize)
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn recv_with_fds<T: FromRawFd>(sock: &Socket,
+                               buf: &mut [u8],
+                               fds: &mut Vec<T>) -> io::Result<usize> {
+    unsafe {
+        let mut iov = libc::iovec {
+            iov_base: buf.as_mut_ptr() as *mut libc::c_void,
+            iov_len: buf.len(),
+        };
+        let mut msg: libc::msghdr = mem::zeroed();
+        msg.msg_iov = &mut iov;
+        msg.msg_iovlen = 1;
+
+        let space = cmsg_space(SCM_MAX_FD);
+        let mut control = vec![0usize; space / mem::size_of::<usize>()];
+        msg.msg_control = control.as_mut_ptr() as *mut libc::c_void;
+        msg.msg_controllen = space as _;
+
+        let ret = cvt(libc::recvmsg(*sock.as_inner(), &mut msg, libc::MSG_CMSG_CLOEXEC))?;
+
+        // Take ownership of every descriptor that arrived before anything
+        // else, so that none of them leak if we bail out below.
+        let mut received = Vec::new();
+        let hdr_len = cmsg_align(mem::size_of::<libc::cmsghdr>());
+        let control_len = msg.msg_controllen as usize;
+        let base = control.as_ptr() as *const u8;
+        let mut offset = 0;
+        while offset + mem::size_of::<libc::cmsghdr>() <= control_len {
+            let cmsg = base.offset(offset as isize) as *const libc::cmsghdr;
+            let len = (*cmsg).cmsg_len as usize;
+            if len < hdr_len || offset + len > control_len {
+                break
+            }
+            if (*cmsg).cmsg_level == libc::SOL_SOCKET &&
+               (*cmsg).cmsg_type == libc::SCM_RIGHTS {
+                let data = (cmsg as *const u8).offset(hdr_len as isize) as *const RawFd;
+                for i in 0..(len - hdr_len) / mem::size_of::<RawFd>() {
+                    received.push(FileDesc::new(*data.offset(i as isize)));
+                }
+            }
+            offset += cmsg_align(len);
+        }
+
+        if msg.msg_flags & libc::MSG_CTRUNC != 0 {
+            return Err(io::Error::new(io::ErrorKind::Other,
+                                      "received file descriptors were truncated"));
+        }
+
+        fds.extend(received.into_iter().map(|fd| T::from_raw_fd(fd.into_raw())));
+        Ok(ret as usize)
+    }
+}
+
 enum AddressKind<'a> {
     Unnamed,
     Pathname(&'a Path),
@@ -168,6 +280,21 @@ impl<'a> fmt::Display for AsciiEscaped<'a> {
     }
 }
 
+/// Credentials of the process on the other end of a Unix socket, as
+/// returned by `UnixStream::peer_cred`.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[unstable(feature = "peer_credentials_unix_socket", issue = "0")]
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UCred {
+    /// The process ID of the peer.
+    pub pid: i32,
+    /// The effective user ID of the peer.
+    pub uid: u32,
+    /// The effective group ID of the peer.
+    pub gid: u32,
+}
+
 /// A Unix stream socket.
 ///
 /// # Examples
@@ -301,6 +428,91 @@ impl UnixStream {
     pub fn shutdown(&self, how: Shutdown) -> io::Result<()> {
         self.0.shutdown(how)
     }
+
+    /// Returns the credentials of the process on the other end of this
+    /// connection, as they were when the connection was established.
+    ///
+    /// This corresponds to the `SO_PEERCRED` socket option.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(peer_credentials_unix_socket)]
+    ///
+    /// use std::os::unix::net::UnixStream;
+    ///
+    /// let stream = UnixStream::connect("/tmp/sock").unwrap();
+    /// let cred = stream.peer_cred().unwrap();
+    /// println!("peer is pid {} running as uid {}", cred.pid, cred.uid);
+    /// ```
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    #[unstable(feature = "peer_credentials_unix_socket", issue = "0")]
+    pub fn peer_cred(&self) -> io::Result<UCred> {
+        getsockopt(&self.0, libc::SOL_SOCKET, libc::SO_PEERCRED)
+    }
+
+    /// Sends data on the socket along with the file descriptors in `fds`.
+    ///
+    /// The descriptors are passed in an `SCM_RIGHTS` control message and are
+    /// duplicated into the receiving process; the caller's copies remain
+    /// open. They are attached to the first byte sent, so `buf` must not be
+    /// empty when `fds` isn't.
+    ///
+    /// On success, returns the number of bytes written.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_socket_ancillary_data)]
+    ///
+    /// use std::net::TcpListener;
+    /// use std::os::unix::io::AsRawFd;
+    /// use std::os::unix::net::UnixStream;
+    ///
+    /// let listener = TcpListener::bind("127.0.0.1:8080").unwrap();
+    /// let stream = UnixStream::connect("/tmp/sock").unwrap();
+    /// stream.send_with_fds(b"listener", &[listener.as_raw_fd()]).unwrap();
+    /// ```
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "0")]
+    pub fn send_with_fds(&self, buf: &[u8], fds: &[RawFd]) -> io::Result<usize> {
+        if buf.is_empty() && !fds.is_empty() {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput,
+                                      "file descriptors must be sent with data"));
+        }
+        send_with_fds(&self.0, buf, fds)
+    }
+
+    /// Receives data from the socket along with any file descriptors sent
+    /// with it.
+    ///
+    /// Each received descriptor is converted into a `T` and appended to
+    /// `fds`, which then owns it. Descriptors are received with the
+    /// close-on-exec flag set. If more descriptors arrive than can be
+    /// received at once, all of them are closed and an error is returned.
+    ///
+    /// On success, returns the number of bytes read.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_socket_ancillary_data)]
+    ///
+    /// use std::net::TcpListener;
+    /// use std::os::unix::net::UnixStream;
+    ///
+    /// let stream = UnixStream::connect("/tmp/sock").unwrap();
+    /// let mut buf = [0; 8];
+    /// let mut listeners: Vec<TcpListener> = Vec::new();
+    /// stream.recv_with_fds(&mut buf, &mut listeners).unwrap();
+    /// ```
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "0")]
+    pub fn recv_with_fds<T: FromRawFd>(&self,
+                                       buf: &mut [u8],
+                                       fds: &mut Vec<T>) -> io::Result<usize> {
+        recv_with_fds(&self.0, buf, fds)
+    }
 }
 
 #[stable(feature = "unix_socket", since = "1.10.0")]
@@ -309,6 +521,10 @@ impl io::Read for UnixStream {
         io::Read::read(&mut &*self, buf)
     }
 
+    fn read_vectored(&mut self, bufs: &mut [io::IoSliceMut]) -> io::Result<usize> {
+        io::Read::read_vectored(&mut &*self, bufs)
+    }
+
     fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
         io::Read::read_to_end(&mut &*self, buf)
     }
@@ -320,6 +536,10 @@ impl<'a> io::Read for &'a UnixStream {
         self.0.read(buf)
     }
 
+    fn read_vectored(&mut self, bufs: &mut [io::IoSliceMut]) -> io::Result<usize> {
+        self.0.read_vectored(bufs)
+    }
+
     fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
         self.0.read_to_end(buf)
     }
@@ -331,6 +551,10 @@ impl io::Write for UnixStream {
         io::Write::write(&mut &*self, buf)
     }
 
+    fn write_vectored(&mut self, bufs: &[io::IoSlice]) -> io::Result<usize> {
+        io::Write::write_vectored(&mut &*self, bufs)
+    }
+
     fn flush(&mut self) -> io::Result<()> {
         io::Write::flush(&mut &*self)
     }
@@ -342,6 +566,10 @@ impl<'a> io::Write for &'a UnixStream {
         self.0.write(buf)
     }
 
+    fn write_vectored(&mut self, bufs: &[io::IoSlice]) -> io::Result<usize> {
+        self.0.write_vectored(bufs)
+    }
+
     fn flush(&mut self) -> io::Result<()> {
         Ok(())
     }
@@ -764,6 +992,37 @@ impl UnixDatagram {
     pub fn shutdown(&self, how: Shutdown) -> io::Result<()> {
         self.0.shutdown(how)
     }
+
+    /// Sends data on the socket to its connected peer along with the file
+    /// descriptors in `fds`.
+    ///
+    /// The descriptors are passed in an `SCM_RIGHTS` control message and are
+    /// duplicated into the receiving process; the caller's copies remain
+    /// open.
+    ///
+    /// On success, returns the number of bytes written.
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "0")]
+    pub fn send_with_fds(&self, buf: &[u8], fds: &[RawFd]) -> io::Result<usize> {
+        send_with_fds(&self.0, buf, fds)
+    }
+
+    /// Receives a datagram from the socket along with any file descriptors
+    /// sent with it.
+    ///
+    /// Each received descriptor is converted into a `T` and appended to
+    /// `fds`, which then owns it. Descriptors are received with the
+    /// close-on-exec flag set. If more descriptors arrive than can be
+    /// received at once, all of them are closed and an error is returned.
+    ///
+    /// On success, returns the number of bytes read.
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "0")]
+    pub fn recv_with_fds<T: FromRawFd>(&self,
+                                       buf: &mut [u8],
+                                       fds: &mut Vec<T>) -> io::Result<usize> {
+        recv_with_fds(&self.0, buf, fds)
+    }
 }
 
 #[stable(feature = "unix_socket", since = "1.10.0")]
@@ -1097,4 +1356,79 @@ mod test {
     fn abstract_namespace_not_allowed() {
         assert!(UnixStream::connect("\0asdf").is_err());
     }
+
+    #[test]
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    fn peer_cred() {
+        use libc;
+
+        let (s1, s2) = or_panic!(UnixStream::pair());
+        let cred = or_panic!(s1.peer_cred());
+        assert_eq!(cred, or_panic!(s2.peer_cred()));
+        unsafe {
+            assert_eq!(cred.pid, libc::getpid() as i32);
+            assert_eq!(cred.uid, libc::geteuid() as u32);
+            assert_eq!(cred.gid, libc::getegid() as u32);
+        }
+    }
+
+    #[test]
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    fn send_recv_fds() {
+        use fs::{File, OpenOptions};
+        use io::SeekFrom;
+        use os::unix::io::AsRawFd;
+
+        let dir = tmpdir();
+        let path = dir.path().join("passed");
+        let mut file = or_panic!(OpenOptions::new().read(true).write(true)
+                                                   .create(true).open(&path));
+        or_panic!(file.write_all(b"passed along"));
+
+        let (s1, s2) = or_panic!(UnixStream::pair());
+        assert_eq!(or_panic!(s1.send_with_fds(b"x", &[file.as_raw_fd()])), 1);
+        drop(file);
+
+        let mut buf = [0; 4];
+        let mut files = Vec::new();
+        assert_eq!(or_panic!(s2.recv_with_fds(&mut buf, &mut files)), 1);
+        assert_eq!(buf[0], b'x');
+        assert_eq!(files.len(), 1);
+
+        let mut file: File = files.pop().unwrap();
+        or_panic!(file.seek(SeekFrom::Start(0)));
+        let mut contents = String::new();
+        or_panic!(file.read_to_string(&mut contents));
+        assert_eq!(contents, "passed along");
+
+        assert!(s1.send_with_fds(b"", &[file.as_raw_fd()]).is_err());
+    }
+
+    #[test]
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    fn datagram_send_recv_fds() {
+        use os::unix::io::AsRawFd;
+
+        let (s1, s2) = or_panic!(UnixDatagram::pair());
+        let (a, mut b) = or_panic!(UnixStream::pair());
+        assert_eq!(or_panic!(s1.send_with_fds(b"", &[a.as_raw_fd()])), 0);
+        drop(a);
+
+        let mut buf = [0; 4];
+        let mut streams: Vec<UnixStream> = Vec::new();
+        assert_eq!(or_panic!(s2.recv_with_fds(&mut buf, &mut streams)), 0);
+        assert_eq!(streams.len(), 1);
+
+        let mut a = streams.pop().unwrap();
+        or_panic!(a.write_all(b"hi"));
+        let mut buf = [0; 2];
+        or_panic!(b.read_exact(&mut buf));
+        assert_eq!(&buf, b"hi");
+
+        // A plain datagram carries no descriptors.
+        or_panic!(s1.send(b"none"));
+        let mut buf = [0; 4];
+        assert_eq!(or_panic!(s2.recv_with_fds(&mut buf, &mut streams)), 4);
+        assert!(streams.is_empty());
+    }
 }
@@ -14,6 +14,7 @@
 
 use prelude::v1::*;
 
+use ffi::OsStr;
 use io;
 use os::unix::io::{FromRawFd, RawFd, AsRawFd, IntoRawFd};
 use process;
@@ -83,6 +84,55 @@ pub trait CommandExt {
     /// cross-platform `spawn` instead.
     #[stable(feature = "process_exec2", since = "1.9.0")]
     fn exec(&mut self) -> io::Error;
+
+    /// Sets the process group of the child process. This translates to a
+    /// `setpgid` call in the child process, and failure in that call will
+    /// cause the spawn to fail.
+    ///
+    /// If `pgroup` is 0, the child is put in a new process group whose id is
+    /// its own process id. Otherwise it joins the existing group `pgroup`,
+    /// which must belong to the same session as the current process.
+    ///
+    /// The whole group can then be signalled at once, for example with
+    /// `ChildExt::kill_group`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(process_group)]
+    ///
+    /// use std::process::Command;
+    /// use std::os::unix::process::{ChildExt, CommandExt};
+    ///
+    /// let mut child = Command::new("sh")
+    ///                         .arg("-c").arg("sleep 100 & sleep 100")
+    ///                         .process_group(0)
+    ///                         .spawn().unwrap();
+    ///
+    /// // Kills both `sleep`s along with the shell.
+    /// child.kill_group().unwrap();
+    /// ```
+    #[unstable(feature = "process_group", issue = "0")]
+    fn process_group(&mut self, pgroup: i32) -> &mut process::Command;
+
+    /// Makes the child process the leader of a new session. This translates
+    /// to a `setsid` call in the child process, and failure in that call will
+    /// cause the spawn to fail.
+    ///
+    /// The child is detached from the controlling terminal of the current
+    /// process and put in a new process group of its own, which is what
+    /// daemons usually want. Because of the latter, this can't be combined
+    /// with `process_group`.
+    #[unstable(feature = "process_setsid", issue = "0")]
+    fn setsid(&mut self, setsid: bool) -> &mut process::Command;
+
+    /// Sets the first process argument, `argv[0]`, to something other than
+    /// the default executable path.
+    ///
+    /// The program passed to `Command::new` is still the one executed; only
+    /// the name the child sees for itself changes.
+    #[unstable(feature = "process_set_argv0", issue = "0")]
+    fn arg0<S: AsRef<OsStr>>(&mut self, arg: S) -> &mut process::Command;
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -107,6 +157,43 @@ impl CommandExt for process::Command {
     fn exec(&mut self) -> io::Error {
         self.as_inner_mut().exec(sys::process::Stdio::Inherit)
     }
+
+    fn process_group(&mut self, pgroup: i32) -> &mut process::Command {
+        self.as_inner_mut().pgroup(pgroup);
+        self
+    }
+
+    fn setsid(&mut self, setsid: bool) -> &mut process::Command {
+        self.as_inner_mut().setsid(setsid);
+        self
+    }
+
+    fn arg0<S: AsRef<OsStr>>(&mut self, arg: S) -> &mut process::Command {
+        self.as_inner_mut().arg0(arg.as_ref());
+        self
+    }
+}
+
+/// Unix-specific extensions to `std::process::Child`
+#[unstable(feature = "process_group", issue = "0")]
+pub trait ChildExt {
+    /// Forces the child's whole process group to exit, by sending SIGKILL to
+    /// the group the child belongs to.
+    ///
+    /// This is meant for children spawned with `CommandExt::process_group` or
+    /// `CommandExt::setsid`, and returns an error if the child is still in
+    /// the process group of the current process, since that would kill the
+    /// current process as well. As with `Child::kill`, an error is also
+    /// returned if the child has already been waited on.
+    #[unstable(feature = "process_group", issue = "0")]
+    fn kill_group(&mut self) -> io::Result<()>;
+}
+
+#[unstable(feature = "process_group", issue = "0")]
+impl ChildExt for process::Child {
+    fn kill_group(&mut self) -> io::Result<()> {
+        self.as_inner_mut().kill_group()
+    }
 }
 
 /// Unix-specific extensions to `std::process::ExitStatus`
@@ -12,7 +12,8 @@
 
 use prelude::v1::*;
 
-use io::{self, Read};
+use cmp;
+use io::{self, Read, IoSlice, IoSliceMut};
 use libc::{self, c_int, size_t, c_void};
 use mem;
 use sync::atomic::{AtomicBool, Ordering};
@@ -24,6 +25,11 @@ pub struct FileDesc {
     fd: c_int,
 }
 
+// `readv` and `writev` fail with EINVAL when handed more than IOV_MAX buffers.
+// 1024 is the limit on Linux and the BSDs; anything past it is simply left for
+// the next call.
+const IOV_MAX: usize = 1024;
+
 impl FileDesc {
     pub fn new(fd: c_int) -> FileDesc {
         FileDesc { fd: fd }
@@ -47,6 +53,15 @@ impl FileDesc {
         Ok(ret as usize)
     }
 
+    pub fn read_vectored(&self, bufs: &mut [IoSliceMut]) -> io::Result<usize> {
+        let ret = cvt(unsafe {
+            libc::readv(self.fd,
+                        bufs.as_ptr() as *const libc::iovec,
+                        cmp::min(bufs.len(), IOV_MAX) as c_int)
+        })?;
+        Ok(ret as usize)
+    }
+
     pub fn read_to_end(&self, buf: &mut Vec<u8>) -> io::Result<usize> {
         let mut me = self;
         (&mut me).read_to_end(buf)
@@ -61,6 +76,15 @@ impl FileDesc {
         Ok(ret as usize)
     }
 
+    pub fn write_vectored(&self, bufs: &[IoSlice]) -> io::Result<usize> {
+        let ret = cvt(unsafe {
+            libc::writev(self.fd,
+                         bufs.as_ptr() as *const libc::iovec,
+                         cmp::min(bufs.len(), IOV_MAX) as c_int)
+        })?;
+        Ok(ret as usize)
+    }
+
     #[cfg(not(any(target_env = "newlib", target_os = "solaris", target_os = "emscripten")))]
     pub fn set_cloexec(&self) -> io::Result<()> {
         unsafe {
@@ -13,7 +13,7 @@ use os::unix::prelude::*;
 
 use ffi::{CString, CStr, OsString, OsStr};
 use fmt;
-use io::{self, Error, ErrorKind, SeekFrom};
+use io::{self, Error, ErrorKind, IoSlice, IoSliceMut, SeekFrom};
 use libc::{self, c_int, mode_t};
 use mem;
 use path::{Path, PathBuf};
@@ -453,6 +453,45 @@ impl File {
         unsafe fn os_datasync(fd: c_int) -> c_int { libc::fsync(fd) }
     }
 
+    pub fn lock_shared(&self) -> io::Result<()> {
+        self.flock(libc::LOCK_SH).map(|_| ())
+    }
+
+    pub fn lock_exclusive(&self) -> io::Result<()> {
+        self.flock(libc::LOCK_EX).map(|_| ())
+    }
+
+    pub fn try_lock_shared(&self) -> io::Result<bool> {
+        self.flock(libc::LOCK_SH | libc::LOCK_NB)
+    }
+
+    pub fn try_lock_exclusive(&self) -> io::Result<bool> {
+        self.flock(libc::LOCK_EX | libc::LOCK_NB)
+    }
+
+    pub fn unlock(&self) -> io::Result<()> {
+        self.flock(libc::LOCK_UN).map(|_| ())
+    }
+
+    // `flock` rather than `fcntl` locks: the former belong to the open file
+    // description, so they are shared with `try_clone` handles and aren't
+    // dropped when some unrelated descriptor for the same file is closed.
+    #[cfg(not(any(target_env = "newlib", target_os = "solaris", target_os = "emscripten")))]
+    fn flock(&self, operation: c_int) -> io::Result<bool> {
+        match cvt_r(|| unsafe { libc::flock(self.0.raw(), operation) }) {
+            Ok(_) => Ok(true),
+            Err(ref e) if e.kind() == ErrorKind::WouldBlock => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    // These targets have no `flock`, and `fcntl` locks would not provide the
+    // semantics documented above, so file locking is unsupported on them.
+    #[cfg(any(target_env = "newlib", target_os = "solaris", target_os = "emscripten"))]
+    fn flock(&self, _operation: c_int) -> io::Result<bool> {
+        Err(io::Error::new(ErrorKind::Other, "file locking is not supported on this platform"))
+    }
+
     pub fn truncate(&self, size: u64) -> io::Result<()> {
         #[cfg(target_os = "android")]
         return ::sys::android::ftruncate64(self.0.raw(), size);
@@ -467,6 +506,10 @@ impl File {
         self.0.read(buf)
     }
 
+    pub fn read_vectored(&self, bufs: &mut [IoSliceMut]) -> io::Result<usize> {
+        self.0.read_vectored(bufs)
+    }
+
     pub fn read_to_end(&self, buf: &mut Vec<u8>) -> io::Result<usize> {
         self.0.read_to_end(buf)
     }
@@ -475,6 +518,10 @@ impl File {
         self.0.write(buf)
     }
 
+    pub fn write_vectored(&self, bufs: &[IoSlice]) -> io::Result<usize> {
+        self.0.write_vectored(bufs)
+    }
+
     pub fn flush(&self) -> io::Result<()> { Ok(()) }
 
     pub fn seek(&self, pos: SeekFrom) -> io::Result<u64> {
@@ -0,0 +1,206 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Copying between file descriptors without a trip through userspace.
+//!
+//! `io::copy` asks this module first. When both ends are backed by plain file
+//! descriptors (see `CopyFd`) the data is moved by the kernel with
+//! `copy_file_range`, `sendfile` or `splice`, whichever applies to the kinds
+//! of file involved. If none of them can do the copy before anything has been
+//! written, it's handed back to the buffered copy in `io::copy`.
+
+use prelude::v1::*;
+
+use fs::File;
+use io::{self, Read, Write};
+use libc::{self, c_int, c_uint, size_t, ssize_t};
+use mem;
+use net::TcpStream;
+use os::unix::io::{AsRawFd, RawFd};
+use os::unix::net::UnixStream;
+use process::{ChildStdin, ChildStdout, ChildStderr};
+use ptr;
+use sync::atomic::{AtomicBool, Ordering};
+use sys::cvt;
+use sys::os::errno;
+
+#[cfg(target_os = "linux")]
+use libc::{stat64, fstat64};
+#[cfg(target_os = "android")]
+use libc::{stat as stat64, fstat as fstat64};
+
+// The most any one of the syscalls below will move at once.
+const MAX_CHUNK: usize = 0x7fff_f000;
+
+pub enum CopyResult {
+    /// The copy was done by the kernel, successfully or not.
+    Ended(io::Result<u64>),
+    /// This many bytes were copied before running into something the kernel
+    /// can't do; the rest is left to the caller.
+    Fallback(u64),
+}
+
+/// Implemented by the readers and writers whose I/O goes straight to a file
+/// descriptor, with nothing buffered in between. Everything else gets the
+/// default impl, which has no descriptor to offer.
+trait CopyFd {
+    fn copy_fd(&self) -> Option<RawFd>;
+}
+
+impl<T: ?Sized> CopyFd for T {
+    default fn copy_fd(&self) -> Option<RawFd> {
+        None
+    }
+}
+
+impl<'a, T: ?Sized> CopyFd for &'a mut T {
+    fn copy_fd(&self) -> Option<RawFd> {
+        (**self).copy_fd()
+    }
+}
+
+impl<T: ?Sized> CopyFd for Box<T> {
+    fn copy_fd(&self) -> Option<RawFd> {
+        (**self).copy_fd()
+    }
+}
+
+macro_rules! copy_fd {
+    ($($t:ty),*) => ($(
+        impl CopyFd for $t {
+            fn copy_fd(&self) -> Option<RawFd> {
+                Some(self.as_raw_fd())
+            }
+        }
+    )*);
+    (by ref: $($t:ty),*) => ($(
+        copy_fd! { $t }
+        impl<'a> CopyFd for &'a $t {
+            fn copy_fd(&self) -> Option<RawFd> {
+                Some(self.as_raw_fd())
+            }
+        }
+    )*)
+}
+
+copy_fd! { by ref: File, TcpStream, UnixStream }
+copy_fd! { ChildStdin, ChildStdout, ChildStderr }
+
+pub fn copy<R: ?Sized, W: ?Sized>(reader: &mut R, writer: &mut W) -> CopyResult
+    where R: Read, W: Write
+{
+    let (src, dst) = match (<R as CopyFd>::copy_fd(reader), <W as CopyFd>::copy_fd(writer)) {
+        (Some(src), Some(dst)) => (src, dst),
+        _ => return CopyResult::Fallback(0),
+    };
+    let (src_stat, dst_stat) = match (fstat(src), fstat(dst)) {
+        (Ok(src), Ok(dst)) => (src, dst),
+        _ => return CopyResult::Fallback(0),
+    };
+    let is = |stat: &stat64, kind| stat.st_mode & libc::S_IFMT == kind;
+
+    // None of the syscalls can write to a descriptor opened for appending.
+    match unsafe { cvt(libc::fcntl(dst, libc::F_GETFL)) } {
+        Ok(flags) if flags & libc::O_APPEND == 0 => {}
+        _ => return CopyResult::Fallback(0),
+    }
+    // Files in procfs and the like claim to be empty even though reading
+    // them produces data, so leave those to `read`.
+    if is(&src_stat, libc::S_IFREG) && src_stat.st_size == 0 {
+        return CopyResult::Fallback(0)
+    }
+
+    let mut written = 0;
+    if is(&src_stat, libc::S_IFREG) && is(&dst_stat, libc::S_IFREG) {
+        if let Some(result) = copy_file_range(src, dst, &mut written) {
+            return CopyResult::Ended(result.map(|()| written))
+        }
+    }
+    if is(&src_stat, libc::S_IFREG) {
+        if let Some(result) = sendfile(src, dst, &mut written) {
+            return CopyResult::Ended(result.map(|()| written))
+        }
+    }
+    if is(&src_stat, libc::S_IFIFO) || is(&dst_stat, libc::S_IFIFO) {
+        if let Some(result) = splice(src, dst, &mut written) {
+            return CopyResult::Ended(result.map(|()| written))
+        }
+    }
+    CopyResult::Fallback(written)
+}
+
+fn fstat(fd: RawFd) -> io::Result<stat64> {
+    let mut stat: stat64 = unsafe { mem::zeroed() };
+    cvt(unsafe { fstat64(fd, &mut stat) })?;
+    Ok(stat)
+}
+
+// Runs `f` until it reports end of input, adding up what it moved in
+// `written`. Returns `None` if `f` turns out not to be able to copy between
+// these descriptors before anything was written, so that something else can
+// be tried.
+fn copy_loop<F>(written: &mut u64, mut f: F) -> Option<io::Result<()>>
+    where F: FnMut(size_t) -> ssize_t
+{
+    loop {
+        match cvt(f(MAX_CHUNK as size_t)) {
+            // Some filesystems report end of input straight away rather than
+            // an error when they don't support the syscall.
+            Ok(0) if *written == 0 => return None,
+            Ok(0) => return Some(Ok(())),
+            Ok(n) => *written += n as u64,
+            Err(ref e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(ref e) if *written == 0 && unsupported(e) => return None,
+            Err(e) => return Some(Err(e)),
+        }
+    }
+}
+
+// Whether `e` means that a syscall isn't available for these descriptors, as
+// opposed to the copy itself failing.
+fn unsupported(e: &io::Error) -> bool {
+    match e.raw_os_error() {
+        Some(libc::ENOSYS) | Some(libc::EXDEV) | Some(libc::EOPNOTSUPP) => true,
+        _ => false,
+    }
+}
+
+fn copy_file_range(src: RawFd, dst: RawFd, written: &mut u64) -> Option<io::Result<()>> {
+    // Not in every libc yet (and not in every kernel either), so look the
+    // wrapper up at runtime and remember when the kernel turns out to lack it.
+    weak! {
+        fn copy_file_range(c_int, *mut i64, c_int, *mut i64, size_t, c_uint) -> ssize_t
+    }
+    static UNAVAILABLE: AtomicBool = AtomicBool::new(false);
+
+    let copy_file_range = match copy_file_range.get() {
+        Some(f) if !UNAVAILABLE.load(Ordering::Relaxed) => f,
+        _ => return None,
+    };
+    copy_loop(written, |len| unsafe {
+        let ret = copy_file_range(src, ptr::null_mut(), dst, ptr::null_mut(), len, 0);
+        if ret == -1 && errno() == libc::ENOSYS {
+            UNAVAILABLE.store(true, Ordering::Relaxed);
+        }
+        ret
+    })
+}
+
+fn sendfile(src: RawFd, dst: RawFd, written: &mut u64) -> Option<io::Result<()>> {
+    copy_loop(written, |len| unsafe {
+        libc::sendfile(dst, src, ptr::null_mut(), len)
+    })
+}
+
+fn splice(src: RawFd, dst: RawFd, written: &mut u64) -> Option<io::Result<()>> {
+    copy_loop(written, |len| unsafe {
+        libc::splice(src, ptr::null_mut(), dst, ptr::null_mut(), len, 0)
+    })
+}
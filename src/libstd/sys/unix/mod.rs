@@ -35,6 +35,9 @@ pub mod condvar;
 pub mod ext;
 pub mod fd;
 pub mod fs;
+pub mod io;
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub mod kernel_copy;
 pub mod mutex;
 pub mod net;
 pub mod os;
@@ -11,7 +11,7 @@
 use prelude::v1::*;
 
 use cmp;
-use io;
+use io::{self, IoSlice, IoSliceMut};
 use libc::{self, c_int};
 use mem;
 use sys::cvt_r;
@@ -62,6 +62,10 @@ impl AnonPipe {
         self.0.read(buf)
     }
 
+    pub fn read_vectored(&self, bufs: &mut [IoSliceMut]) -> io::Result<usize> {
+        self.0.read_vectored(bufs)
+    }
+
     pub fn read_to_end(&self, buf: &mut Vec<u8>) -> io::Result<usize> {
         self.0.read_to_end(buf)
     }
@@ -70,6 +74,10 @@ impl AnonPipe {
         self.0.write(buf)
     }
 
+    pub fn write_vectored(&self, bufs: &[IoSlice]) -> io::Result<usize> {
+        self.0.write_vectored(bufs)
+    }
+
     pub fn fd(&self) -> &FileDesc { &self.0 }
     pub fn into_fd(self) -> FileDesc { self.0 }
 }
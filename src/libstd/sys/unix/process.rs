@@ -11,6 +11,7 @@
 use prelude::v1::*;
 use os::unix::prelude::*;
 
+use cmp;
 use collections::hash_map::{HashMap, Entry};
 use env;
 use ffi::{OsString, OsStr, CString, CStr};
@@ -23,6 +24,8 @@ use sys::fd::FileDesc;
 use sys::fs::{File, OpenOptions};
 use sys::pipe::{self, AnonPipe};
 use sys::{self, cvt, cvt_r};
+use thread;
+use time::{Duration, Instant};
 
 ////////////////////////////////////////////////////////////////////////////////
 // Command
@@ -36,9 +39,9 @@ pub struct Command {
     // just not do it at all!
     //
     // Along those lines, the `argv` and `envp` raw pointers here are exactly
-    // what's gonna get passed to `execvp`. The `argv` array starts with the
-    // `program` and ends with a NULL, and the `envp` pointer, if present, is
-    // also null-terminated.
+    // what's gonna get passed to `execvp`. The `argv` array starts with
+    // `arg0` if one was set and with the `program` otherwise, and ends with a
+    // NULL, and the `envp` pointer, if present, is also null-terminated.
     //
     // Right now we don't support removing arguments, so there's no much fancy
     // support there, but we support adding and removing environment variables,
@@ -55,6 +58,10 @@ pub struct Command {
     cwd: Option<CString>,
     uid: Option<uid_t>,
     gid: Option<gid_t>,
+    pgroup: Option<pid_t>,
+    setsid: bool,
+    // Overrides `argv[0]`; `program` is still the file that gets executed.
+    arg0: Option<CString>,
     saw_nul: bool,
     closures: Vec<Box<FnMut() -> io::Result<()> + Send + Sync>>,
     stdin: Option<Stdio>,
@@ -91,6 +98,18 @@ pub enum Stdio {
     Fd(FileDesc),
 }
 
+impl From<AnonPipe> for Stdio {
+    fn from(pipe: AnonPipe) -> Stdio {
+        Stdio::Fd(pipe.into_fd())
+    }
+}
+
+impl From<File> for Stdio {
+    fn from(file: File) -> Stdio {
+        Stdio::Fd(file.into_fd())
+    }
+}
+
 impl Command {
     pub fn new(program: &OsStr) -> Command {
         let mut saw_nul = false;
@@ -104,6 +123,9 @@ impl Command {
             cwd: None,
             uid: None,
             gid: None,
+            pgroup: None,
+            setsid: false,
+            arg0: None,
             saw_nul: saw_nul,
             closures: Vec::new(),
             stdin: None,
@@ -195,6 +217,17 @@ impl Command {
     pub fn gid(&mut self, id: gid_t) {
         self.gid = Some(id);
     }
+    pub fn pgroup(&mut self, pgroup: pid_t) {
+        self.pgroup = Some(pgroup);
+    }
+    pub fn setsid(&mut self, setsid: bool) {
+        self.setsid = setsid;
+    }
+    pub fn arg0(&mut self, arg: &OsStr) {
+        let arg = os2c(arg, &mut self.saw_nul);
+        self.argv[0] = arg.as_ptr();
+        self.arg0 = Some(arg);
+    }
 
     pub fn before_exec(&mut self,
                        f: Box<FnMut() -> io::Result<()> + Send + Sync>) {
@@ -347,6 +380,16 @@ impl Command {
             t!(cvt_r(|| libc::dup2(fd, libc::STDERR_FILENO)));
         }
 
+        // A new session also starts a new process group, and a session leader
+        // can't be moved to another group, so `setpgid` will fail with EPERM
+        // if both were requested.
+        if self.setsid {
+            t!(cvt(libc::setsid()));
+        }
+        if let Some(pgroup) = self.pgroup {
+            t!(cvt(libc::setpgid(0, pgroup)));
+        }
+
         if let Some(u) = self.gid {
             t!(cvt(libc::setgid(u as gid_t)));
         }
@@ -392,7 +435,7 @@ impl Command {
             t!(callback());
         }
 
-        libc::execvp(self.argv[0], self.argv.as_ptr());
+        libc::execvp(self.program.as_ptr(), self.argv.as_ptr());
         io::Error::last_os_error()
     }
 
@@ -579,6 +622,25 @@ impl Process {
         }
     }
 
+    pub fn kill_group(&mut self) -> io::Result<()> {
+        // As with `kill`, the pid may have been recycled after a wait.
+        if self.status.is_some() {
+            return Err(Error::new(ErrorKind::InvalidInput,
+                                  "invalid argument: can't kill an exited process"))
+        }
+        unsafe {
+            let pgroup = cvt(libc::getpgid(self.pid))?;
+            // A child which wasn't moved to a group of its own would take
+            // the current process down with it.
+            if pgroup == libc::getpgrp() {
+                return Err(Error::new(ErrorKind::InvalidInput,
+                                      "invalid argument: the process shares the \
+                                       process group of the current process"))
+            }
+            cvt(libc::kill(-pgroup, libc::SIGKILL)).map(|_| ())
+        }
+    }
+
     pub fn wait(&mut self) -> io::Result<ExitStatus> {
         if let Some(status) = self.status {
             return Ok(status)
@@ -588,6 +650,39 @@ impl Process {
         self.status = Some(ExitStatus(status));
         Ok(ExitStatus(status))
     }
+
+    pub fn try_wait(&mut self) -> io::Result<Option<ExitStatus>> {
+        if let Some(status) = self.status {
+            return Ok(Some(status))
+        }
+        let mut status = 0 as c_int;
+        let pid = cvt_r(|| unsafe { libc::waitpid(self.pid, &mut status, libc::WNOHANG) })?;
+        if pid == 0 {
+            Ok(None)
+        } else {
+            self.status = Some(ExitStatus(status));
+            Ok(Some(ExitStatus(status)))
+        }
+    }
+
+    pub fn wait_timeout(&mut self, dur: Duration) -> io::Result<Option<ExitStatus>> {
+        // There is no `waitpid` with a timeout, and catching SIGCHLD would
+        // replace any handler the program installed itself, so instead poll
+        // with `WNOHANG`, backing off up to a small interval between polls.
+        let deadline = Instant::now() + dur;
+        let mut delay = Duration::from_millis(1);
+        loop {
+            if let Some(status) = self.try_wait()? {
+                return Ok(Some(status))
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                return Ok(None)
+            }
+            thread::sleep(cmp::min(delay, deadline - now));
+            delay = cmp::min(delay * 2, Duration::from_millis(50));
+        }
+    }
 }
 
 #[cfg(test)]
@@ -33,6 +33,7 @@ use ptr;
 use sync::StaticMutex;
 use sys::c;
 use sys::dynamic_lib::DynamicLibrary;
+use sys_common::backtrace::{Frame, Symbol};
 
 macro_rules! sym {
     ($lib:expr, $e:expr, $t:ident) => (
@@ -98,10 +99,11 @@ impl Drop for Cleanup {
     }
 }
 
+// According to windows documentation, all dbghelp functions are
+// single-threaded.
+static LOCK: StaticMutex = StaticMutex::new();
+
 pub fn write(w: &mut Write) -> io::Result<()> {
-    // According to windows documentation, all dbghelp functions are
-    // single-threaded.
-    static LOCK: StaticMutex = StaticMutex::new();
     let _g = LOCK.lock();
 
     let dbghelp = match DynamicLibrary::open("dbghelp.dll") {
@@ -151,3 +153,71 @@ pub fn write(w: &mut Write) -> io::Result<()> {
         Ok(())
     }
 }
+
+#[inline(never)]
+pub fn trace(frames: &mut Vec<Frame>) -> io::Result<()> {
+    let _g = LOCK.lock();
+
+    let dbghelp = match DynamicLibrary::open("dbghelp.dll") {
+        Ok(lib) => lib,
+        Err(..) => return Ok(()),
+    };
+    unsafe {
+        let SymInitialize = sym!(dbghelp, "SymInitialize", SymInitializeFn);
+        let SymCleanup = sym!(dbghelp, "SymCleanup", SymCleanupFn);
+        let StackWalk64 = sym!(dbghelp, "StackWalk64", StackWalk64Fn);
+
+        let process = c::GetCurrentProcess();
+        let thread = c::GetCurrentThread();
+        let mut context: c::CONTEXT = mem::zeroed();
+        c::RtlCaptureContext(&mut context);
+        let mut frame: c::STACKFRAME64 = mem::zeroed();
+        let image = init_frame(&mut frame, &context);
+
+        let ret = SymInitialize(process, ptr::null_mut(), c::TRUE);
+        if ret != c::TRUE { return Ok(()) }
+        let _c = Cleanup { handle: process, SymCleanup: SymCleanup };
+
+        while StackWalk64(image, process, thread, &mut frame, &mut context,
+                          ptr::null_mut(),
+                          ptr::null_mut(),
+                          ptr::null_mut(),
+                          ptr::null_mut()) == c::TRUE {
+            let addr = frame.AddrPC.Offset;
+            if addr == frame.AddrReturn.Offset || addr == 0 ||
+               frame.AddrReturn.Offset == 0 { break }
+
+            let addr = (addr - 1) as usize as *const c_void;
+            frames.push(Frame { exact_position: addr, symbol_addr: addr });
+        }
+
+        Ok(())
+    }
+}
+
+/// Resolves the symbols of each frame in `frames`, calling `cb` with the index
+/// of the frame and each symbol found for it.
+pub fn resolve(frames: &[Frame], cb: &mut FnMut(usize, Symbol)) -> io::Result<()> {
+    let _g = LOCK.lock();
+
+    let dbghelp = match DynamicLibrary::open("dbghelp.dll") {
+        Ok(lib) => lib,
+        Err(..) => return Ok(()),
+    };
+    unsafe {
+        let SymInitialize = sym!(dbghelp, "SymInitialize", SymInitializeFn);
+        let SymCleanup = sym!(dbghelp, "SymCleanup", SymCleanupFn);
+
+        let process = c::GetCurrentProcess();
+        let ret = SymInitialize(process, ptr::null_mut(), c::TRUE);
+        if ret != c::TRUE { return Ok(()) }
+        let _c = Cleanup { handle: process, SymCleanup: SymCleanup };
+
+        for (i, frame) in frames.iter().enumerate() {
+            printing::resolve(frame, &mut |symbol| cb(i, symbol), process,
+                              &dbghelp)?;
+        }
+
+        Ok(())
+    }
+}
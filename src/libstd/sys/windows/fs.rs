@@ -13,7 +13,7 @@ use os::windows::prelude::*;
 
 use ffi::OsString;
 use fmt;
-use io::{self, Error, SeekFrom};
+use io::{self, Error, IoSlice, IoSliceMut, SeekFrom};
 use mem;
 use path::{Path, PathBuf};
 use ptr;
@@ -271,6 +271,44 @@ impl File {
 
     pub fn datasync(&self) -> io::Result<()> { self.fsync() }
 
+    pub fn lock_shared(&self) -> io::Result<()> {
+        self.lock(0).map(|_| ())
+    }
+
+    pub fn lock_exclusive(&self) -> io::Result<()> {
+        self.lock(c::LOCKFILE_EXCLUSIVE_LOCK).map(|_| ())
+    }
+
+    pub fn try_lock_shared(&self) -> io::Result<bool> {
+        self.lock(c::LOCKFILE_FAIL_IMMEDIATELY)
+    }
+
+    pub fn try_lock_exclusive(&self) -> io::Result<bool> {
+        self.lock(c::LOCKFILE_EXCLUSIVE_LOCK | c::LOCKFILE_FAIL_IMMEDIATELY)
+    }
+
+    pub fn unlock(&self) -> io::Result<()> {
+        cvt(unsafe {
+            c::UnlockFile(self.handle.raw(), 0, 0, <c::DWORD>::max_value(),
+                          <c::DWORD>::max_value())
+        })?;
+        Ok(())
+    }
+
+    // Locks the whole file, however large it may grow.
+    fn lock(&self, flags: c::DWORD) -> io::Result<bool> {
+        let mut overlapped: c::OVERLAPPED = unsafe { mem::zeroed() };
+        let ret = cvt(unsafe {
+            c::LockFileEx(self.handle.raw(), flags, 0, <c::DWORD>::max_value(),
+                          <c::DWORD>::max_value(), &mut overlapped)
+        });
+        match ret {
+            Ok(_) => Ok(true),
+            Err(ref e) if e.raw_os_error() == Some(c::ERROR_LOCK_VIOLATION as i32) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
     pub fn truncate(&self, size: u64) -> io::Result<()> {
         let mut info = c::FILE_END_OF_FILE_INFO {
             EndOfFile: size as c::LARGE_INTEGER,
@@ -312,6 +350,10 @@ impl File {
         self.handle.read(buf)
     }
 
+    pub fn read_vectored(&self, bufs: &mut [IoSliceMut]) -> io::Result<usize> {
+        self.handle.read_vectored(bufs)
+    }
+
     pub fn read_to_end(&self, buf: &mut Vec<u8>) -> io::Result<usize> {
         self.handle.read_to_end(buf)
     }
@@ -320,6 +362,10 @@ impl File {
         self.handle.write(buf)
     }
 
+    pub fn write_vectored(&self, bufs: &[IoSlice]) -> io::Result<usize> {
+        self.handle.write_vectored(bufs)
+    }
+
     pub fn flush(&self) -> io::Result<()> { Ok(()) }
 
     pub fn seek(&self, pos: SeekFrom) -> io::Result<u64> {
@@ -13,13 +13,14 @@
 use prelude::v1::*;
 
 use cmp;
-use io::{ErrorKind, Read};
+use io::{ErrorKind, Read, IoSlice, IoSliceMut};
 use io;
 use mem;
 use ops::Deref;
 use ptr;
 use sys::c;
 use sys::cvt;
+use sys_common::io::{default_read_vectored, default_write_vectored};
 use sys_common::io::read_to_end_uninitialized;
 use u32;
 
@@ -159,6 +160,10 @@ impl RawHandle {
         }
     }
 
+    pub fn read_vectored(&self, bufs: &mut [IoSliceMut]) -> io::Result<usize> {
+        default_read_vectored(|buf| self.read(buf), bufs)
+    }
+
     pub fn read_to_end(&self, buf: &mut Vec<u8>) -> io::Result<usize> {
         let mut me = self;
         (&mut me).read_to_end(buf)
@@ -176,6 +181,10 @@ impl RawHandle {
         Ok(amt as usize)
     }
 
+    pub fn write_vectored(&self, bufs: &[IoSlice]) -> io::Result<usize> {
+        default_write_vectored(|buf| self.write(buf), bufs)
+    }
+
     pub fn duplicate(&self, access: c::DWORD, inherit: bool,
                      options: c::DWORD) -> io::Result<Handle> {
         let mut ret = 0 as c::HANDLE;
@@ -27,6 +27,7 @@ pub mod dynamic_lib;
 pub mod ext;
 pub mod fs;
 pub mod handle;
+pub mod io;
 pub mod mutex;
 pub mod net;
 pub mod os;
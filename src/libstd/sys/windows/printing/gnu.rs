@@ -13,6 +13,7 @@ use io;
 use libc::c_void;
 use sys::c;
 use sys::dynamic_lib::DynamicLibrary;
+use sys_common::backtrace::{Frame, Symbol};
 use sys_common::gnu::libbacktrace;
 
 pub fn print(w: &mut Write,
@@ -24,3 +25,11 @@ pub fn print(w: &mut Write,
     let addr = addr as usize as *mut c_void;
     libbacktrace::print(w, i, addr, addr)
 }
+
+pub fn resolve(frame: &Frame,
+               cb: &mut FnMut(Symbol),
+               _process: c::HANDLE,
+               _dbghelp: &DynamicLibrary)
+               -> io::Result<()> {
+    libbacktrace::resolve(frame, cb)
+}
@@ -15,7 +15,7 @@ use libc::{c_ulong, c_int, c_char, c_void};
 use mem;
 use sys::c;
 use sys::dynamic_lib::DynamicLibrary;
-use sys_common::backtrace::{output, output_fileline};
+use sys_common::backtrace::{output, output_fileline, Frame, Symbol};
 
 type SymFromAddrFn =
     unsafe extern "system" fn(c::HANDLE, u64, *mut u64,
@@ -71,3 +71,46 @@ pub fn print(w: &mut Write,
         }
     }
 }
+
+pub fn resolve(frame: &Frame,
+               cb: &mut FnMut(Symbol),
+               process: c::HANDLE,
+               dbghelp: &DynamicLibrary)
+               -> io::Result<()> {
+    unsafe {
+        let SymFromAddr = sym!(dbghelp, "SymFromAddr", SymFromAddrFn);
+        let SymGetLineFromAddr64 = sym!(dbghelp,
+                                        "SymGetLineFromAddr64",
+                                        SymGetLineFromAddr64Fn);
+        let addr = frame.exact_position as usize as u64;
+
+        let mut info: c::SYMBOL_INFO = mem::zeroed();
+        info.MaxNameLen = c::MAX_SYM_NAME as c_ulong;
+        // see `print` for where this comes from
+        info.SizeOfStruct = 88;
+
+        let mut displacement = 0u64;
+        let ret = SymFromAddr(process, addr, &mut displacement, &mut info);
+        let name = if ret == c::TRUE {
+            let ptr = info.Name.as_ptr() as *const c_char;
+            Some(CStr::from_ptr(ptr).to_bytes().to_vec())
+        } else {
+            None
+        };
+
+        let mut line: c::IMAGEHLP_LINE64 = mem::zeroed();
+        line.SizeOfStruct = ::mem::size_of::<c::IMAGEHLP_LINE64>() as u32;
+
+        let mut displacement = 0u32;
+        let ret = SymGetLineFromAddr64(process, addr, &mut displacement, &mut line);
+        let (filename, lineno) = if ret == c::TRUE {
+            (Some(CStr::from_ptr(line.Filename).to_bytes().to_vec()),
+             Some(line.LineNumber as u32))
+        } else {
+            (None, None)
+        };
+
+        cb(Symbol { name: name, filename: filename, lineno: lineno });
+        Ok(())
+    }
+}
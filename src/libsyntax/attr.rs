@@ -27,6 +27,8 @@ use parse::{ParseSess, token};
 use ptr::P;
 use util::ThinVec;
 
+use rustc_i128::u128;
+
 use std::cell::{RefCell, Cell};
 use std::collections::HashSet;
 
@@ -724,7 +726,8 @@ pub fn require_unique_names(diagnostic: &Handler, metas: &[P<MetaItem>]) {
 /// Valid repr contents: any of the primitive integral type names (see
 /// `int_type_of_word`, below) to specify enum discriminant type; `C`, to use
 /// the same discriminant size that the corresponding C enum would or C
-/// structure layout, and `packed` to remove padding.
+/// structure layout, `packed` to remove padding, and `align(N)` to raise the
+/// alignment of the type to `N` bytes.
 pub fn find_repr_attrs(diagnostic: &Handler, attr: &Attribute) -> Vec<ReprAttr> {
     let mut acc = Vec::new();
     match attr.node.value.node {
@@ -754,6 +757,13 @@ pub fn find_repr_attrs(diagnostic: &Handler, attr: &Attribute) -> Vec<ReprAttr>
                             None => { }
                         }
                     }
+                    ast::MetaItemKind::NameValue(ref name, ref value) if name == "align" => {
+                        match parse_alignment(value) {
+                            Ok(align) => acc.push(ReprAlign(align)),
+                            Err(msg) => span_err!(diagnostic, item.span, E0565,
+                                                  "invalid `repr(align)` attribute: {}", msg),
+                        }
+                    }
                     // Not a word:
                     _ => span_err!(diagnostic, item.span, E0553,
                                    "unrecognized enum representation hint"),
@@ -766,6 +776,24 @@ pub fn find_repr_attrs(diagnostic: &Handler, attr: &Attribute) -> Vec<ReprAttr>
     acc
 }
 
+/// The largest alignment, in bytes, that can be requested with
+/// `#[repr(align(N))]`.
+pub const MAX_REPR_ALIGN: u64 = 1 << 15;
+
+fn parse_alignment(value: &ast::Lit) -> Result<u64, String> {
+    let align = match value.node {
+        ast::LitKind::Int(align, ast::LitIntType::Unsuffixed) => align,
+        _ => return Err("expected an unsuffixed integer".to_string()),
+    };
+    if !align.is_power_of_two() {
+        Err(format!("`{}` is not a power of two", align))
+    } else if align > MAX_REPR_ALIGN as u128 {
+        Err(format!("`{}` is larger than {}", align, MAX_REPR_ALIGN))
+    } else {
+        Ok(align as u64)
+    }
+}
+
 fn int_type_of_word(s: &str) -> Option<IntType> {
     match s {
         "i8" => Some(SignedInt(ast::IntTy::I8)),
@@ -789,6 +817,7 @@ pub enum ReprAttr {
     ReprExtern,
     ReprPacked,
     ReprSimd,
+    ReprAlign(u64),
 }
 
 impl ReprAttr {
@@ -799,6 +828,15 @@ impl ReprAttr {
             ReprExtern => true,
             ReprPacked => false,
             ReprSimd => true,
+            ReprAlign(_) => false,
+        }
+    }
+
+    /// True if this hint only affects the alignment of the type.
+    pub fn is_align(&self) -> bool {
+        match *self {
+            ReprAlign(_) => true,
+            _ => false,
         }
     }
 }
@@ -823,6 +861,7 @@ impl IntType {
             SignedInt(ast::IntTy::I16) | UnsignedInt(ast::UintTy::U16) |
             SignedInt(ast::IntTy::I32) | UnsignedInt(ast::UintTy::U32) |
             SignedInt(ast::IntTy::I64) | UnsignedInt(ast::UintTy::U64) => true,
+            SignedInt(ast::IntTy::I128) | UnsignedInt(ast::UintTy::U128) |
             SignedInt(ast::IntTy::Is) | UnsignedInt(ast::UintTy::Us) => false
         }
     }
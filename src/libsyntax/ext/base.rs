@@ -19,6 +19,7 @@ use errors::DiagnosticBuilder;
 use ext;
 use ext::expand;
 use ext::tt::macro_rules;
+use feature_gate;
 use parse;
 use parse::parser;
 use parse::token;
@@ -579,12 +580,20 @@ fn initial_syntax_expander_table<'feat>(ecfg: &expand::ExpansionConfig<'feat>)
 }
 
 pub trait MacroLoader {
-    fn load_crate(&mut self, extern_crate: &ast::Item, allows_macros: bool) -> Vec<ast::MacroDef>;
+    fn load_crate(&mut self, extern_crate: &ast::Item, allows_macros: bool) -> Vec<LoadedMacro>;
+}
+
+/// A macro made available by an `extern crate` item.
+pub enum LoadedMacro {
+    /// A `macro_rules!` definition exported with `#[macro_export]`.
+    Def(ast::MacroDef),
+    /// A custom derive mode registered by a `proc-macro` crate.
+    CustomDerive(String, Box<MultiItemDecorator>),
 }
 
 pub struct DummyMacroLoader;
 impl MacroLoader for DummyMacroLoader {
-    fn load_crate(&mut self, _: &ast::Item, _: bool) -> Vec<ast::MacroDef> {
+    fn load_crate(&mut self, _: &ast::Item, _: bool) -> Vec<LoadedMacro> {
         Vec::new()
     }
 }
@@ -604,6 +613,7 @@ pub struct ExtCtxt<'a> {
     pub exported_macros: Vec<ast::MacroDef>,
 
     pub syntax_env: SyntaxEnv,
+    pub derive_modes: HashSet<InternedString>,
     pub recursion_count: usize,
 
     pub filename: Option<String>,
@@ -627,6 +637,7 @@ impl<'a> ExtCtxt<'a> {
             exported_macros: Vec::new(),
             loader: loader,
             syntax_env: env,
+            derive_modes: HashSet::new(),
             recursion_count: 0,
 
             filename: None,
@@ -725,6 +736,23 @@ impl<'a> ExtCtxt<'a> {
         }
     }
 
+    pub fn insert_custom_derive(&mut self, name: &str, ext: Box<MultiItemDecorator>, sp: Span) {
+        if !self.ecfg.enable_proc_macro() {
+            feature_gate::emit_feature_err(&self.parse_sess.span_diagnostic,
+                                           "proc_macro",
+                                           sp,
+                                           feature_gate::GateIssue::Language,
+                                           "loading custom derive macro crates \
+                                            is experimentally supported");
+        }
+        let name = token::intern_and_get_ident(name);
+        if !self.derive_modes.insert(name.clone()) {
+            self.span_err(sp, &format!("cannot shadow existing derive mode `{}`", name));
+        }
+        let mname = intern(&format!("derive_{}", name));
+        self.syntax_env.insert(mname, MultiDecorator(ext));
+    }
+
     pub fn struct_span_warn(&self,
                             sp: Span,
                             msg: &str)
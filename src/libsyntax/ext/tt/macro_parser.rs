@@ -135,6 +135,8 @@ pub struct MatcherPos {
     stack: Vec<MatcherTtFrame>,
     top_elts: TokenTreeOrTokenTreeVec,
     sep: Option<Token>,
+    /// The repetition operator of the sequence being matched, if any
+    seq_op: Option<tokenstream::KleeneOp>,
     idx: usize,
     up: Option<Box<MatcherPos>>,
     matches: Vec<Vec<Rc<NamedMatch>>>,
@@ -169,6 +171,7 @@ pub fn initial_matcher_pos(ms: Rc<Vec<TokenTree>>, sep: Option<Token>, lo: ByteP
         stack: vec![],
         top_elts: TtSeq(ms),
         sep: sep,
+        seq_op: None,
         idx: 0,
         up: None,
         matches: matches,
@@ -350,6 +353,8 @@ pub fn parse(sess: &ParseSess,
 
                     // the *_t vars are workarounds for the lack of unary move
                     match ei.sep {
+                        // `$(...)?` never repeats
+                        _ if ei.seq_op == Some(tokenstream::KleeneOp::ZeroOrOne) => {}
                         Some(ref t) if idx == len => { // we need a separator
                             // i'm conflicted about whether this should be hygienic....
                             // though in this case, if the separators are never legal
@@ -375,7 +380,7 @@ pub fn parse(sess: &ParseSess,
                 match ei.top_elts.get_tt(idx) {
                     /* need to descend into sequence */
                     TokenTree::Sequence(sp, seq) => {
-                        if seq.op == tokenstream::KleeneOp::ZeroOrMore {
+                        if seq.op != tokenstream::KleeneOp::OneOrMore {
                             let mut new_ei = ei.clone();
                             new_ei.match_cur += seq.num_captures;
                             new_ei.idx += 1;
@@ -393,6 +398,7 @@ pub fn parse(sess: &ParseSess,
                         cur_eis.push(Box::new(MatcherPos {
                             stack: vec![],
                             sep: seq.separator.clone(),
+                            seq_op: Some(seq.op),
                             idx: 0,
                             matches: matches,
                             match_lo: ei_t.match_cur,
@@ -403,11 +409,23 @@ pub fn parse(sess: &ParseSess,
                             top_elts: Tt(TokenTree::Sequence(sp, seq)),
                         }));
                     }
-                    TokenTree::Token(_, MatchNt(..)) => {
+                    TokenTree::Token(_, MatchNt(_, frag)) => {
                         // Built-in nonterminals never start with these tokens,
                         // so we can eliminate them from consideration.
                         match tok {
-                            token::CloseDelim(_) => {},
+                            token::CloseDelim(_) | token::Eof => {
+                                // ...except `vis`, which may match nothing at all.
+                                if frag.name.as_str() == "vis" {
+                                    let mut ei_t = ei;
+                                    let match_cur = ei_t.match_cur;
+                                    let vis = token::NtVis(ast::Visibility::Inherited);
+                                    (&mut ei_t.matches[match_cur])
+                                        .push(Rc::new(MatchedNonterminal(vis)));
+                                    ei_t.idx += 1;
+                                    ei_t.match_cur += 1;
+                                    cur_eis.push(ei_t);
+                                }
+                            }
                             _ => bb_eis.push(ei),
                         }
                     }
@@ -550,6 +568,8 @@ pub fn parse_nt<'a>(p: &mut Parser<'a>, sp: Span, name: &str) -> Nonterminal {
             token::NtPath(Box::new(panictry!(p.parse_path(PathStyle::Type))))
         },
         "meta" => token::NtMeta(panictry!(p.parse_meta_item())),
+        "vis" => token::NtVis(panictry!(p.parse_visibility(true))),
+        "lifetime" => token::NtLifetime(panictry!(p.parse_lifetime())),
         // this is not supposed to happen, since it has been checked
         // when compiling the macro.
         _ => p.span_bug(sp, "invalid fragment specifier")
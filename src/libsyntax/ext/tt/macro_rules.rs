@@ -15,6 +15,7 @@ use ext::base::{NormalTT, TTMacroExpander};
 use ext::tt::macro_parser::{Success, Error, Failure};
 use ext::tt::macro_parser::{MatchedSeq, MatchedNonterminal};
 use ext::tt::macro_parser::parse;
+use feature_gate::{self, emit_feature_err};
 use parse::lexer::new_tt_reader;
 use parse::parser::{Parser, Restrictions};
 use parse::token::{self, gensym_ident, NtTT, Token};
@@ -306,6 +307,7 @@ pub fn compile<'cx>(cx: &'cx mut ExtCtxt,
         MatchedSeq(ref s, _) => {
             s.iter().map(|m| match **m {
                 MatchedNonterminal(NtTT(ref tt)) => {
+                    check_macro_features(cx, tt);
                     valid &= check_lhs_nt_follows(cx, tt);
                     (**tt).clone()
                 }
@@ -318,7 +320,10 @@ pub fn compile<'cx>(cx: &'cx mut ExtCtxt,
     let rhses = match **argument_map.get(&rhs_nm.name).unwrap() {
         MatchedSeq(ref s, _) => {
             s.iter().map(|m| match **m {
-                MatchedNonterminal(NtTT(ref tt)) => (**tt).clone(),
+                MatchedNonterminal(NtTT(ref tt)) => {
+                    check_macro_features(cx, tt);
+                    (**tt).clone()
+                }
                 _ => cx.span_bug(def.span, "wrong-structured rhs")
             }).collect()
         }
@@ -355,6 +360,48 @@ fn check_lhs_nt_follows(cx: &mut ExtCtxt, lhs: &TokenTree) -> bool {
     // after parsing/expansion. we can report every error in every macro this way.
 }
 
+/// Reports the unstable fragment specifiers and repetition operators used
+/// in `tt` whose features have not been enabled.
+fn check_macro_features(cx: &ExtCtxt, tt: &TokenTree) {
+    let features = match cx.ecfg.features {
+        Some(features) => features,
+        None => return,
+    };
+    let gate = |feature, span, explain| {
+        emit_feature_err(&cx.parse_sess.span_diagnostic, feature, span,
+                         feature_gate::GateIssue::Language, explain);
+    };
+    match *tt {
+        TokenTree::Token(sp, MatchNt(_, ref frag)) => {
+            match &*frag.name.as_str() {
+                "vis" if !features.macro_vis_matcher => {
+                    gate("macro_vis_matcher", sp, "the `vis` fragment specifier is experimental")
+                }
+                "lifetime" if !features.macro_lifetime_matcher => {
+                    gate("macro_lifetime_matcher", sp,
+                         "the `lifetime` fragment specifier is experimental")
+                }
+                _ => {}
+            }
+        }
+        TokenTree::Token(..) => {}
+        TokenTree::Delimited(_, ref delimed) => {
+            for tt in &delimed.tts {
+                check_macro_features(cx, tt);
+            }
+        }
+        TokenTree::Sequence(sp, ref seq) => {
+            if seq.op == tokenstream::KleeneOp::ZeroOrOne && !features.macro_at_most_once_rep {
+                gate("macro_at_most_once_rep", sp,
+                     "the `?` macro repetition operator is experimental");
+            }
+            for tt in &seq.tts {
+                check_macro_features(cx, tt);
+            }
+        }
+    }
+}
+
 fn check_rhs(cx: &mut ExtCtxt, rhs: &TokenTree) -> bool {
     match *rhs {
         TokenTree::Delimited(..) => return true,
@@ -441,7 +488,8 @@ impl FirstSets {
                         }
 
                         // Reverse scan: Sequence comes before `first`.
-                        if subfirst.maybe_empty || seq_rep.op == tokenstream::KleeneOp::ZeroOrMore {
+                        if subfirst.maybe_empty ||
+                           seq_rep.op != tokenstream::KleeneOp::OneOrMore {
                             // If sequence is potentially empty, then
                             // union them (preserving first emptiness).
                             first.add_all(&TokenSet { maybe_empty: true, ..subfirst });
@@ -489,7 +537,7 @@ impl FirstSets {
                             assert!(first.maybe_empty);
                             first.add_all(subfirst);
                             if subfirst.maybe_empty ||
-                               seq_rep.op == tokenstream::KleeneOp::ZeroOrMore {
+                               seq_rep.op != tokenstream::KleeneOp::OneOrMore {
                                 // continue scanning for more first
                                 // tokens, but also make sure we
                                 // restore empty-tracking state
@@ -640,8 +688,8 @@ fn check_matcher_core(cx: &mut ExtCtxt,
                 if let Err(bad_frag) = has_legal_fragment_specifier(tok) {
                     cx.struct_span_err(sp, &format!("invalid fragment specifier `{}`", bad_frag))
                         .help("valid fragment specifiers are `ident`, `block`, \
-                               `stmt`, `expr`, `pat`, `ty`, `path`, `meta`, `tt` \
-                               and `item`")
+                               `stmt`, `expr`, `pat`, `ty`, `path`, `meta`, `tt`, \
+                               `item`, `vis` and `lifetime`")
                         .emit();
                     // (This eliminates false positives and duplicates
                     // from error messages.)
@@ -773,6 +821,7 @@ fn frag_can_be_followed_by_any(frag: &str) -> bool {
         "block" | // exactly one token tree
         "ident" | // exactly one token tree
         "meta"  | // exactly one token tree
+        "lifetime" | // exactly one token tree
         "tt" =>   // exactly one token tree
             true,
 
@@ -838,10 +887,30 @@ fn is_in_follow(_: &ExtCtxt, tok: &Token, frag: &str) -> Result<bool, (String, &
                 // harmless
                 Ok(true)
             },
+            "lifetime" => {
+                // being a single token, lifetimes are harmless
+                Ok(true)
+            },
+            "vis" => {
+                // `pub(...)` would make a following `(` ambiguous, and `priv`
+                // is still reserved, but otherwise a visibility is followed
+                // by whatever it qualifies
+                match *tok {
+                    Comma => Ok(true),
+                    Ident(i) => Ok(i.name.as_str() != "priv"),
+                    MatchNt(_, ref frag) => {
+                        let frag = frag.name.as_str();
+                        Ok(frag == "ident" || frag == "ty" || frag == "path")
+                    }
+                    OpenDelim(token::DelimToken::Bracket) | BinOp(token::And) | AndAnd |
+                    BinOp(token::Star) | Lt | ModSep => Ok(true),
+                    _ => Ok(false)
+                }
+            },
             _ => Err((format!("invalid fragment specifier `{}`", frag),
                      "valid fragment specifiers are `ident`, `block`, \
-                      `stmt`, `expr`, `pat`, `ty`, `path`, `meta`, `tt` \
-                      and `item`"))
+                      `stmt`, `expr`, `pat`, `ty`, `path`, `meta`, `tt`, \
+                      `item`, `vis` and `lifetime`"))
         }
     }
 }
@@ -860,7 +929,8 @@ fn has_legal_fragment_specifier(tok: &Token) -> Result<(), String> {
 fn is_legal_fragment_specifier(frag: &str) -> bool {
     match frag {
         "item" | "block" | "stmt" | "expr" | "pat" |
-        "path" | "ty" | "ident" | "meta" | "tt" => true,
+        "path" | "ty" | "ident" | "meta" | "tt" |
+        "vis" | "lifetime" => true,
         _ => false,
     }
 }
@@ -14,7 +14,7 @@ use syntax_pos::{Span, DUMMY_SP};
 use errors::{Handler, DiagnosticBuilder};
 use ext::tt::macro_parser::{NamedMatch, MatchedSeq, MatchedNonterminal};
 use parse::token::{DocComment, MatchNt, SubstNt};
-use parse::token::{Token, NtIdent, SpecialMacroVar};
+use parse::token::{Token, NtIdent, NtLifetime, SpecialMacroVar};
 use parse::token;
 use parse::lexer::TokenAndSpan;
 use tokenstream::{self, TokenTree};
@@ -265,6 +265,11 @@ pub fn tt_next_token(r: &mut TtReader) -> TokenAndSpan {
                             r.stack.last_mut().unwrap().idx += 1;
                             return tt_next_token(r);
                         }
+                        if len > 1 && seq.op == tokenstream::KleeneOp::ZeroOrOne {
+                            // FIXME #2887 blame invoker
+                            panic!(r.sp_diag.span_fatal(sp.clone(),
+                                                 "this must repeat at most once"));
+                        }
                         r.repeat_len.push(len);
                         r.repeat_idx.push(0);
                         r.stack.push(TtFrame {
@@ -296,6 +301,12 @@ pub fn tt_next_token(r: &mut TtReader) -> TokenAndSpan {
                                 r.cur_tok = token::Ident(sn.node);
                                 return ret_val;
                             }
+                            // likewise for lifetimes, which are also single tokens
+                            MatchedNonterminal(NtLifetime(ref lt)) => {
+                                r.cur_span = lt.span;
+                                r.cur_tok = token::Lifetime(Ident::with_empty_ctxt(lt.name));
+                                return ret_val;
+                            }
                             MatchedNonterminal(ref other_whole_nt) => {
                                 // FIXME(pcwalton): Bad copy.
                                 r.cur_span = sp;
@@ -277,7 +277,40 @@ declare_features! (
     (active, cfg_target_has_atomic, "1.9.0", Some(32976)),
 
     // Allows `..` in tuple (struct) patterns
-    (active, dotdot_in_tuple_patterns, "1.10.0", Some(33627))
+    (active, dotdot_in_tuple_patterns, "1.10.0", Some(33627)),
+
+    // Allows untagged unions `union U { ... }`
+    (active, untagged_unions, "1.12.0", Some(32836)),
+
+    // The `i128` and `u128` types
+    (active, i128_type, "1.12.0", Some(35118)),
+
+    // Allows `impl Trait` in function return types.
+    (active, conservative_impl_trait, "1.12.0", Some(34511)),
+
+    // Allows the `proc-macro` crate type and `#[proc_macro_derive]`
+    (active, proc_macro, "1.13.0", Some(35900)),
+
+    // Allows `#[repr(align(N))]` to raise the alignment of a type
+    (active, repr_align, "1.13.0", None),
+
+    // Allows `break` with a value inside `loop`
+    (active, loop_break_value, "1.13.0", None),
+
+    // Allows `#[derive(Default)]` on enums with a `#[default]` variant
+    (active, derive_default_enum, "1.13.0", None),
+
+    // Allows the `vis` fragment specifier in `macro_rules!` matchers
+    (active, macro_vis_matcher, "1.13.0", None),
+
+    // Allows the `lifetime` fragment specifier in `macro_rules!` matchers
+    (active, macro_lifetime_matcher, "1.13.0", None),
+
+    // Allows the `$(...)?` zero-or-one repetition in `macro_rules!`
+    (active, macro_at_most_once_rep, "1.13.0", None),
+
+    // Allows `#![stack_frame_limit]` and `#![move_size_limit]`
+    (active, stack_size_limits, "1.13.0", None)
 );
 
 declare_features! (
@@ -391,6 +424,13 @@ pub const KNOWN_ATTRIBUTES: &'static [(&'static str, AttributeType, AttributeGat
     ("no_mangle", Normal, Ungated),
     ("no_link", Normal, Ungated),
     ("derive", Normal, Ungated),
+    ("proc_macro_derive", Normal, Gated("proc_macro",
+                                        "the `#[proc_macro_derive]` attribute \
+                                         is an experimental feature",
+                                        cfg_fn!(proc_macro))),
+    ("default", Normal, Gated("derive_default_enum",
+                              "the `#[default]` attribute is an experimental feature",
+                              cfg_fn!(derive_default_enum))),
     ("should_panic", Normal, Ungated),
     ("ignore", Normal, Ungated),
     ("no_implicit_prelude", Normal, Ungated),
@@ -416,6 +456,14 @@ pub const KNOWN_ATTRIBUTES: &'static [(&'static str, AttributeType, AttributeGat
     ("no_core", CrateLevel, Gated("no_core",
                                   "no_core is experimental",
                                   cfg_fn!(no_core))),
+    ("stack_frame_limit", CrateLevel, Gated("stack_size_limits",
+                                            "the `stack_frame_limit` attribute \
+                                             is experimental",
+                                            cfg_fn!(stack_size_limits))),
+    ("move_size_limit", CrateLevel, Gated("stack_size_limits",
+                                          "the `move_size_limit` attribute \
+                                           is experimental",
+                                          cfg_fn!(stack_size_limits))),
     ("lang", Normal, Gated("lang_items",
                            "language items are subject to change",
                            cfg_fn!(lang_items))),
@@ -502,6 +550,9 @@ pub const KNOWN_ATTRIBUTES: &'static [(&'static str, AttributeType, AttributeGat
                                          is just used to make tests pass \
                                          and will never be stable",
                                         cfg_fn!(rustc_attrs))),
+    ("rustc_derive_registrar", Normal, Gated("rustc_attrs",
+                                             "used internally by rustc",
+                                             cfg_fn!(rustc_attrs))),
     ("rustc_inherit_overflow_checks", Whitelisted, Gated("rustc_attrs",
                                                          "the `#[rustc_inherit_overflow_checks]` \
                                                           attribute is just used to control \
@@ -815,6 +866,18 @@ impl<'a> Visitor for PostExpansionVisitor<'a> {
     }
 
     fn visit_item(&mut self, i: &ast::Item) {
+        for attr in &i.attrs {
+            if attr.name() == "repr" {
+                for item in attr.meta_item_list().unwrap_or(&[]) {
+                    if item.name() == "align" {
+                        gate_feature_post!(&self, repr_align, i.span,
+                                           "`#[repr(align(N))]` is experimental \
+                                            and possibly buggy");
+                    }
+                }
+            }
+        }
+
         match i.node {
             ast::ItemKind::ExternCrate(_) => {
                 if attr::contains_name(&i.attrs[..], "macro_reexport") {
@@ -889,6 +952,12 @@ impl<'a> Visitor for PostExpansionVisitor<'a> {
                 }
             }
 
+            ast::ItemKind::Union(..) => {
+                gate_feature_post!(&self, untagged_unions,
+                                   i.span,
+                                   "unions are unstable and possibly buggy");
+            }
+
             ast::ItemKind::DefaultImpl(..) => {
                 gate_feature_post!(&self, optin_builtin_traits,
                                    i.span,
@@ -928,6 +997,14 @@ impl<'a> Visitor for PostExpansionVisitor<'a> {
         visit::walk_foreign_item(self, i)
     }
 
+    fn visit_ty(&mut self, ty: &ast::Ty) {
+        if let ast::TyKind::ImplTrait(..) = ty.node {
+            gate_feature_post!(&self, conservative_impl_trait, ty.span,
+                               "`impl Trait` is experimental");
+        }
+        visit::walk_ty(self, ty);
+    }
+
     fn visit_expr(&mut self, e: &ast::Expr) {
         match e.node {
             ast::ExprKind::Box(_) => {
@@ -948,6 +1025,22 @@ impl<'a> Visitor for PostExpansionVisitor<'a> {
             ast::ExprKind::InPlace(..) => {
                 gate_feature_post!(&self, placement_in_syntax, e.span, EXPLAIN_PLACEMENT_IN);
             }
+            ast::ExprKind::Break(_, Some(_)) => {
+                gate_feature_post!(&self, loop_break_value, e.span,
+                                   "`break` with a value is experimental");
+            }
+            ast::ExprKind::Lit(ref lit) => {
+                if let ast::LitKind::Int(_, ref ty) = lit.node {
+                    match *ty {
+                        ast::LitIntType::Signed(ast::IntTy::I128) |
+                        ast::LitIntType::Unsigned(ast::UintTy::U128) => {
+                            gate_feature_post!(&self, i128_type, e.span,
+                                               "128-bit integers are not stable");
+                        }
+                        _ => {}
+                    }
+                }
+            }
             _ => {}
         }
         visit::walk_expr(self, e);
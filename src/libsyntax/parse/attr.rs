@@ -155,6 +155,13 @@ impl<'a> Parser<'a> {
     /// | IDENT = lit
     /// | IDENT meta_seq
     pub fn parse_meta_item(&mut self) -> PResult<'a, P<ast::MetaItem>> {
+        self.parse_meta_item_in(None)
+    }
+
+    /// Parses a meta item nested directly inside the list of the meta item
+    /// named `parent`, if any.
+    fn parse_meta_item_in(&mut self, parent: Option<&token::InternedString>)
+                          -> PResult<'a, P<ast::MetaItem>> {
         let nt_meta = match self.token {
             token::Interpolated(token::NtMeta(ref e)) => Some(e.clone()),
             _ => None,
@@ -184,8 +191,14 @@ impl<'a> Parser<'a> {
                 let hi = self.span.hi;
                 Ok(P(spanned(lo, hi, ast::MetaItemKind::NameValue(name, lit))))
             }
+            token::OpenDelim(token::Paren) if name == "align" &&
+                                              parent.map_or(false, |p| *p == "repr") => {
+                let lit = self.parse_repr_align()?;
+                let hi = self.last_span.hi;
+                Ok(P(spanned(lo, hi, ast::MetaItemKind::NameValue(name, lit))))
+            }
             token::OpenDelim(token::Paren) => {
-                let inner_items = self.parse_meta_seq()?;
+                let inner_items = self.parse_meta_seq(&name)?;
                 let hi = self.span.hi;
                 Ok(P(spanned(lo, hi, ast::MetaItemKind::List(name, inner_items))))
             }
@@ -197,10 +210,22 @@ impl<'a> Parser<'a> {
     }
 
     /// matches meta_seq = ( COMMASEP(meta_item) )
-    fn parse_meta_seq(&mut self) -> PResult<'a, Vec<P<ast::MetaItem>>> {
+    fn parse_meta_seq(&mut self, parent: &token::InternedString)
+                      -> PResult<'a, Vec<P<ast::MetaItem>>> {
         self.parse_unspanned_seq(&token::OpenDelim(token::Paren),
                                  &token::CloseDelim(token::Paren),
                                  SeqSep::trailing_allowed(token::Comma),
-                                 |p: &mut Parser<'a>| p.parse_meta_item())
+                                 |p: &mut Parser<'a>| p.parse_meta_item_in(Some(parent)))
+    }
+
+    /// matches repr_align = ( lit )
+    ///
+    /// The argument of `align` in `#[repr(align(N))]` is a literal rather
+    /// than a meta item, so it is kept as the value of a `NameValue` item.
+    fn parse_repr_align(&mut self) -> PResult<'a, ast::Lit> {
+        self.expect(&token::OpenDelim(token::Paren))?;
+        let lit = self.parse_lit()?;
+        self.expect(&token::CloseDelim(token::Paren))?;
+        Ok(lit)
     }
 }
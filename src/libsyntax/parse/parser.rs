@@ -64,6 +64,8 @@ use std::path::{Path, PathBuf};
 use std::rc::Rc;
 use std::slice;
 
+use rustc_i128::u128;
+
 bitflags! {
     flags Restrictions: u8 {
         const RESTRICTION_STMT_EXPR         = 1 << 0,
@@ -1406,6 +1408,14 @@ impl<'a> Parser<'a> {
             self.parse_borrowed_pointee()?
         } else if self.check_keyword(keywords::For) {
             self.parse_for_in_type()?
+        } else if self.eat_keyword(keywords::Impl) {
+            // IMPL TRAIT
+            let bounds = self.parse_ty_param_bounds(BoundParsingMode::Bare)?;
+            if !bounds.iter().any(|b| if let TraitTyParamBound(..) = *b { true } else { false }) {
+                let last_span = self.last_span;
+                self.span_err(last_span, "at least one trait must be specified");
+            }
+            TyKind::ImplTrait(bounds)
         } else if self.token_is_bare_fn_keyword() {
             // BARE FUNCTION
             self.parse_ty_bare_fn(Vec::new())?
@@ -1899,6 +1909,8 @@ impl<'a> Parser<'a> {
     /// Parses a single lifetime
     /// Matches lifetime = LIFETIME
     pub fn parse_lifetime(&mut self) -> PResult<'a, ast::Lifetime> {
+        maybe_whole!(self, NtLifetime);
+
         match self.token {
             token::Lifetime(i) => {
                 let span = self.span;
@@ -2079,7 +2091,7 @@ impl<'a> Parser<'a> {
     pub fn mk_lit_u32(&mut self, i: u32, attrs: ThinVec<Attribute>) -> P<Expr> {
         let span = &self.span;
         let lv_lit = P(codemap::Spanned {
-            node: LitKind::Int(i as u64, ast::LitIntType::Unsigned(UintTy::U32)),
+            node: LitKind::Int(i as u128, ast::LitIntType::Unsigned(UintTy::U32)),
             span: *span
         });
 
@@ -2275,15 +2287,25 @@ impl<'a> Parser<'a> {
                         ex = ExprKind::Ret(None);
                     }
                 } else if self.eat_keyword(keywords::Break) {
-                    if self.token.is_lifetime() {
-                        ex = ExprKind::Break(Some(Spanned {
+                    let lt = if self.token.is_lifetime() {
+                        let spanned_lt = Spanned {
                             node: self.get_lifetime(),
                             span: self.span
-                        }));
+                        };
                         self.bump();
+                        Some(spanned_lt)
                     } else {
-                        ex = ExprKind::Break(None);
-                    }
+                        None
+                    };
+                    let e = if self.token.can_begin_expr()
+                               && !(self.token == token::OpenDelim(token::Brace)
+                                    && self.restrictions.contains(
+                                           Restrictions::RESTRICTION_NO_STRUCT_LITERAL)) {
+                        Some(self.parse_expr()?)
+                    } else {
+                        None
+                    };
+                    ex = ExprKind::Break(lt, e);
                     hi = self.last_span.hi;
                 } else if self.token.is_keyword(keywords::Let) {
                     // Catch this syntax error here, instead of in `check_strict_keywords`, so
@@ -2675,7 +2697,7 @@ impl<'a> Parser<'a> {
     }
 
     /// Parse an optional separator followed by a Kleene-style
-    /// repetition token (+ or *).
+    /// repetition token (+, * or ?).
     pub fn parse_sep_and_kleene_op(&mut self)
                                    -> PResult<'a, (Option<token::Token>, tokenstream::KleeneOp)> {
         fn parse_kleene_op<'a>(parser: &mut Parser<'a>) ->
@@ -2689,6 +2711,16 @@ impl<'a> Parser<'a> {
                     parser.bump();
                     Ok(Some(tokenstream::KleeneOp::OneOrMore))
                 },
+                // `?` is the zero-or-one operator, unless it is followed by `*` or `+`. Then
+                // it is still a separator, so existing matchers such as `$(a)?*` keep their
+                // meaning; `?` anywhere else used to be an error. The operator is feature
+                // gated (`macro_at_most_once_rep`) when the macro is compiled.
+                token::Question if !parser.look_ahead(1, |t| {
+                    *t == token::BinOp(token::Star) || *t == token::BinOp(token::Plus)
+                }) => {
+                    parser.bump();
+                    Ok(Some(tokenstream::KleeneOp::ZeroOrOne))
+                },
                 _ => Ok(None)
             }
         };
@@ -2699,8 +2731,11 @@ impl<'a> Parser<'a> {
 
         let separator = self.bump_and_get();
         match parse_kleene_op(self)? {
+            Some(tokenstream::KleeneOp::ZeroOrOne) => {
+                Err(self.fatal("the `?` macro repetition operator does not take a separator"))
+            }
             Some(zerok) => Ok((Some(separator), zerok)),
-            None => return Err(self.fatal("expected `*` or `+`"))
+            None => return Err(self.fatal("expected `*`, `+` or `?`"))
         }
     }
 
@@ -5067,6 +5102,32 @@ impl<'a> Parser<'a> {
         Ok((class_name, ItemKind::Struct(vdata, generics), None))
     }
 
+    /// Parse union Foo { ... }
+    fn parse_item_union(&mut self) -> PResult<'a, ItemInfo> {
+        let union_name = self.parse_ident()?;
+        let mut generics = self.parse_generics()?;
+
+        let vdata = if self.token.is_keyword(keywords::Where) {
+            generics.where_clause = self.parse_where_clause()?;
+            VariantData::Struct(self.parse_record_struct_body()?, ast::DUMMY_NODE_ID)
+        } else if self.token == token::OpenDelim(token::Brace) {
+            VariantData::Struct(self.parse_record_struct_body()?, ast::DUMMY_NODE_ID)
+        } else {
+            let token_str = self.this_token_to_string();
+            return Err(self.fatal(&format!("expected `where` or `{{` after union \
+                                            name, found `{}`", token_str)))
+        };
+
+        Ok((union_name, ItemKind::Union(vdata, generics), None))
+    }
+
+    /// `union` is only a keyword when followed by the name of the union,
+    /// so that its uses as an identifier keep working.
+    fn is_union_item(&mut self) -> bool {
+        self.token.is_keyword(keywords::Union) &&
+            self.look_ahead(1, |t| t.is_ident() && !t.is_any_keyword())
+    }
+
     pub fn parse_record_struct_body(&mut self) -> PResult<'a, Vec<StructField>> {
         let mut fields = Vec::new();
         if self.eat(&token::OpenDelim(token::Brace)) {
@@ -5158,7 +5219,9 @@ impl<'a> Parser<'a> {
     }
 
     // If `allow_path` is false, just parse the `pub` in `pub(path)` (but still parse `pub(crate)`)
-    fn parse_visibility(&mut self, allow_path: bool) -> PResult<'a, Visibility> {
+    pub fn parse_visibility(&mut self, allow_path: bool) -> PResult<'a, Visibility> {
+        maybe_whole!(self, NtVis);
+
         let pub_crate = |this: &mut Self| {
             let span = this.last_span;
             this.expect(&token::CloseDelim(token::Paren))?;
@@ -5890,6 +5953,19 @@ impl<'a> Parser<'a> {
                                     maybe_append(attrs, extra_attrs));
             return Ok(Some(item));
         }
+        if self.is_union_item() {
+            // UNION ITEM
+            self.bump();
+            let (ident, item_, extra_attrs) = self.parse_item_union()?;
+            let last_span = self.last_span;
+            let item = self.mk_item(lo,
+                                    last_span.hi,
+                                    ident,
+                                    item_,
+                                    visibility,
+                                    maybe_append(attrs, extra_attrs));
+            return Ok(Some(item));
+        }
         self.parse_macro_use_or_failure(attrs,macros_allowed,attributes_allowed,lo,visibility)
     }
 
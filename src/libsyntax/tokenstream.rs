@@ -98,6 +98,7 @@ pub struct SequenceRepetition {
 pub enum KleeneOp {
     ZeroOrMore,
     OneOrMore,
+    ZeroOrOne,
 }
 
 /// When the main rust parser encounters a syntax-extension invocation, it
@@ -0,0 +1,118 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Custom derive modes loaded from `proc-macro` crates.
+
+use std::panic;
+
+use errors::FatalError;
+use proc_macro::TokenStream;
+use proc_macro::__internal;
+use syntax::ast::{self, ItemKind};
+use syntax::attr::AttrMetaMethods;
+use syntax::ext::base::*;
+use syntax::fold::{self, Folder};
+use syntax_pos::Span;
+
+/// A `#[derive(Trait)]` expander defined by a `#[proc_macro_derive(Trait)]`
+/// function in a `proc-macro` crate.
+pub struct CustomDerive {
+    inner: fn(TokenStream) -> TokenStream,
+}
+
+impl CustomDerive {
+    pub fn new(inner: fn(TokenStream) -> TokenStream) -> CustomDerive {
+        CustomDerive { inner: inner }
+    }
+}
+
+impl MultiItemDecorator for CustomDerive {
+    fn expand(&self,
+              cx: &mut ExtCtxt,
+              span: Span,
+              _meta_item: &ast::MetaItem,
+              item: &Annotatable,
+              push: &mut FnMut(Annotatable)) {
+        let item = match *item {
+            Annotatable::Item(ref item) => item.clone(),
+            _ => {
+                cx.span_err(span, "custom derive attributes may only be \
+                                   applied to struct/enum items");
+                return
+            }
+        };
+        match item.node {
+            ItemKind::Struct(..) |
+            ItemKind::Enum(..) => {}
+            _ => {
+                cx.span_err(span, "custom derive attributes may only be \
+                                   applied to struct/enum items");
+                return
+            }
+        }
+
+        // Other `#[derive_*]` markers on the item are an implementation
+        // detail of the expander, so don't hand them to the macro.
+        let item = item.map(|mut item| {
+            item.attrs.retain(|a| !a.name().starts_with("derive_"));
+            item
+        });
+        let input = __internal::new_token_stream(item);
+        let res = __internal::set_parse_sess(cx.parse_sess, || {
+            let inner = self.inner;
+            panic::catch_unwind(panic::AssertUnwindSafe(|| inner(input)))
+        });
+        let stream = match res {
+            Ok(stream) => stream,
+            Err(e) => {
+                let mut msg = cx.struct_span_fatal(span, "custom derive attribute panicked");
+                if let Some(s) = e.downcast_ref::<String>() {
+                    msg.help(&format!("message: {}", s));
+                }
+                if let Some(s) = e.downcast_ref::<&'static str>() {
+                    msg.help(&format!("message: {}", s));
+                }
+                msg.emit();
+                panic!(FatalError);
+            }
+        };
+
+        let new_items = __internal::set_parse_sess(cx.parse_sess, || {
+            __internal::token_stream_parse_items(stream)
+        });
+        let new_items = match new_items {
+            Ok(items) => items,
+            Err(_) => {
+                cx.span_fatal(span, "custom derive produced unparseable tokens");
+            }
+        };
+
+        let mut marker = ChangeSpan { span: span };
+        for item in new_items {
+            push(Annotatable::Item(marker.fold_item(item).expect_one("expected one item")));
+        }
+    }
+}
+
+/// Attributes all spans in the generated items to the `#[derive]` expansion,
+/// so that `allow_internal_unstable` and the expansion backtrace apply.
+struct ChangeSpan {
+    span: Span,
+}
+
+impl Folder for ChangeSpan {
+    fn new_span(&mut self, _sp: Span) -> Span {
+        self.span
+    }
+
+    fn fold_mac(&mut self, mac: ast::Mac) -> ast::Mac {
+        fold::noop_fold_mac(mac, self)
+    }
+}
@@ -12,6 +12,7 @@ use deriving::generic::*;
 use deriving::generic::ty::*;
 
 use syntax::ast::{MetaItem, Expr};
+use syntax::attr;
 use syntax::ext::base::{ExtCtxt, Annotatable};
 use syntax::ext::build::AstBuilder;
 use syntax::parse::token::InternedString;
@@ -76,8 +77,39 @@ fn default_substructure(cx: &mut ExtCtxt, trait_span: Span, substr: &Substructur
                 }
             }
         }
-        StaticEnum(..) => {
-            cx.span_err(trait_span, "`Default` cannot be derived for enums, only structs");
+        StaticEnum(enum_def, _) => {
+            let default_variants = enum_def.variants.iter().filter(|variant| {
+                attr::contains_name(&variant.node.attrs, "default")
+            }).collect::<Vec<_>>();
+
+            match default_variants.len() {
+                0 => {
+                    cx.struct_span_err(trait_span, "no default declared for enum")
+                      .help("make a unit variant default by placing `#[default]` above it")
+                      .emit();
+                }
+                1 => {
+                    let variant = default_variants[0];
+                    if variant.node.data.is_unit() {
+                        let path = cx.path(variant.span,
+                                           vec![substr.type_ident, variant.node.name]);
+                        return cx.expr_path(path);
+                    }
+                    cx.struct_span_err(variant.span,
+                                       "the `#[default]` attribute may only be used on \
+                                        unit enum variants")
+                      .help("consider a manual implementation of `Default`")
+                      .emit();
+                }
+                _ => {
+                    let mut err = cx.struct_span_err(trait_span,
+                                                     "multiple declared defaults for enum");
+                    for variant in &default_variants {
+                        err.span_note(variant.span, "marked as default here");
+                    }
+                    err.emit();
+                }
+            }
             // let compilation continue
             cx.expr_usize(trait_span, 0)
         }
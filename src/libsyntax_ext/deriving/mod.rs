@@ -57,6 +57,7 @@ macro_rules! path_std {
 
 pub mod bounds;
 pub mod clone;
+pub mod custom;
 pub mod encodable;
 pub mod decodable;
 pub mod hash;
@@ -106,7 +107,8 @@ fn expand_derive(cx: &mut ExtCtxt,
                     }
                 };
 
-                if !(is_builtin_trait(tname) || cx.ecfg.enable_custom_derive()) {
+                if !(is_builtin_trait(tname) || cx.derive_modes.contains(tname) ||
+                     cx.ecfg.enable_custom_derive()) {
                     feature_gate::emit_feature_err(&cx.parse_sess.span_diagnostic,
                                                    "custom_derive",
                                                    titem.span,
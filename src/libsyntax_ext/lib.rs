@@ -19,11 +19,14 @@
        html_root_url = "https://doc.rust-lang.org/nightly/")]
 #![cfg_attr(not(stage0), deny(warnings))]
 
+#![feature(proc_macro_internals)]
+#![feature(proc_macro_lib)]
 #![feature(rustc_private)]
 #![feature(staged_api)]
 
 extern crate fmt_macros;
 #[macro_use] extern crate log;
+extern crate proc_macro;
 #[macro_use]
 extern crate syntax;
 extern crate syntax_pos;
@@ -43,6 +46,8 @@ mod format;
 mod log_syntax;
 mod trace_macros;
 
+pub mod proc_macro_registrar;
+
 // for custom_derive
 pub mod deriving;
 
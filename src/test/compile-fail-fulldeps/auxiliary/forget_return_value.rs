@@ -0,0 +1,45 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// force-host
+
+#![feature(plugin_registrar, rustc_private)]
+#![feature(box_syntax)]
+
+#[macro_use] extern crate rustc;
+extern crate rustc_plugin;
+
+use rustc::mir::transform::{self, MirPass, MirSource};
+use rustc::mir::repr::{Mir, Lvalue, StatementKind};
+use rustc::ty::TyCtxt;
+use rustc_plugin::Registry;
+
+/// A deliberately broken pass, which drops every assignment to the
+/// return pointer.
+struct ForgetReturnValue;
+
+impl transform::Pass for ForgetReturnValue {}
+
+impl<'tcx> MirPass<'tcx> for ForgetReturnValue {
+    fn run_pass<'a>(&mut self, _: TyCtxt<'a, 'tcx, 'tcx>,
+                    _: MirSource, mir: &mut Mir<'tcx>) {
+        for data in mir.basic_blocks_mut().iter_mut() {
+            data.statements.retain(|stmt| match stmt.kind {
+                StatementKind::Assign(Lvalue::ReturnPointer, _) => false,
+                _ => true,
+            });
+        }
+    }
+}
+
+#[plugin_registrar]
+pub fn plugin_registrar(reg: &mut Registry) {
+    reg.register_mir_pass(box ForgetReturnValue);
+}
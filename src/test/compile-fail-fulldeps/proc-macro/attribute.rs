@@ -0,0 +1,35 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// ignore-stage1
+
+#![crate_type = "proc-macro"]
+#![feature(proc_macro)]
+#![feature(proc_macro_lib)]
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+
+#[proc_macro_derive]
+//~^ ERROR: attribute must be of form: #[proc_macro_derive(TraitName)]
+pub fn foo1(input: TokenStream) -> TokenStream { input }
+
+#[proc_macro_derive = "foo"]
+//~^ ERROR: attribute must be of form: #[proc_macro_derive(TraitName)]
+pub fn foo2(input: TokenStream) -> TokenStream { input }
+
+#[proc_macro_derive(a, b)]
+//~^ ERROR: attribute must be of form: #[proc_macro_derive(TraitName)]
+pub fn foo3(input: TokenStream) -> TokenStream { input }
+
+#[proc_macro_derive(d3 = "a")]
+//~^ ERROR: not a meta item
+pub fn foo4(input: TokenStream) -> TokenStream { input }
@@ -0,0 +1,27 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// aux-build:forget_return_value.rs
+// ignore-stage1
+// compile-flags: -Z validate-mir
+// error-pattern: broken MIR in `forty_two` after pass `ForgetReturnValue`
+
+// Check that -Z validate-mir blames the (plugin) pass that broke the MIR.
+
+#![feature(plugin)]
+#![plugin(forget_return_value)]
+
+fn forty_two() -> i32 {
+    42
+}
+
+fn main() {
+    forty_two();
+}
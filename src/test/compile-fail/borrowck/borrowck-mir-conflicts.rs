@@ -0,0 +1,53 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: -Z borrowck-mir
+
+// Check that the MIR borrow checker reports conflicts alongside the
+// AST one, but (unlike the AST one) does not complain about borrows
+// that are no longer used.
+
+struct Point { x: isize, y: isize }
+
+fn assign_to_borrowed_field() {
+    let mut p = Point { x: 3, y: 4 };
+    let q = &p;
+    p.x = 5; //~ ERROR cannot assign to `p.x` because it is borrowed (Mir)
+    //~| ERROR cannot assign to `p.x`
+    q.x;
+}
+
+fn disjoint_fields() {
+    let mut p = Point { x: 3, y: 4 };
+    let q = &p.y;
+    p.x = 5;
+    *q;
+}
+
+fn mutable_borrow_while_shared() {
+    let mut v = vec![1];
+    let first = &v[0];
+    v.push(2);
+    //~^ ERROR cannot borrow `v` as mutable because it is also borrowed as immutable (Mir)
+    //~| ERROR cannot borrow `v` as mutable because it is also borrowed as immutable
+    drop(first);
+}
+
+fn borrow_not_used_afterwards() {
+    let mut x = 5;
+    let y = &mut x;
+    *y += 1;
+    // `y` is dead from here on, so only the AST borrow checker objects.
+    x = 6; //~ ERROR cannot assign to `x` because it is borrowed
+    drop(x);
+}
+
+fn main() {
+}
@@ -0,0 +1,41 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(derive_default_enum)]
+
+#[derive(Default)] //~ ERROR no default declared for enum
+enum NoDefault {
+    A,
+    B,
+}
+
+#[derive(Default)] //~ ERROR multiple declared defaults for enum
+enum TwoDefaults {
+    #[default]
+    A,
+    #[default]
+    B,
+}
+
+#[derive(Default)]
+enum TupleDefault {
+    #[default]
+    A(u8), //~ ERROR the `#[default]` attribute may only be used on unit enum variants
+    B,
+}
+
+#[derive(Default)]
+enum StructDefault {
+    #[default]
+    A { x: u8 }, //~ ERROR the `#[default]` attribute may only be used on unit enum variants
+    B,
+}
+
+fn main() {}
@@ -0,0 +1,22 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+fn test1() -> i128 { //~ ERROR 128-bit integers are not stable
+    0
+}
+
+fn test2() -> u128 { //~ ERROR 128-bit integers are not stable
+    0
+}
+
+fn main() {
+    let _ = 0i128; //~ ERROR 128-bit integers are not stable
+    let _ = 0u128; //~ ERROR 128-bit integers are not stable
+}
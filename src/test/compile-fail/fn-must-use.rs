@@ -0,0 +1,74 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![deny(unused_must_use)]
+#![allow(dead_code)]
+
+struct MyStruct {
+    n: usize,
+}
+
+impl MyStruct {
+    #[must_use]
+    fn need_to_use_this_method_value(&self) -> usize {
+        self.n
+    }
+
+    #[must_use = "the builder is consumed"]
+    fn with_n(self, n: usize) -> MyStruct {
+        MyStruct { n: n }
+    }
+}
+
+trait EvenNature {
+    #[must_use = "no side effects"]
+    fn is_even(&self) -> bool;
+}
+
+impl EvenNature for MyStruct {
+    fn is_even(&self) -> bool {
+        self.n % 2 == 0
+    }
+}
+
+#[must_use = "it's important"]
+fn need_to_use_this_value() -> bool {
+    false
+}
+
+fn no_need_to_use() -> usize {
+    0
+}
+
+#[must_use = "it's a token"]
+struct Token;
+
+#[must_use = "the token is discarded"]
+fn need_to_use_this_token() -> Token {
+    Token
+}
+
+fn main() {
+    need_to_use_this_value(); //~ ERROR which must be used: it's important
+
+    let m = MyStruct { n: 2 };
+    m.need_to_use_this_method_value(); //~ ERROR unused return value
+    MyStruct::need_to_use_this_method_value(&m); //~ ERROR unused return value
+    m.is_even(); //~ ERROR which must be used: no side effects
+
+    let m = MyStruct { n: 3 };
+    m.with_n(4); //~ ERROR which must be used: the builder is consumed
+
+    // Only the type is reported when both it and the function are #[must_use].
+    need_to_use_this_token(); //~ ERROR unused result which must be used: it's a token
+
+    no_need_to_use();
+    let _ = need_to_use_this_value();
+}
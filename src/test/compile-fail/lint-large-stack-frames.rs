@@ -0,0 +1,54 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(stack_size_limits)]
+#![deny(large_stack_frames, large_moves)]
+#![stack_frame_limit = "1024"]
+#![move_size_limit = "256"]
+#![allow(unused_variables)]
+
+struct Big([u8; 512]);
+
+fn small_frame() {
+    let a = [0u8; 100];
+    let b = [0u64; 100];
+}
+
+fn large_frame() {
+    let a = [0u8; 600];
+    let b = [0u8; 800]; //~ ERROR local variables of this function take up 1400 bytes of stack
+}
+
+fn closure_frame() {
+    let f = || { let a = [0u8; 2000]; }; //~ ERROR take up 2000 bytes of stack
+    f();
+}
+
+fn by_value(x: Big) {} //~ ERROR parameter of type `Big` is 512 bytes large
+
+fn by_reference(x: &Big) {}
+
+fn return_value() -> Big { //~ ERROR return value of type `Big` is 512 bytes large
+    Big([0; 512])
+}
+
+// The size of `T` is unknown here.
+fn generic<T>(x: T) -> T {
+    x
+}
+
+fn main() {
+    small_frame();
+    large_frame();
+    closure_frame();
+    by_value(return_value());
+    by_reference(&Big([0; 512]));
+    generic(Big([0; 512]));
+}
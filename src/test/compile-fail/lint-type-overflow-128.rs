@@ -0,0 +1,22 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(i128_type)]
+#![deny(overflowing_literals)]
+
+#[allow(unused_variables)]
+fn main() {
+    let x: u128 = 340282366920938463463374607431768211455; // should be OK
+    let x = 0x1_0000_0000_0000_0000_u128; // should be OK
+    let x: i128 = 170141183460469231731687303715884105727; // should be OK
+    let x: i128 = 170141183460469231731687303715884105728; //~ error: literal out of range for i128
+    let x: i128 = -170141183460469231731687303715884105728; // should be OK
+    let x = -170141183460469231731687303715884105729_i128; //~ error: literal out of range for i128
+}
@@ -53,4 +53,5 @@ fn main() {
     let x = 18446744073709551615_i64; //~ error: literal out of range for i64
     let x: i64 = -9223372036854775809; //~ error: literal out of range for i64
     let x = -9223372036854775809_i64; //~ error: literal out of range for i64
+    let x = 18446744073709551616_u64; //~ error: literal out of range for u64
 }
@@ -0,0 +1,34 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(loop_break_value)]
+
+fn main() {
+    let mut i = 0;
+    while i < 10 {
+        break i; //~ ERROR `break` with a value in a `while` loop
+    }
+
+    while let Some(x) = Some(1) {
+        break x; //~ ERROR `break` with a value in a `while let` loop
+    }
+
+    for x in 0..10 {
+        break x; //~ ERROR `break` with a value in a `for` loop
+    }
+
+    'outer: for _ in 0..10 {
+        loop {
+            break 'outer 1; //~ ERROR `break` with a value in a `for` loop
+        }
+    }
+
+    let _ = break 0; //~ ERROR `break` outside of loop
+}
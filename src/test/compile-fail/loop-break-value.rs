@@ -0,0 +1,31 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(loop_break_value)]
+
+fn main() {
+    let val: i32 = loop { //~ ERROR mismatched types
+        break "not an integer";
+    };
+
+    let _: i32 = loop {
+        if val > 0 {
+            break 1;
+        }
+        break; //~ ERROR mismatched types
+    };
+
+    'outer: loop {
+        for _ in 0..10 {
+            break 'outer 1;
+        }
+        break 'outer "two"; //~ ERROR mismatched types
+    };
+}
@@ -0,0 +1,27 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Check the follow-set restrictions of the `vis` fragment specifier.
+
+#![feature(macro_vis_matcher)]
+
+macro_rules! paren {
+    ($v:vis ($t:ty)) => {} //~ ERROR `$v:vis` is followed by `(`
+}
+
+macro_rules! privy {
+    ($v:vis priv) => {} //~ ERROR `$v:vis` is followed by `priv`
+}
+
+macro_rules! fine {
+    ($v:vis struct $i:ident, $l:lifetime $t:tt) => {}
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(repr_align)]
+#![allow(dead_code)]
+
+#[repr(align(8))] //~ ERROR: attribute should be applied to struct, enum or union
+fn f() {}
+
+#[repr(packed, align(8))]
+struct E(i32); //~ ERROR: conflicting packed and align representation hints
+
+fn main() {}
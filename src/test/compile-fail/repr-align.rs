@@ -0,0 +1,26 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(repr_align)]
+#![allow(dead_code)]
+
+#[repr(align(3))] //~ ERROR: not a power of two
+struct A(i32);
+
+#[repr(align(65536))] //~ ERROR: is larger than
+struct B(i32);
+
+#[repr(align(8u32))] //~ ERROR: expected an unsuffixed integer
+struct C(i32);
+
+#[repr(align("8"))] //~ ERROR: expected an unsuffixed integer
+struct D(i32);
+
+fn main() {}
@@ -0,0 +1,31 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(untagged_unions)]
+
+struct S;
+
+union U {
+    a: S,
+    b: S,
+}
+
+fn main() {
+    unsafe {
+        let u = U { a: S };
+        let a = u.a;
+        let b = u.b; //~ ERROR use of moved value: `u.b`
+
+        let mut u = U { a: S };
+        let a = u.a;
+        u.a = S;
+        let b = u.b; // OK, assigning to `u.a` reinitialized `u.b` as well
+    }
+}
@@ -0,0 +1,34 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(untagged_unions)]
+
+union U {
+    a: u8,
+    b: u16,
+}
+
+union U2 {
+    a: String,
+    b: u64,
+}
+
+fn main() {
+    let mut u = U { a: 10 }; // OK
+    u.a = 11; // OK
+    let a = u.a; //~ ERROR access to union field requires unsafe function or block
+    let U { a } = u; //~ ERROR matching on union field requires unsafe function or block
+    let b = unsafe { u.b }; // OK
+
+    let mut u2 = U2 { b: 1 }; // OK
+    u2.b = 2; // OK
+    u2.a = String::new(); //~ ERROR assignment to non-`Copy` union field requires unsafe
+    unsafe { u2.a = String::new() }; // OK
+}
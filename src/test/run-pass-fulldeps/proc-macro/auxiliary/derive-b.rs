@@ -0,0 +1,33 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// force-host
+// no-prefer-dynamic
+
+#![feature(proc_macro)]
+#![feature(proc_macro_lib)]
+#![crate_type = "proc-macro"]
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+
+#[proc_macro_derive(Named)]
+pub fn derive_named(input: TokenStream) -> TokenStream {
+    let input = input.to_string();
+    let name = input.split_whitespace()
+                    .skip_while(|s| *s != "struct" && *s != "enum")
+                    .nth(1)
+                    .unwrap()
+                    .trim_right_matches(|c: char| !c.is_alphanumeric());
+    format!("impl ::Named for {0} {{
+                 fn name() -> &'static str {{ \"{0}\" }}
+             }}", name).parse().unwrap()
+}
@@ -0,0 +1,41 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// aux-build:derive-b.rs
+// ignore-stage1
+
+#![feature(proc_macro)]
+
+#[macro_use]
+extern crate derive_b;
+
+trait Named {
+    fn name() -> &'static str;
+}
+
+#[derive(Debug, Named, PartialEq)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Named, Clone)]
+enum Shape {
+    Dot(Point),
+}
+
+fn main() {
+    assert_eq!(Point::name(), "Point");
+    assert_eq!(Shape::name(), "Shape");
+    let p = Point { x: 1, y: 2 };
+    assert_eq!(p, Point { x: 1, y: 2 });
+    let Shape::Dot(q) = Shape::Dot(p).clone();
+    assert_eq!(format!("{:?}", q), "Point { x: 1, y: 2 }");
+}
@@ -0,0 +1,44 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(derive_default_enum)]
+
+#[derive(Debug, Default, PartialEq)]
+enum Mode {
+    Fast,
+    #[default]
+    Balanced,
+    Careful { retries: u32 },
+}
+
+#[derive(Debug, Default, PartialEq)]
+enum MaybeNumber<T> {
+    #[default]
+    Nothing,
+    Number(T),
+}
+
+#[derive(Default)]
+struct Config {
+    mode: Mode,
+    limit: MaybeNumber<u64>,
+}
+
+fn main() {
+    assert_eq!(Mode::default(), Mode::Balanced);
+    assert_eq!(MaybeNumber::<String>::default(), MaybeNumber::Nothing);
+
+    let config = Config::default();
+    assert_eq!(config.mode, Mode::Balanced);
+    assert_eq!(config.limit, MaybeNumber::Nothing);
+
+    // The other variants are still usable.
+    let _ = (Mode::Fast, Mode::Careful { retries: 3 }, MaybeNumber::Number(1));
+}
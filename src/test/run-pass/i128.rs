@@ -0,0 +1,66 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(i128_type, i128)]
+
+use std::i128;
+
+fn b<T>(t: T) -> T { t }
+
+fn main() {
+    let x: i128 = -1;
+    assert_eq!(0, !x);
+    let y: i128 = -2;
+    assert_eq!(!1, y);
+    let z: i128 = 0xABCD_EF;
+    assert_eq!(z * z, 0x734C_C2F2_A521);
+    assert_eq!(z * z * z * z, (0x33EE_0E2A << 64) | 0x54E2_59DA_A0E7_8E41);
+    assert_eq!(-z * -z, 0x734C_C2F2_A521);
+    assert_eq!(-z * -z * -z * -z, (0x33EE_0E2A << 64) | 0x54E2_59DA_A0E7_8E41);
+    assert_eq!(-z + -z + -z + -z, -0x2AF_37BC);
+
+    let k: i128 = -((0x1234_5678_9ABC_DEF << 64) | 0xFEDC_BA98_7654_3210);
+    assert_eq!(k + k, -((0x2468_ACF1_3579_BDF << 64) | 0xFDB9_7530_ECA8_6420));
+    assert_eq!(0, k - k);
+    assert_eq!(k, -0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210);
+    assert_eq!(k + z, -((0x1234_5678_9ABC_DEF << 64) | 0xFEDC_BA98_75A8_6421));
+
+    let a: i128 = (0x1234_5678_9ABC_DEF0 << 64) | 0x0FED_CBA9_8765_4321;
+    let d: i128 = 0x1_0000_0001;
+    assert_eq!(b(a) / b(d), (0x1234_5678 << 64) | 0x8888_8877_8765_4331);
+    assert_eq!(b(a) % b(d), 0xFFFF_FFF0);
+    assert_eq!(b(-a) / b(d), -((0x1234_5678 << 64) | 0x8888_8877_8765_4331));
+    assert_eq!(b(-a) % b(d), -0xFFFF_FFF0);
+    assert_eq!(b(a) >> 64, 0x1234_5678_9ABC_DEF0);
+    assert_eq!(b(-a) >> 120, -0x13);
+    assert_eq!(b(1i128) << 100 >> 100, 1);
+
+    assert_eq!(i128::MIN, i128::min_value());
+    assert_eq!(i128::MAX, i128::max_value());
+    assert_eq!(i128::MIN, !i128::MAX);
+    assert_eq!(i128::MIN, -170141183460469231731687303715884105728);
+    assert_eq!(i128::MAX, 170141183460469231731687303715884105727i128);
+    assert_eq!(i128::MAX.checked_add(1), None);
+    assert_eq!(i128::MIN.checked_sub(1), None);
+    assert_eq!(i128::MAX.checked_mul(2), None);
+    assert_eq!(i128::MIN.checked_div(-1), None);
+    assert_eq!(i128::MAX.wrapping_add(1), i128::MIN);
+    assert_eq!((-1i64) as i128, -1);
+    assert_eq!(i128::MAX as i64, -1);
+    assert_eq!(i128::MIN.count_zeros(), 127);
+    assert_eq!((1i128 << 90).trailing_zeros(), 90);
+
+    assert_eq!(format!("{}", i128::MIN), "-170141183460469231731687303715884105728");
+    assert_eq!(format!("{}", i128::MAX), "170141183460469231731687303715884105727");
+    assert_eq!(format!("{:?}", k), "-1512366075204170947332355369683137040");
+    assert_eq!(format!("{:x}", -1i128), "ffffffffffffffffffffffffffffffff");
+    assert_eq!("-170141183460469231731687303715884105728".parse::<i128>(), Ok(i128::MIN));
+    assert!("170141183460469231731687303715884105728".parse::<i128>().is_err());
+}
@@ -0,0 +1,37 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(conservative_impl_trait)]
+
+use std::thread;
+
+// Auto traits like `Send` leak through `impl Trait`, whether the
+// concrete type is known at the use site (`before`) or only once
+// type-checking of all function bodies has finished (`after`).
+fn before() -> impl Fn() -> u32 {
+    let x = 10;
+    move || x
+}
+
+fn is_send<T: Send>(_: &T) {}
+
+fn main() {
+    let f = before();
+    is_send(&f);
+    let g = after();
+    is_send(&g);
+    let total = thread::spawn(move || f() + g()).join().unwrap();
+    assert_eq!(total, 30);
+}
+
+fn after() -> impl Fn() -> u32 {
+    let x = 20;
+    move || x
+}
@@ -0,0 +1,47 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(conservative_impl_trait)]
+
+use std::fmt::Debug;
+
+// Both the concrete type and the bounds of an `impl Trait` return
+// value are visible to callers through the bounds only.
+fn hide<T: Debug + Clone>(x: T) -> impl Debug + Clone {
+    x
+}
+
+fn counter(start: u32) -> impl Iterator<Item=u32> {
+    (start..).take(3)
+}
+
+fn evens(limit: u32) -> impl Iterator<Item=u32> {
+    counter(0).chain(counter(limit)).filter(|x| x % 2 == 0)
+}
+
+struct Wrapper(u32);
+
+impl Wrapper {
+    fn adder(self) -> impl Fn(u32) -> u32 {
+        move |x| x + self.0
+    }
+}
+
+fn main() {
+    let hidden = hide(vec![1, 2, 3]);
+    let copy = hidden.clone();
+    assert_eq!(format!("{:?}", copy), "[1, 2, 3]");
+
+    assert_eq!(counter(5).collect::<Vec<_>>(), [5, 6, 7]);
+    assert_eq!(evens(10).collect::<Vec<_>>(), [0, 2, 10, 12]);
+
+    let w = Wrapper(40);
+    assert_eq!(w.adder()(2), 42);
+}
@@ -0,0 +1,83 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(loop_break_value)]
+
+fn first_multiple_of(n: u32, xs: &[u32]) -> Option<u32> {
+    let mut i = 0;
+    loop {
+        if i == xs.len() {
+            break None;
+        }
+        if xs[i] % n == 0 {
+            break Some(xs[i]);
+        }
+        i += 1;
+    }
+}
+
+fn main() {
+    let mut count = 0;
+    let x = loop {
+        count += 1;
+        if count == 10 {
+            break count * 2;
+        }
+    };
+    assert_eq!(x, 20);
+
+    // A labelled break from a nested loop gives the value of the outer loop.
+    let pair = 'outer: loop {
+        for i in 0..10 {
+            let mut j = 0;
+            while j < 10 {
+                if i * j == 12 {
+                    break 'outer (i, j);
+                }
+                j += 1;
+            }
+        }
+        break (0, 0);
+    };
+    assert_eq!(pair, (2, 6));
+
+    // An inner `loop` can have its own value.
+    let sum = loop {
+        let inner: u32 = loop {
+            break 5;
+        };
+        break inner + 1;
+    };
+    assert_eq!(sum, 6);
+
+    // A plain `break` in a `loop` with a value gives `()`.
+    let unit: () = loop {
+        if count > 0 {
+            break;
+        }
+        break ();
+    };
+    assert_eq!(unit, ());
+
+    // `break` in a closure does not target the enclosing loop.
+    let v = loop {
+        let f = || loop { break "closure" };
+        break f().len();
+    };
+    assert_eq!(v, 7);
+
+    let owned = loop {
+        break String::from("moved");
+    };
+    assert_eq!(owned, "moved");
+
+    assert_eq!(first_multiple_of(3, &[1, 2, 9, 12]), Some(9));
+    assert_eq!(first_multiple_of(5, &[1, 2]), None);
+}
@@ -0,0 +1,39 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Test the `$(...)?` zero-or-one repetition operator.
+
+#![feature(macro_at_most_once_rep)]
+
+macro_rules! field {
+    ($name:ident $(= $default:expr)?) => {{
+        let v: Option<u32> = None;
+        $(let v = Some($default);)?
+        (stringify!($name), v)
+    }}
+}
+
+macro_rules! nested {
+    ($($a:ident $($b:ident)?);*) => {
+        [$(stringify!($a $($b)?)),*]
+    }
+}
+
+macro_rules! sep {
+    ($($a:ident)?*) => { [$(stringify!($a)),*].len() }
+}
+
+fn main() {
+    assert_eq!(field!(x), ("x", None));
+    assert_eq!(field!(y = 3), ("y", Some(3)));
+    assert_eq!(nested!(a; b c).len(), 2);
+    // `?` followed by `*` is still a separator.
+    assert_eq!(sep!(a ? b ? c), 3);
+}
@@ -0,0 +1,49 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Test the `lifetime` fragment specifier.
+
+#![feature(macro_lifetime_matcher)]
+
+macro_rules! borrow {
+    ($l:lifetime, $name:ident, $t:ty) => {
+        struct $name<$l> {
+            inner: &$l $t,
+        }
+
+        impl<$l> $name<$l> {
+            fn get(&self) -> &$l $t {
+                self.inner
+            }
+        }
+    }
+}
+
+borrow!('a, Wrapper, u32);
+
+macro_rules! labelled {
+    ($l:lifetime) => {{
+        let mut n = 0;
+        $l: loop {
+            loop {
+                n += 1;
+                break $l;
+            }
+        }
+        n
+    }}
+}
+
+fn main() {
+    let x = 5;
+    let w = Wrapper { inner: &x };
+    assert_eq!(*w.get(), 5);
+    assert_eq!(labelled!('outer), 1);
+}
@@ -0,0 +1,50 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Test the `vis` fragment specifier, including the empty visibility.
+
+#![feature(macro_vis_matcher, pub_restricted)]
+
+macro_rules! make_struct {
+    ($v:vis struct $name:ident;) => {
+        #[derive(Debug)]
+        $v struct $name;
+    }
+}
+
+macro_rules! make_fn {
+    ($v:vis fn $name:ident() -> $t:ty { $e:expr }) => {
+        $v fn $name() -> $t { $e }
+    }
+}
+
+macro_rules! vis_only {
+    ($v:vis) => { stringify!($v).is_empty() }
+}
+
+mod foo {
+    make_struct!(pub struct Public;);
+    make_struct!(struct Private;);
+    make_fn!(pub fn answer() -> u32 { 42 });
+    make_fn!(pub(crate) fn crate_answer() -> u32 { 43 });
+
+    pub fn private() -> Private {
+        Private
+    }
+}
+
+fn main() {
+    let _ = foo::Public;
+    let _ = foo::private();
+    assert_eq!(foo::answer(), 42);
+    assert_eq!(foo::crate_answer(), 43);
+    assert!(!vis_only!(pub));
+    assert!(vis_only!());
+}
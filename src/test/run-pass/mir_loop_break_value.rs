@@ -0,0 +1,68 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(rustc_attrs, loop_break_value)]
+
+#[rustc_mir]
+fn count_to(n: u32) -> u32 {
+    let mut i = 0;
+    loop {
+        if i == n {
+            break i;
+        }
+        i += 1;
+    }
+}
+
+#[rustc_mir]
+fn find_pair(product: u32) -> Option<(u32, u32)> {
+    'outer: loop {
+        for i in 1..10 {
+            let mut j = 1;
+            while j < 10 {
+                if i * j == product {
+                    break 'outer Some((i, j));
+                }
+                j += 1;
+            }
+        }
+        break None;
+    }
+}
+
+#[rustc_mir]
+fn owned(s: &str) -> String {
+    let mut parts = Vec::new();
+    loop {
+        parts.push(String::from(s));
+        if parts.len() == 2 {
+            break parts.concat();
+        }
+    }
+}
+
+#[rustc_mir]
+fn unit_loop() -> () {
+    let mut i = 0;
+    loop {
+        i += 1;
+        if i > 3 {
+            break;
+        }
+    }
+}
+
+fn main() {
+    assert_eq!(count_to(7), 7);
+    assert_eq!(find_pair(12), Some((2, 6)));
+    assert_eq!(find_pair(97), None);
+    assert_eq!(owned("ab"), "abab");
+    unit_loop();
+}
@@ -0,0 +1,107 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Check the decision trees built for matches on string and byte string
+// constants.
+
+fn keyword(s: &str) -> u32 {
+    match s {
+        "" => 0,
+        "as" => 1,
+        "if" => 2,
+        "in" => 3,
+        "fn" => 4,
+        "let" => 5,
+        "for" => 6,
+        "mut" => 7,
+        "loop" => 8,
+        "impl" => 9,
+        "else" => 10,
+        "match" => 11,
+        "while" => 12,
+        "break" => 13,
+        "trait" => 14,
+        "héllo" => 15,
+        _ => 100,
+    }
+}
+
+fn guarded(s: &str, flag: bool) -> u32 {
+    match s {
+        "foo" if flag => 1,
+        "bar" | "baz" => 2,
+        "foo" => 3,
+        x if x.len() == 3 => 4,
+        _ => 5,
+    }
+}
+
+fn bytes(b: &[u8]) -> u32 {
+    match b {
+        b"GET" => 1,
+        b"PUT" => 2,
+        b"POST" => 3,
+        b"HEAD" => 4,
+        b"DELETE" => 5,
+        _ => 0,
+    }
+}
+
+fn array(b: &[u8; 2]) -> u32 {
+    match b {
+        b"ab" => 1,
+        b"ba" => 2,
+        b"aa" => 3,
+        _ => 0,
+    }
+}
+
+fn single(s: &str) -> bool {
+    match s {
+        "only" => true,
+        _ => false,
+    }
+}
+
+fn main() {
+    let keywords = ["", "as", "if", "in", "fn", "let", "for", "mut", "loop", "impl",
+                    "else", "match", "while", "break", "trait", "héllo"];
+    for (i, k) in keywords.iter().enumerate() {
+        assert_eq!(keyword(k), i as u32);
+    }
+    for s in &["a", "is", "fo", "lets", "matches", "whilf", "hello", "trai", "impl "] {
+        assert_eq!(keyword(s), 100);
+    }
+
+    assert_eq!(guarded("foo", true), 1);
+    assert_eq!(guarded("foo", false), 3);
+    assert_eq!(guarded("bar", true), 2);
+    assert_eq!(guarded("baz", false), 2);
+    assert_eq!(guarded("qux", false), 4);
+    assert_eq!(guarded("quux", true), 5);
+
+    assert_eq!(bytes(b"GET"), 1);
+    assert_eq!(bytes(b"PUT"), 2);
+    assert_eq!(bytes(b"POST"), 3);
+    assert_eq!(bytes(b"HEAD"), 4);
+    assert_eq!(bytes(b"DELETE"), 5);
+    assert_eq!(bytes(b"PATCH"), 0);
+    assert_eq!(bytes(b"GOT"), 0);
+    assert_eq!(bytes(b""), 0);
+
+    assert_eq!(array(b"ab"), 1);
+    assert_eq!(array(b"ba"), 2);
+    assert_eq!(array(b"aa"), 3);
+    assert_eq!(array(b"bb"), 0);
+
+    assert!(single("only"));
+    assert!(!single("onlx"));
+    assert!(!single(""));
+}
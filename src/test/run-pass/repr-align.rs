@@ -0,0 +1,102 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(repr_align, untagged_unions)]
+
+use std::mem;
+
+// Raising the alignment rounds the size up to a multiple of it.
+#[repr(align(16))]
+struct Align16(i32);
+
+#[repr(align(64))]
+struct CacheLine {
+    counter: u64,
+}
+
+// A lower alignment than the natural one has no effect.
+#[repr(align(1))]
+struct Natural(u32);
+
+#[repr(C, align(8))]
+struct Pair(u8, u8);
+
+struct Nested {
+    a: u8,
+    b: Align16,
+}
+
+#[repr(align(32))]
+enum Tagged {
+    A(u8),
+    B(u32),
+}
+
+#[repr(align(8))]
+enum CLike {
+    X,
+    Y,
+}
+
+#[repr(align(16))]
+union Overlay {
+    a: u8,
+    b: u32,
+}
+
+static STATIC: Align16 = Align16(7);
+
+fn is_aligned<T>(t: &T, align: usize) -> bool {
+    (t as *const T as usize) % align == 0
+}
+
+pub fn main() {
+    assert_eq!(mem::align_of::<Align16>(), 16);
+    assert_eq!(mem::size_of::<Align16>(), 16);
+    assert_eq!(mem::align_of::<CacheLine>(), 64);
+    assert_eq!(mem::size_of::<CacheLine>(), 64);
+    assert_eq!(mem::align_of::<Natural>(), 4);
+    assert_eq!(mem::size_of::<Natural>(), 4);
+    assert_eq!(mem::align_of::<Pair>(), 8);
+    assert_eq!(mem::size_of::<Pair>(), 8);
+
+    assert_eq!(mem::align_of::<Nested>(), 16);
+    assert_eq!(mem::size_of::<Nested>(), 32);
+    assert_eq!(mem::size_of::<[Align16; 3]>(), 48);
+
+    assert_eq!(mem::align_of::<Tagged>(), 32);
+    assert_eq!(mem::size_of::<Tagged>(), 32);
+    assert_eq!(mem::align_of::<CLike>(), 8);
+    assert_eq!(mem::size_of::<CLike>(), 8);
+    assert_eq!(mem::align_of::<Overlay>(), 16);
+    assert_eq!(mem::size_of::<Overlay>(), 16);
+
+    let a = Align16(1);
+    let b = CacheLine { counter: 2 };
+    let arr = [Align16(3), Align16(4)];
+    assert!(is_aligned(&a, 16));
+    assert!(is_aligned(&b, 64));
+    assert!(is_aligned(&arr[1], 16));
+    assert!(is_aligned(&STATIC, 16));
+    assert_eq!(a.0 + arr[1].0 + STATIC.0, 12);
+    assert_eq!(b.counter, 2);
+
+    let boxed = Box::new(CacheLine { counter: 3 });
+    assert!(is_aligned(&*boxed, 64));
+
+    match Tagged::B(5) {
+        Tagged::B(x) => assert_eq!(x, 5),
+        Tagged::A(_) => panic!(),
+    }
+    match CLike::Y {
+        CLike::Y => {}
+        CLike::X => panic!(),
+    }
+}
@@ -0,0 +1,52 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(i128_type, i128)]
+
+use std::u128;
+
+fn b<T>(t: T) -> T { t }
+
+fn main() {
+    let x: u128 = 0xFFFF_FFFF_FFFF_FFFF;
+    assert_eq!(x + 1, 1 << 64);
+    assert_eq!(!x, 0xFFFF_FFFF_FFFF_FFFF << 64);
+    let y: u128 = (0x1234_5678_9ABC_DEF0 << 64) | 0x0FED_CBA9_8765_4321;
+    let d: u128 = 0x1_0000_0001;
+    assert_eq!(b(y) / b(d), (0x1234_5678 << 64) | 0x8888_8877_8765_4331);
+    assert_eq!(b(y) % b(d), 0xFFFF_FFF0);
+    assert_eq!(b(y) * b(d) / b(d), y);
+    assert_eq!(b(y) >> 68, 0x1234_5678_9ABC_DEF);
+    assert_eq!(b(y) << 64 >> 64, 0x0FED_CBA9_8765_4321);
+    assert_eq!(y.swap_bytes().swap_bytes(), y);
+    assert_eq!(y.count_ones(), 64);
+    assert_eq!(y.leading_zeros(), 3);
+    assert_eq!(0x1234_5678_9ABC_DEF0_0FED_CBA9_8765_4321, y);
+    assert_eq!(0x1_0000_0000_0000_0000, 1u128 << 64);
+
+    assert_eq!(u128::MIN, 0);
+    assert_eq!(u128::MAX, u128::max_value());
+    assert_eq!(u128::MAX, !0);
+    assert_eq!(u128::MAX, 340282366920938463463374607431768211455);
+    assert_eq!(u128::MAX, 0xFFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFFu128);
+    assert_eq!(u128::MAX.checked_add(1), None);
+    assert_eq!(0u128.checked_sub(1), None);
+    assert_eq!(u128::MAX.checked_mul(2), None);
+    assert_eq!(u128::MAX.wrapping_add(1), 0);
+    assert_eq!(u128::MAX as u64, u64::max_value());
+    assert_eq!(u64::max_value() as u128 + 1, 1 << 64);
+
+    assert_eq!(format!("{}", u128::MAX), "340282366920938463463374607431768211455");
+    assert_eq!(format!("{:?}", 1u128 << 64), "18446744073709551616");
+    assert_eq!(format!("{:X}", y), "123456789ABCDEF00FEDCBA987654321");
+    assert_eq!(format!("{:b}", 1u128 << 100).len(), 101);
+    assert_eq!("340282366920938463463374607431768211455".parse::<u128>(), Ok(u128::MAX));
+    assert!("340282366920938463463374607431768211456".parse::<u128>().is_err());
+}
@@ -0,0 +1,122 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(untagged_unions)]
+
+use std::mem::{size_of, align_of, zeroed};
+
+union U {
+    a: u8,
+}
+
+union U64 {
+    a: u64,
+}
+
+#[repr(C)]
+union W {
+    a: u8,
+    b: u64,
+}
+
+#[repr(C)]
+union Y {
+    f1: u16,
+    f2: [u8; 4],
+}
+
+union G<T: Copy> {
+    a: T,
+    b: u8,
+}
+
+impl Clone for W {
+    fn clone(&self) -> Self { *self }
+}
+impl Copy for W {}
+
+// `union` is only a keyword in item position.
+fn union() -> u8 {
+    let union = 10;
+    union
+}
+
+static mut COUNT: u8 = 0;
+
+struct D;
+
+impl Drop for D {
+    fn drop(&mut self) {
+        unsafe { COUNT += 1; }
+    }
+}
+
+union ND {
+    a: D,
+}
+
+union WithDrop {
+    a: u8,
+}
+
+impl Drop for WithDrop {
+    fn drop(&mut self) {
+        unsafe { COUNT += 10; }
+    }
+}
+
+fn main() {
+    assert_eq!(size_of::<U>(), 1);
+    assert_eq!(size_of::<U64>(), 8);
+    assert_eq!(size_of::<W>(), 8);
+    assert_eq!(align_of::<U>(), 1);
+    assert_eq!(align_of::<U64>(), align_of::<u64>());
+    assert_eq!(align_of::<W>(), align_of::<u64>());
+    assert_eq!(size_of::<Y>(), 4);
+    assert_eq!(size_of::<G<u32>>(), 4);
+
+    unsafe {
+        let u = U { a: 10 };
+        assert_eq!(u.a, 10);
+        let U { a } = u;
+        assert_eq!(a, 10);
+
+        let mut w = W { b: 0 };
+        assert_eq!(w.a, 0);
+        assert_eq!(w.b, 0);
+        w.a = 1;
+        assert_eq!(w.a, 1);
+        assert_eq!(w.b.to_le(), 1);
+        let w2 = w;
+        assert_eq!(w2.a, 1);
+
+        let w: W = zeroed();
+        assert_eq!(w.b, 0);
+
+        let y = Y { f1: 0xFFFF };
+        assert_eq!(y.f2[0], 0xFF);
+        assert_eq!(y.f2[1], 0xFF);
+
+        let g = G { a: 7u32 };
+        assert_eq!(g.a, 7);
+    }
+
+    assert_eq!(union(), 10);
+
+    // The fields of a union are never dropped, but its own `Drop` impl is.
+    {
+        let _nd = ND { a: D };
+    }
+    assert_eq!(unsafe { COUNT }, 0);
+    {
+        let _wd = WithDrop { a: 0 };
+    }
+    assert_eq!(unsafe { COUNT }, 10);
+}